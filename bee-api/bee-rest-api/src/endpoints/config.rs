@@ -4,7 +4,10 @@
 use multiaddr::{Multiaddr, Protocol};
 use serde::Deserialize;
 
-use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs};
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs},
+    path::{Path, PathBuf},
+};
 
 pub(crate) const DEFAULT_BIND_ADDRESS: &str = "/ip4/0.0.0.0/tcp/14265";
 
@@ -27,6 +30,7 @@ pub(crate) const ROUTE_OUTPUTS_ED25519: &str = "/api/v1/addresses/ed25519/:addre
 pub(crate) const ROUTE_PEER: &str = "/api/v1/peers/:peerId";
 pub(crate) const ROUTE_PEERS: &str = "/api/v1/peers";
 pub(crate) const ROUTE_REMOVE_PEER: &str = "/api/v1/peers/:peerId";
+pub(crate) const ROUTE_STORAGE_BACKUP: &str = "/api/v1/storage/backup";
 pub(crate) const ROUTE_SUBMIT_MESSAGE: &str = "/api/v1/messages";
 pub(crate) const ROUTE_SUBMIT_MESSAGE_RAW: &str = "/api/v1/messages";
 pub(crate) const ROUTE_TIPS: &str = "/api/v1/tips";
@@ -66,6 +70,8 @@ pub(crate) const DEFAULT_ALLOWED_IPS: [IpAddr; 2] = [
 ];
 pub(crate) const DEFAULT_FEATURE_PROOF_OF_WORK: bool = true;
 pub(crate) const DEFAULT_WHITE_FLAG_SOLIDIFICATION_TIMEOUT: u64 = 2;
+pub(crate) const DEFAULT_BACKUP_PATH: &str = "./storage/backups";
+pub(crate) const MAX_PAGE_SIZE: usize = 1000;
 
 /// REST API configuration builder.
 #[derive(Default, Deserialize)]
@@ -76,6 +82,7 @@ pub struct RestApiConfigBuilder {
     allowed_ips: Option<Vec<IpAddr>>,
     feature_proof_of_work: Option<bool>,
     white_flag_solidification_timeout: Option<u64>,
+    backup_path: Option<String>,
 }
 
 impl RestApiConfigBuilder {
@@ -119,6 +126,12 @@ impl RestApiConfigBuilder {
         self
     }
 
+    /// Sets the directory in which storage backups are created.
+    pub fn backup_path(mut self, path: String) -> Self {
+        self.backup_path.replace(path);
+        self
+    }
+
     /// Builds the REST API config.
     pub fn finish(self) -> RestApiConfig {
         let multi_addr = self
@@ -160,6 +173,7 @@ impl RestApiConfigBuilder {
         let white_flag_solidification_timeout = self
             .white_flag_solidification_timeout
             .unwrap_or(DEFAULT_WHITE_FLAG_SOLIDIFICATION_TIMEOUT);
+        let backup_path = PathBuf::from(self.backup_path.unwrap_or_else(|| DEFAULT_BACKUP_PATH.to_string()));
 
         RestApiConfig {
             binding_socket_addr: SocketAddr::new(address, port),
@@ -167,6 +181,7 @@ impl RestApiConfigBuilder {
             allowed_ips,
             feature_proof_of_work,
             white_flag_solidification_timeout,
+            backup_path,
         }
     }
 }
@@ -179,6 +194,7 @@ pub struct RestApiConfig {
     pub(crate) allowed_ips: Box<[IpAddr]>,
     pub(crate) feature_proof_of_work: bool,
     pub(crate) white_flag_solidification_timeout: u64,
+    pub(crate) backup_path: PathBuf,
 }
 
 impl RestApiConfig {
@@ -211,4 +227,9 @@ impl RestApiConfig {
     pub fn white_flag_solidification_timeout(&self) -> u64 {
         self.white_flag_solidification_timeout
     }
+
+    /// Returns the directory in which storage backups are created.
+    pub fn backup_path(&self) -> &Path {
+        &self.backup_path
+    }
 }
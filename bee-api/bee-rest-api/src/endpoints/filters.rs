@@ -5,6 +5,7 @@ use crate::endpoints::{config::RestApiConfig, storage::StorageBackend, Bech32Hrp
 
 use bee_gossip::NetworkCommandSender;
 use bee_ledger::workers::consensus::ConsensusWorkerCommand;
+use bee_message::output::ByteCostConfig;
 use bee_protocol::workers::{
     config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
 };
@@ -40,6 +41,12 @@ pub(crate) fn with_protocol_config(
     warp::any().map(move || config.clone())
 }
 
+pub(crate) fn with_byte_cost_config(
+    config: ByteCostConfig,
+) -> impl Filter<Extract = (ByteCostConfig,), Error = Infallible> + Clone {
+    warp::any().map(move || config)
+}
+
 pub(crate) fn with_tangle<B: StorageBackend>(
     tangle: ResourceHandle<Tangle<B>>,
 ) -> impl Filter<Extract = (ResourceHandle<Tangle<B>>,), Error = Infallible> + Clone {
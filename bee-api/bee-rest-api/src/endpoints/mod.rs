@@ -6,6 +6,7 @@ mod filters;
 pub mod config;
 pub mod path_params;
 pub mod permission;
+pub mod query_params;
 pub mod rejection;
 pub mod routes;
 pub mod storage;
@@ -18,6 +19,7 @@ use crate::types::body::{DefaultErrorResponse, ErrorBody};
 
 use bee_gossip::NetworkCommandSender;
 use bee_ledger::workers::consensus::ConsensusWorker;
+use bee_message::output::ByteCostConfig;
 use bee_protocol::workers::{
     config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorker, PeerManager, PeerManagerResWorker,
     RequestedMessages,
@@ -42,6 +44,7 @@ pub(crate) const CONFIRMED_THRESHOLD: u32 = 5;
 pub async fn init<N: Node>(
     rest_api_config: RestApiConfig,
     protocol_config: ProtocolConfig,
+    byte_cost_config: ByteCostConfig,
     network_id: NetworkId,
     bech32_hrp: Bech32Hrp,
     node_builder: N::Builder,
@@ -49,7 +52,13 @@ pub async fn init<N: Node>(
 where
     N::Backend: StorageBackend,
 {
-    node_builder.with_worker_cfg::<ApiWorker>((rest_api_config, protocol_config, network_id, bech32_hrp))
+    node_builder.with_worker_cfg::<ApiWorker>((
+        rest_api_config,
+        protocol_config,
+        byte_cost_config,
+        network_id,
+        bech32_hrp,
+    ))
 }
 
 pub struct ApiWorker;
@@ -58,7 +67,7 @@ impl<N: Node> Worker<N> for ApiWorker
 where
     N::Backend: StorageBackend,
 {
-    type Config = (RestApiConfig, ProtocolConfig, NetworkId, Bech32Hrp);
+    type Config = (RestApiConfig, ProtocolConfig, ByteCostConfig, NetworkId, Bech32Hrp);
     type Error = WorkerError;
 
     fn dependencies() -> &'static [TypeId] {
@@ -73,8 +82,9 @@ where
     async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
         let rest_api_config = config.0;
         let protocol_config = config.1;
-        let network_id = config.2;
-        let bech32_hrp = config.3;
+        let byte_cost_config = config.2;
+        let network_id = config.3;
+        let bech32_hrp = config.4;
 
         let consensus_worker = node.worker::<ConsensusWorker>().unwrap().tx.clone();
         let tangle = node.resource::<Tangle<N::Backend>>();
@@ -100,6 +110,7 @@ where
                 bech32_hrp,
                 rest_api_config.clone(),
                 protocol_config,
+                byte_cost_config,
                 peer_manager,
                 network_controller,
                 node_info,
@@ -4,7 +4,13 @@
 use crate::endpoints::rejection::CustomRejection;
 
 use bee_gossip::PeerId;
-use bee_message::{milestone::MilestoneIndex, output::OutputId, payload::transaction::TransactionId, MessageId};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::MilestoneIndex,
+    output::OutputId,
+    payload::transaction::TransactionId,
+    MessageId,
+};
 
 use warp::{reject, Filter, Rejection};
 
@@ -32,6 +38,21 @@ pub(super) fn transaction_id() -> impl Filter<Extract = (TransactionId,), Error
     })
 }
 
+pub(super) fn bech32_address() -> impl Filter<Extract = (Address,), Error = Rejection> + Copy {
+    warp::path::param().and_then(|value: String| async move {
+        Address::try_from_bech32(&value)
+            .map_err(|_| reject::custom(CustomRejection::BadRequest("invalid bech32 address".to_string())))
+    })
+}
+
+pub(super) fn ed25519_address() -> impl Filter<Extract = (Ed25519Address,), Error = Rejection> + Copy {
+    warp::path::param().and_then(|value: String| async move {
+        value
+            .parse::<Ed25519Address>()
+            .map_err(|_| reject::custom(CustomRejection::BadRequest("invalid ed25519 address".to_string())))
+    })
+}
+
 pub(super) fn milestone_index() -> impl Filter<Extract = (MilestoneIndex,), Error = Rejection> + Copy {
     warp::path::param().and_then(|value: String| async move {
         value
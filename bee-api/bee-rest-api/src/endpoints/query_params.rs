@@ -0,0 +1,61 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::endpoints::{config::MAX_PAGE_SIZE, rejection::CustomRejection};
+
+use bee_ledger::types::AddressRole;
+
+use warp::{reject, Filter, Rejection};
+
+use std::{collections::HashMap, str::FromStr};
+
+/// Extracts the optional `cursor` and `pageSize` query parameters of a paginated route. The page size defaults to, and
+/// can not exceed, `MAX_PAGE_SIZE`.
+pub(super) fn page<C: FromStr + Send>() -> impl Filter<Extract = (Option<C>, usize), Error = Rejection> + Copy {
+    warp::query::<HashMap<String, String>>()
+        .and_then(|query: HashMap<String, String>| async move {
+            let cursor = query
+                .get("cursor")
+                .map(|cursor| {
+                    cursor
+                        .parse::<C>()
+                        .map_err(|_| reject::custom(CustomRejection::BadRequest("invalid cursor".to_string())))
+                })
+                .transpose()?;
+            let page_size = match query.get("pageSize") {
+                Some(page_size) => page_size
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|page_size| (1..=MAX_PAGE_SIZE).contains(page_size))
+                    .ok_or_else(|| {
+                        reject::custom(CustomRejection::BadRequest(format!(
+                            "invalid page size, expected a number from 1 to {}",
+                            MAX_PAGE_SIZE
+                        )))
+                    })?,
+                None => MAX_PAGE_SIZE,
+            };
+
+            Ok::<_, Rejection>((cursor, page_size))
+        })
+        .untuple_one()
+}
+
+/// Extracts the optional `role` query parameter of the address outputs routes. The role defaults to `address`, the
+/// owner of the outputs.
+pub(super) fn address_role() -> impl Filter<Extract = (AddressRole,), Error = Rejection> + Copy {
+    warp::query::<HashMap<String, String>>().and_then(|query: HashMap<String, String>| async move {
+        match query.get("role").map(String::as_str) {
+            None | Some("address") => Ok(AddressRole::Address),
+            Some("dustDepositReturn") => Ok(AddressRole::DustDepositReturn),
+            Some("expirationReturn") => Ok(AddressRole::ExpirationReturn),
+            Some("stateController") => Ok(AddressRole::StateController),
+            Some("governor") => Ok(AddressRole::Governor),
+            Some(_) => Err(reject::custom(CustomRejection::BadRequest(
+                "invalid role, expected one of address, dustDepositReturn, expirationReturn, stateController or \
+                 governor"
+                    .to_string(),
+            ))),
+        }
+    })
+}
@@ -8,6 +8,7 @@ use crate::endpoints::{config::RestApiConfig, storage::StorageBackend, Bech32Hrp
 
 use bee_gossip::NetworkCommandSender;
 use bee_ledger::workers::consensus::ConsensusWorkerCommand;
+use bee_message::output::ByteCostConfig;
 use bee_protocol::workers::{
     config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
 };
@@ -34,6 +35,7 @@ pub(crate) fn filter<B: StorageBackend>(
     bech32_hrp: Bech32Hrp,
     rest_api_config: RestApiConfig,
     protocol_config: ProtocolConfig,
+    byte_cost_config: ByteCostConfig,
     peer_manager: ResourceHandle<PeerManager>,
     network_command_sender: ResourceHandle<NetworkCommandSender>,
     node_info: ResourceHandle<NodeInfo>,
@@ -66,5 +68,6 @@ pub(crate) fn filter<B: StorageBackend>(
         message_requester,
         requested_messages,
         rest_api_config,
+        byte_cost_config,
     ))
 }
@@ -5,7 +5,8 @@ use crate::{
     endpoints::{
         config::{RestApiConfig, ROUTE_WHITE_FLAG},
         filters::{
-            with_bus, with_message_requester, with_requested_messages, with_rest_api_config, with_storage, with_tangle,
+            with_bus, with_byte_cost_config, with_message_requester, with_requested_messages, with_rest_api_config,
+            with_storage, with_tangle,
         },
         permission::has_permission,
         rejection::CustomRejection,
@@ -15,7 +16,7 @@ use crate::{
 };
 
 use bee_ledger::workers::consensus::{self, WhiteFlagMetadata};
-use bee_message::{milestone::MilestoneIndex, MessageId};
+use bee_message::{milestone::MilestoneIndex, output::ByteCostConfig, MessageId};
 use bee_protocol::workers::{event::MessageSolidified, request_message, MessageRequesterWorker, RequestedMessages};
 use bee_runtime::{event::Bus, resource::ResourceHandle};
 use bee_tangle::Tangle;
@@ -47,6 +48,7 @@ pub(crate) fn filter<B: StorageBackend>(
     message_requester: MessageRequesterWorker,
     requested_messages: ResourceHandle<RequestedMessages>,
     rest_api_config: RestApiConfig,
+    byte_cost_config: ByteCostConfig,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::post())
@@ -58,10 +60,12 @@ pub(crate) fn filter<B: StorageBackend>(
         .and(with_message_requester(message_requester))
         .and(with_requested_messages(requested_messages))
         .and(with_rest_api_config(rest_api_config))
+        .and(with_byte_cost_config(byte_cost_config))
         .and_then(white_flag)
         .boxed()
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn white_flag<B: StorageBackend>(
     body: JsonValue,
     storage: ResourceHandle<B>,
@@ -70,6 +74,7 @@ pub(crate) async fn white_flag<B: StorageBackend>(
     message_requester: MessageRequesterWorker,
     requested_messages: ResourceHandle<RequestedMessages>,
     rest_api_config: RestApiConfig,
+    byte_cost_config: ByteCostConfig,
 ) -> Result<impl Reply, Rejection> {
     let index_json = &body["index"];
     let parents_json = &body["parentMessageIds"];
@@ -160,8 +165,7 @@ pub(crate) async fn white_flag<B: StorageBackend>(
         }
     }
 
-    // TODO
-    let mut metadata = WhiteFlagMetadata::new(index, 0);
+    let mut metadata = WhiteFlagMetadata::new(index, 0, byte_cost_config);
 
     // Wait for either all parents to get solid or the timeout to expire.
     let response = match timeout(
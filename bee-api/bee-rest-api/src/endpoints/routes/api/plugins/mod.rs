@@ -5,6 +5,7 @@ mod debug;
 
 use crate::endpoints::{config::RestApiConfig, storage::StorageBackend};
 
+use bee_message::output::ByteCostConfig;
 use bee_protocol::workers::{MessageRequesterWorker, RequestedMessages};
 use bee_runtime::{event::Bus, resource::ResourceHandle};
 use bee_tangle::Tangle;
@@ -27,6 +28,7 @@ pub(crate) fn filter<B: StorageBackend>(
     message_requester: MessageRequesterWorker,
     requested_messages: ResourceHandle<RequestedMessages>,
     rest_api_config: RestApiConfig,
+    byte_cost_config: ByteCostConfig,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     debug::filter(
         public_routes,
@@ -37,5 +39,6 @@ pub(crate) fn filter<B: StorageBackend>(
         message_requester,
         requested_messages,
         rest_api_config,
+        byte_cost_config,
     )
 }
@@ -3,17 +3,18 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_MESSAGE_CHILDREN, filters::with_tangle, path_params::message_id, permission::has_permission,
-        storage::StorageBackend,
+        config::ROUTE_MESSAGE_CHILDREN, filters::with_storage, path_params::message_id, permission::has_permission,
+        query_params::page, rejection::CustomRejection, storage::StorageBackend,
     },
     types::{body::SuccessBody, responses::MessageChildrenResponse},
 };
 
+use bee_ledger::workers::storage;
 use bee_message::MessageId;
 use bee_runtime::resource::ResourceHandle;
-use bee_tangle::Tangle;
 
-use warp::{filters::BoxedFilter, Filter, Rejection, Reply};
+use log::error;
+use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
 
 use std::net::IpAddr;
 
@@ -28,28 +29,38 @@ fn path() -> impl Filter<Extract = (MessageId,), Error = warp::Rejection> + Clon
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
     allowed_ips: Box<[IpAddr]>,
-    tangle: ResourceHandle<Tangle<B>>,
+    storage: ResourceHandle<B>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
         .and(has_permission(ROUTE_MESSAGE_CHILDREN, public_routes, allowed_ips))
-        .and(with_tangle(tangle))
-        .and_then(message_children)
+        .and(page::<MessageId>())
+        .and(with_storage(storage))
+        .and_then(|message_id, cursor, page_size, storage| async move {
+            message_children(message_id, cursor, page_size, storage)
+        })
         .boxed()
 }
 
-pub async fn message_children<B: StorageBackend>(
+pub fn message_children<B: StorageBackend>(
     message_id: MessageId,
-    tangle: ResourceHandle<Tangle<B>>,
+    cursor: Option<MessageId>,
+    page_size: usize,
+    storage: ResourceHandle<B>,
 ) -> Result<impl Reply, Rejection> {
-    let mut children = Vec::from_iter(tangle.get_children(&message_id).await.unwrap_or_default());
-    let count = children.len();
-    let max_results = 1000;
-    children.truncate(max_results);
+    let (children, cursor) =
+        storage::fetch_children_page(&*storage, &message_id, cursor.as_ref(), page_size).map_err(|e| {
+            error!("unable to fetch the children of the message: {}", e);
+            reject::custom(CustomRejection::ServiceUnavailable(
+                "unable to fetch the children of the message".to_string(),
+            ))
+        })?;
+
     Ok(warp::reply::json(&SuccessBody::new(MessageChildrenResponse {
         message_id: message_id.to_string(),
-        max_results,
-        count,
+        max_results: page_size,
+        count: children.len(),
         children_message_ids: children.iter().map(|id| id.to_string()).collect(),
+        cursor: cursor.map(|cursor| cursor.to_string()),
     })))
 }
@@ -10,11 +10,14 @@ pub mod message_raw;
 pub mod milestone;
 pub mod milestone_utxo_changes;
 pub mod output;
+pub mod outputs_bech32;
+pub mod outputs_ed25519;
 pub mod peer;
 pub mod peers;
 pub mod receipts;
 pub mod receipts_at;
 pub mod remove_peer;
+pub mod storage_backup;
 pub mod submit_message;
 pub mod tips;
 pub mod transaction_included_message;
@@ -78,7 +81,7 @@ pub(crate) fn filter<B: StorageBackend>(
     .or(message_children::filter(
         public_routes.clone(),
         allowed_ips.clone(),
-        tangle.clone(),
+        storage.clone(),
     ))
     .or(message_metadata::filter(
         public_routes.clone(),
@@ -104,6 +107,16 @@ pub(crate) fn filter<B: StorageBackend>(
         public_routes.clone(),
         allowed_ips.clone(),
         storage.clone(),
+        consensus_worker.clone(),
+    ))
+    .or(outputs_bech32::filter(
+        public_routes.clone(),
+        allowed_ips.clone(),
+        consensus_worker.clone(),
+    ))
+    .or(outputs_ed25519::filter(
+        public_routes.clone(),
+        allowed_ips.clone(),
         consensus_worker,
     ))
     .or(peer::filter(
@@ -127,6 +140,12 @@ pub(crate) fn filter<B: StorageBackend>(
         allowed_ips.clone(),
         network_command_sender,
     ))
+    .or(storage_backup::filter(
+        public_routes.clone(),
+        allowed_ips.clone(),
+        storage.clone(),
+        rest_api_config.clone(),
+    ))
     .or(submit_message::filter(
         public_routes.clone(),
         allowed_ips.clone(),
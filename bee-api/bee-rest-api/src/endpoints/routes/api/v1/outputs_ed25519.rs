@@ -0,0 +1,99 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    endpoints::{
+        config::ROUTE_OUTPUTS_ED25519,
+        filters::with_consensus_worker,
+        path_params::ed25519_address,
+        permission::has_permission,
+        query_params::{address_role, page},
+        rejection::CustomRejection,
+    },
+    types::{body::SuccessBody, responses::OutputsAddressResponse},
+};
+
+use bee_ledger::{
+    types::{AddressRole, LedgerIndex},
+    workers::{consensus::ConsensusWorkerCommand, error::Error},
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    output::OutputId,
+};
+
+use futures::channel::oneshot;
+use log::error;
+use tokio::sync::mpsc;
+use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
+
+use std::net::IpAddr;
+
+fn path() -> impl Filter<Extract = (Ed25519Address,), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("addresses"))
+        .and(warp::path("ed25519"))
+        .and(ed25519_address())
+        .and(warp::path("outputs"))
+        .and(warp::path::end())
+}
+
+pub(crate) fn filter(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpAddr]>,
+    consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
+) -> BoxedFilter<(impl Reply,)> {
+    self::path()
+        .and(warp::get())
+        .and(has_permission(ROUTE_OUTPUTS_ED25519, public_routes, allowed_ips))
+        .and(address_role())
+        .and(page::<OutputId>())
+        .and(with_consensus_worker(consensus_worker))
+        .and_then(
+            |address: Ed25519Address, role, cursor, page_size, consensus_worker| async move {
+                outputs_address(Address::Ed25519(address), role, cursor, page_size, consensus_worker).await
+            },
+        )
+        .boxed()
+}
+
+/// Returns a page of the output ids of the outputs in which an address has the given role.
+pub(crate) async fn outputs_address(
+    address: Address,
+    role: AddressRole,
+    cursor: Option<OutputId>,
+    page_size: usize,
+    consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
+) -> Result<impl Reply, Rejection> {
+    let (cmd_tx, cmd_rx) = oneshot::channel::<(Result<(Vec<OutputId>, Option<OutputId>), Error>, LedgerIndex)>();
+
+    if let Err(e) = consensus_worker.send(ConsensusWorkerCommand::FetchOutputs(
+        (address, role),
+        cursor,
+        page_size,
+        cmd_tx,
+    )) {
+        error!("request to consensus worker failed: {}.", e);
+    }
+
+    match cmd_rx.await.map_err(|e| {
+        error!("response from consensus worker failed: {}.", e);
+        reject::custom(CustomRejection::ServiceUnavailable(
+            "unable to fetch the outputs of the address".to_string(),
+        ))
+    })? {
+        (Ok((output_ids, cursor)), ledger_index) => Ok(warp::reply::json(&SuccessBody::new(OutputsAddressResponse {
+            max_results: page_size,
+            count: output_ids.len(),
+            output_ids: output_ids.iter().map(|id| id.to_string()).collect(),
+            ledger_index: *ledger_index,
+            cursor: cursor.map(|cursor| cursor.to_string()),
+        }))),
+        (Err(e), _) => {
+            error!("unable to fetch the outputs of the address: {}", e);
+            Err(reject::custom(CustomRejection::ServiceUnavailable(
+                "unable to fetch the outputs of the address".to_string(),
+            )))
+        }
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    endpoints::{
+        config::{RestApiConfig, ROUTE_STORAGE_BACKUP},
+        filters::{with_rest_api_config, with_storage},
+        permission::has_permission,
+        rejection::CustomRejection,
+        storage::StorageBackend,
+    },
+    types::{body::SuccessBody, responses::StorageBackupResponse},
+};
+
+use bee_runtime::resource::ResourceHandle;
+
+use log::{error, info};
+use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
+
+use std::{
+    net::IpAddr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("storage"))
+        .and(warp::path("backup"))
+        .and(warp::path::end())
+}
+
+pub(crate) fn filter<B: StorageBackend>(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpAddr]>,
+    storage: ResourceHandle<B>,
+    rest_api_config: RestApiConfig,
+) -> BoxedFilter<(impl Reply,)> {
+    self::path()
+        .and(warp::post())
+        .and(has_permission(ROUTE_STORAGE_BACKUP, public_routes, allowed_ips))
+        .and(with_storage(storage))
+        .and(with_rest_api_config(rest_api_config))
+        .and_then(storage_backup)
+        .boxed()
+}
+
+pub(crate) async fn storage_backup<B: StorageBackend>(
+    storage: ResourceHandle<B>,
+    rest_api_config: RestApiConfig,
+) -> Result<impl Reply, Rejection> {
+    // Unwrapping is fine since the system time is after the epoch.
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+    let path = rest_api_config.backup_path().join(timestamp.to_string());
+
+    info!("Backing up the storage to {}.", path.display());
+
+    let backup_path = path.clone();
+    tokio::task::spawn_blocking(move || {
+        std::fs::create_dir_all(rest_api_config.backup_path()).map_err(|e| e.to_string())?;
+        storage.backup(&backup_path).map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|_| reject::custom(CustomRejection::InternalError))?
+    .map_err(|e| {
+        error!("Backing up the storage failed: {}.", e);
+        reject::custom(CustomRejection::ServiceUnavailable(format!(
+            "storage backup failed: {}",
+            e
+        )))
+    })?;
+
+    info!("Backed up the storage to {}.", path.display());
+
+    Ok(warp::reply::json(&SuccessBody::new(StorageBackupResponse {
+        path: path.to_string_lossy().into_owned(),
+    })))
+}
@@ -8,6 +8,7 @@ use crate::endpoints::{config::RestApiConfig, storage::StorageBackend, Bech32Hrp
 
 use bee_gossip::NetworkCommandSender;
 use bee_ledger::workers::consensus::ConsensusWorkerCommand;
+use bee_message::output::ByteCostConfig;
 use bee_protocol::workers::{
     config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
 };
@@ -30,6 +31,7 @@ pub(crate) fn filter_all<B: StorageBackend>(
     bech32_hrp: Bech32Hrp,
     rest_api_config: RestApiConfig,
     protocol_config: ProtocolConfig,
+    byte_cost_config: ByteCostConfig,
     peer_manager: ResourceHandle<PeerManager>,
     network_command_sender: ResourceHandle<NetworkCommandSender>,
     node_info: ResourceHandle<NodeInfo>,
@@ -48,6 +50,7 @@ pub(crate) fn filter_all<B: StorageBackend>(
         bech32_hrp,
         rest_api_config,
         protocol_config,
+        byte_cost_config,
         peer_manager.clone(),
         network_command_sender,
         node_info,
@@ -1,16 +1,17 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_ledger::types::{ConsumedOutput, OutputDiff, Receipt};
-use bee_message::{address::Ed25519Address, milestone::MilestoneIndex, output::OutputId};
+use bee_ledger::types::{AddressRole, ConsumedOutput, OutputDiff, Receipt};
+use bee_message::{address::Address, milestone::MilestoneIndex, output::OutputId};
 use bee_storage::{
     access::{AsIterator, Fetch},
-    backend,
+    backend::{self, StorageBackup},
 };
 
 pub trait StorageBackend:
     backend::StorageBackend
-    + Fetch<Ed25519Address, Vec<OutputId>>
+    + StorageBackup
+    + Fetch<(Address, AddressRole), Vec<OutputId>>
     + Fetch<MilestoneIndex, OutputDiff>
     + Fetch<MilestoneIndex, Vec<Receipt>>
     + Fetch<OutputId, ConsumedOutput>
@@ -22,7 +23,8 @@ pub trait StorageBackend:
 
 impl<T> StorageBackend for T where
     T: backend::StorageBackend
-        + Fetch<Ed25519Address, Vec<OutputId>>
+        + StorageBackup
+        + Fetch<(Address, AddressRole), Vec<OutputId>>
         + Fetch<MilestoneIndex, OutputDiff>
         + Fetch<MilestoneIndex, Vec<Receipt>>
         + Fetch<OutputId, ConsumedOutput>
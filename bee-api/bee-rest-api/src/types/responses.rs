@@ -95,7 +95,7 @@ pub struct MessageMetadataResponse {
 impl BodyInner for MessageMetadataResponse {}
 
 /// Response of GET /api/v1/messages/{message_id}/children.
-/// Returns all children of a specific message.
+/// Returns a page of the children of a specific message, along with the cursor of the next page, if any.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MessageChildrenResponse {
     #[serde(rename = "messageId")]
@@ -105,6 +105,8 @@ pub struct MessageChildrenResponse {
     pub count: usize,
     #[serde(rename = "childrenMessageIds")]
     pub children_message_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub cursor: Option<String>,
 }
 
 impl BodyInner for MessageChildrenResponse {}
@@ -147,7 +149,7 @@ pub struct BalanceAddressResponse {
 impl BodyInner for BalanceAddressResponse {}
 
 /// Response of GET /api/v1/addresses/{address}/outputs.
-/// Returns the outputs of an address.
+/// Returns a page of the outputs of an address, along with the cursor of the next page, if any.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OutputsAddressResponse {
     #[serde(rename = "maxResults")]
@@ -157,6 +159,8 @@ pub struct OutputsAddressResponse {
     pub output_ids: Vec<String>,
     #[serde(rename = "ledgerIndex", default)]
     pub ledger_index: u32,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub cursor: Option<String>,
 }
 
 impl BodyInner for OutputsAddressResponse {}
@@ -229,6 +233,15 @@ pub struct PeerResponse(pub PeerDto);
 
 impl BodyInner for PeerResponse {}
 
+/// Response of POST /api/v1/storage/backup.
+/// Returns the path of the created storage backup.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StorageBackupResponse {
+    pub path: String,
+}
+
+impl BodyInner for StorageBackupResponse {}
+
 /// Response of GET /api/plugins/debug/whiteflag.
 /// Returns the computed merkle tree hash for the given white flag traversal.
 #[derive(Clone, Debug, Serialize, Deserialize)]
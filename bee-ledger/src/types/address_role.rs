@@ -0,0 +1,33 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::types::Error;
+
+/// The role of an address in the unlock conditions of an output.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, packable::Packable)]
+#[packable(unpack_error = Error)]
+#[packable(tag_type = u8, with_error = Error::InvalidAddressRole)]
+pub enum AddressRole {
+    /// The address owns the output, either through an address unlock condition or as the controller of a foundry.
+    Address = 0,
+    /// The address gets the dust deposit of the output back through a dust deposit return unlock condition.
+    DustDepositReturn = 1,
+    /// The address gets the output back once it expired through an expiration unlock condition.
+    ExpirationReturn = 3,
+    /// The address is the state controller of an alias output.
+    StateController = 4,
+    /// The address is the governor of an alias output.
+    Governor = 5,
+}
+
+impl AddressRole {
+    /// All the roles an address can have.
+    pub const ALL: [AddressRole; 5] = [
+        AddressRole::Address,
+        AddressRole::DustDepositReturn,
+        AddressRole::ExpirationReturn,
+        AddressRole::StateController,
+        AddressRole::Governor,
+    ];
+}
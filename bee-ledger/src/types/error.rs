@@ -65,6 +65,9 @@ pub enum Error {
     /// Packable option error happened.
     #[error("packable option error happened")]
     PackableOption,
+    /// Invalid address role.
+    #[error("invalid address role: {0}")]
+    InvalidAddressRole(u8),
     /// Invalid snapshot kind.
     #[error("invalid snapshot kind: {0}")]
     InvalidSnapshotKind(u8),
@@ -0,0 +1,51 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::types::AddressRole;
+
+use bee_message::{
+    address::Address,
+    output::{feature_block::FeatureBlock, unlock_condition::UnlockCondition, Output},
+};
+
+/// Returns the addresses that can unlock an output or get it back, along with their role.
+pub fn output_addresses(output: &Output) -> Vec<(Address, AddressRole)> {
+    let unlock_conditions = match output {
+        Output::Treasury(_) => return Vec::new(),
+        Output::Basic(output) => output.unlock_conditions(),
+        Output::Alias(output) => output.unlock_conditions(),
+        Output::Foundry(output) => return vec![(*output.address(), AddressRole::Address)],
+        Output::Nft(output) => output.unlock_conditions(),
+    };
+
+    unlock_conditions
+        .iter()
+        .filter_map(|unlock_condition| match unlock_condition {
+            UnlockCondition::Address(unlock_condition) => Some((*unlock_condition.address(), AddressRole::Address)),
+            UnlockCondition::DustDepositReturn(unlock_condition) => {
+                Some((*unlock_condition.return_address(), AddressRole::DustDepositReturn))
+            }
+            UnlockCondition::Timelock(_) => None,
+            UnlockCondition::Expiration(unlock_condition) => {
+                Some((*unlock_condition.return_address(), AddressRole::ExpirationReturn))
+            }
+            UnlockCondition::StateControllerAddress(unlock_condition) => {
+                Some((*unlock_condition.address(), AddressRole::StateController))
+            }
+            UnlockCondition::GovernorAddress(unlock_condition) => {
+                Some((*unlock_condition.address(), AddressRole::Governor))
+            }
+        })
+        .collect()
+}
+
+/// Returns the feature blocks of an output, if it can hold any.
+pub fn output_feature_blocks(output: &Output) -> &[FeatureBlock] {
+    match output {
+        Output::Treasury(_) => &[],
+        Output::Basic(output) => output.feature_blocks(),
+        Output::Alias(output) => output.feature_blocks(),
+        Output::Foundry(output) => output.feature_blocks(),
+        Output::Nft(output) => output.feature_blocks(),
+    }
+}
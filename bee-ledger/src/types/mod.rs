@@ -5,9 +5,11 @@
 
 pub mod snapshot;
 
+mod address_role;
 mod consumed_output;
 mod created_output;
 mod error;
+mod indexation;
 mod ledger_index;
 mod migration;
 mod output_diff;
@@ -16,9 +18,11 @@ mod treasury_diff;
 mod treasury_output;
 mod unspent;
 
+pub use address_role::AddressRole;
 pub use consumed_output::ConsumedOutput;
 pub use created_output::CreatedOutput;
 pub use error::Error;
+pub use indexation::{output_addresses, output_feature_blocks};
 pub use ledger_index::LedgerIndex;
 pub use migration::Migration;
 pub use output_diff::OutputDiff;
@@ -32,6 +32,25 @@ pub struct MilestoneDiff {
 }
 
 impl MilestoneDiff {
+    /// Creates a new `MilestoneDiff`.
+    pub fn new(
+        milestone: MilestonePayload,
+        consumed_treasury: Option<(TreasuryOutput, MilestoneId)>,
+        created_outputs: HashMap<OutputId, CreatedOutput>,
+        consumed_outputs: HashMap<OutputId, (CreatedOutput, ConsumedOutput)>,
+    ) -> Result<Self, Error> {
+        if milestone.essence().receipt().is_some() && consumed_treasury.is_none() {
+            return Err(Error::MissingConsumedTreasury);
+        }
+
+        Ok(Self {
+            milestone,
+            consumed_treasury,
+            created_outputs,
+            consumed_outputs,
+        })
+    }
+
     /// Returns the milestone of a `MilestoneDiff`.
     pub fn milestone(&self) -> &MilestonePayload {
         &self.milestone
@@ -57,7 +76,7 @@ impl Packable for MilestoneDiff {
     type UnpackError = Error;
 
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
-        (self.milestone.packed_len() as u32 + MilestonePayload::KIND).pack(packer)?;
+        ((self.milestone.packed_len() + std::mem::size_of_val(&MilestonePayload::KIND)) as u32).pack(packer)?;
         MilestonePayload::KIND.pack(packer)?;
         self.milestone.pack(packer)?;
 
@@ -66,9 +85,8 @@ impl Packable for MilestoneDiff {
                 milestone_id.pack(packer)?;
                 treasury_output.pack(packer)?;
             } else {
-                // This never happens because `MilestoneDiff` values can only be created by
-                // unpacking them and the `unpack` implementation guarantees that the
-                // `consumed_treasury` field is some if the receipt is some.
+                // This never happens because both `MilestoneDiff::new` and the `unpack` implementation guarantee
+                // that the `consumed_treasury` field is some if the receipt is some.
                 unreachable!()
             }
         }
@@ -78,7 +96,7 @@ impl Packable for MilestoneDiff {
         for (output_id, created) in self.created_outputs.iter() {
             created.message_id().pack(packer)?;
             output_id.pack(packer)?;
-            created.pack(packer)?;
+            created.inner().pack(packer)?;
         }
 
         (self.consumed_outputs.len() as u64).pack(packer)?;
@@ -86,6 +104,8 @@ impl Packable for MilestoneDiff {
         for (output_id, (created, consumed)) in self.consumed_outputs.iter() {
             created.message_id().pack(packer)?;
             output_id.pack(packer)?;
+            created.milestone_index().pack(packer)?;
+            created.milestone_timestamp().pack(packer)?;
             created.inner().pack(packer)?;
             consumed.target().pack(packer)?;
         }
@@ -0,0 +1,643 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module containing the integrity check of the ledger and tangle tables.
+
+use crate::{
+    types::{
+        output_addresses, output_feature_blocks, AddressRole, ConsumedOutput, CreatedOutput, OutputDiff, Receipt,
+        TreasuryOutput, Unspent,
+    },
+    workers::{
+        error::Error,
+        storage::{self, StorageBackend},
+    },
+};
+
+use bee_message::{
+    address::Address,
+    constant::IOTA_SUPPLY,
+    milestone::MilestoneIndex,
+    output::{
+        feature_block::{FeatureBlock, SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, Output, OutputId,
+    },
+    payload::Payload,
+    Message, MessageId,
+};
+use bee_storage::access::{AsIterator, Batch, Exist, Fetch};
+
+use log::info;
+
+use std::fmt;
+
+/// The chain ID of an alias, NFT or foundry output, as a key of the chain ID index.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IndexedChainId {
+    /// The ID of an alias output.
+    Alias(AliasId),
+    /// The ID of an NFT output.
+    Nft(NftId),
+    /// The ID of a foundry output.
+    Foundry(FoundryId),
+}
+
+impl IndexedChainId {
+    /// Returns the chain ID of an output, if it has one.
+    fn from_output(output_id: &OutputId, output: &Output) -> Option<Self> {
+        match output {
+            Output::Alias(output) => Some(Self::Alias(output.alias_id().or_from_output_id(*output_id))),
+            Output::Nft(output) => Some(Self::Nft(output.nft_id().or_from_output_id(*output_id))),
+            Output::Foundry(output) => Some(Self::Foundry(output.id())),
+            _ => None,
+        }
+    }
+}
+
+/// An inconsistency found while checking the integrity of the storage.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Inconsistency {
+    /// An unspent output has no created output.
+    UnspentWithoutCreatedOutput(OutputId),
+    /// An unspent output has a consumed output.
+    UnspentWithConsumedOutput(OutputId),
+    /// An address index entry refers to an output that is not unspent or not owned by the address with this role.
+    DanglingAddressIndexEntry((Address, AddressRole), OutputId),
+    /// An unspent output is missing from the index of one of its addresses.
+    MissingAddressIndexEntry((Address, AddressRole), OutputId),
+    /// A chain ID index entry refers to an output that is not unspent or doesn't have the chain ID.
+    DanglingChainIdIndexEntry(IndexedChainId, OutputId),
+    /// The chain ID of an unspent output doesn't refer to it in the index.
+    MissingChainIdIndexEntry(IndexedChainId, OutputId),
+    /// A tag index entry refers to an output that is not unspent or doesn't have the tag.
+    DanglingTagIndexEntry(TagFeatureBlock, OutputId),
+    /// An unspent output is missing from the index of its tag.
+    MissingTagIndexEntry(TagFeatureBlock, OutputId),
+    /// A sender index entry refers to an output that is not unspent or doesn't have the sender.
+    DanglingSenderIndexEntry(SenderFeatureBlock, OutputId),
+    /// An unspent output is missing from the index of its sender.
+    MissingSenderIndexEntry(SenderFeatureBlock, OutputId),
+    /// A message edge refers to a child message that is not stored or that doesn't reference the parent.
+    DanglingMessageEdge(MessageId, MessageId),
+    /// There is not exactly one unspent treasury output.
+    InvalidUnspentTreasuryOutputCount(usize),
+    /// The sum of the unspent amounts and the treasury doesn't match the total supply.
+    SupplyMismatch(u64),
+    /// The receipts included in a milestone don't match the treasury transition of its output diff.
+    ReceiptTreasuryMismatch(MilestoneIndex),
+    /// The ledger index doesn't match the index of the last output diff.
+    LedgerIndexMismatch(Option<MilestoneIndex>, MilestoneIndex),
+}
+
+impl Inconsistency {
+    /// Returns whether the inconsistency lies in a secondary index and can be repaired.
+    pub fn is_repairable(&self) -> bool {
+        matches!(
+            self,
+            Self::DanglingAddressIndexEntry(..)
+                | Self::MissingAddressIndexEntry(..)
+                | Self::DanglingChainIdIndexEntry(..)
+                | Self::MissingChainIdIndexEntry(..)
+                | Self::DanglingTagIndexEntry(..)
+                | Self::MissingTagIndexEntry(..)
+                | Self::DanglingSenderIndexEntry(..)
+                | Self::MissingSenderIndexEntry(..)
+                | Self::DanglingMessageEdge(..)
+        )
+    }
+}
+
+impl fmt::Display for Inconsistency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnspentWithoutCreatedOutput(output_id) => {
+                write!(f, "unspent output {} has no created output", output_id)
+            }
+            Self::UnspentWithConsumedOutput(output_id) => {
+                write!(f, "unspent output {} has a consumed output", output_id)
+            }
+            Self::DanglingAddressIndexEntry((address, role), output_id) => write!(
+                f,
+                "address index entry {:?} ({:?}) refers to an invalid output {}",
+                address, role, output_id
+            ),
+            Self::MissingAddressIndexEntry((address, role), output_id) => write!(
+                f,
+                "unspent output {} is missing from the index of address {:?} ({:?})",
+                output_id, address, role
+            ),
+            Self::DanglingChainIdIndexEntry(chain_id, output_id) => write!(
+                f,
+                "chain ID index entry {:?} refers to an invalid output {}",
+                chain_id, output_id
+            ),
+            Self::MissingChainIdIndexEntry(chain_id, output_id) => write!(
+                f,
+                "unspent output {} is missing from the index of chain ID {:?}",
+                output_id, chain_id
+            ),
+            Self::DanglingTagIndexEntry(tag, output_id) => {
+                write!(f, "tag index entry {} refers to an invalid output {}", tag, output_id)
+            }
+            Self::MissingTagIndexEntry(tag, output_id) => {
+                write!(
+                    f,
+                    "unspent output {} is missing from the index of tag {}",
+                    output_id, tag
+                )
+            }
+            Self::DanglingSenderIndexEntry(sender, output_id) => write!(
+                f,
+                "sender index entry {:?} refers to an invalid output {}",
+                sender.address(),
+                output_id
+            ),
+            Self::MissingSenderIndexEntry(sender, output_id) => write!(
+                f,
+                "unspent output {} is missing from the index of sender {:?}",
+                output_id,
+                sender.address()
+            ),
+            Self::DanglingMessageEdge(parent, child) => {
+                write!(f, "message edge {} -> {} refers to an invalid child", parent, child)
+            }
+            Self::InvalidUnspentTreasuryOutputCount(count) => {
+                write!(f, "found {} unspent treasury outputs instead of one", count)
+            }
+            Self::SupplyMismatch(supply) => {
+                write!(
+                    f,
+                    "ledger supply {} doesn't match the total supply {}",
+                    supply, IOTA_SUPPLY
+                )
+            }
+            Self::ReceiptTreasuryMismatch(index) => write!(
+                f,
+                "receipts included in milestone {} don't match its treasury transition",
+                index
+            ),
+            Self::LedgerIndexMismatch(Some(ledger_index), last_diff_index) => write!(
+                f,
+                "ledger index {} doesn't match the index of the last output diff {}",
+                ledger_index, last_diff_index
+            ),
+            Self::LedgerIndexMismatch(None, last_diff_index) => {
+                write!(
+                    f,
+                    "ledger index is missing, the last output diff is at {}",
+                    last_diff_index
+                )
+            }
+        }
+    }
+}
+
+/// Report of an integrity check of the storage.
+#[derive(Debug, Default)]
+pub struct CheckReport {
+    inconsistencies: Vec<Inconsistency>,
+    repaired: usize,
+}
+
+impl CheckReport {
+    /// Returns the inconsistencies found by the check.
+    pub fn inconsistencies(&self) -> &[Inconsistency] {
+        &self.inconsistencies
+    }
+
+    /// Returns the number of repaired inconsistencies.
+    pub fn repaired(&self) -> usize {
+        self.repaired
+    }
+}
+
+/// Returns the created output of an output that is still unspent.
+fn fetch_unspent_output<B: StorageBackend>(storage: &B, output_id: &OutputId) -> Result<Option<CreatedOutput>, Error> {
+    if storage::is_output_unspent(storage, output_id)? {
+        storage::fetch_output(storage, output_id)
+    } else {
+        Ok(None)
+    }
+}
+
+/// The optional indexes that are filled, and therefore expected to be complete.
+struct FilledIndexes {
+    tag: bool,
+    sender: bool,
+}
+
+impl FilledIndexes {
+    /// Disabled indexes are truncated at startup, so an empty index is considered disabled.
+    fn new<B: StorageBackend>(storage: &B) -> Result<Self, Error> {
+        Ok(Self {
+            tag: AsIterator::<(TagFeatureBlock, OutputId), ()>::iter(storage)
+                .map_err(|e| Error::Storage(Box::new(e)))?
+                .next()
+                .is_some(),
+            sender: AsIterator::<(SenderFeatureBlock, OutputId), ()>::iter(storage)
+                .map_err(|e| Error::Storage(Box::new(e)))?
+                .next()
+                .is_some(),
+        })
+    }
+}
+
+/// Checks the unspent outputs against the created and consumed outputs and the indexes, and the total supply.
+fn check_unspent_outputs<B: StorageBackend>(
+    storage: &B,
+    batch: &mut B::Batch,
+    repair: bool,
+    filled_indexes: &FilledIndexes,
+    report: &mut CheckReport,
+) -> Result<(), Error> {
+    let mut supply = 0u64;
+
+    for result in AsIterator::<Unspent, ()>::iter(storage).map_err(|e| Error::Storage(Box::new(e)))? {
+        let (unspent, ()) = result.map_err(|e| Error::Storage(Box::new(e)))?;
+        let output_id = *unspent.id();
+
+        if Exist::<OutputId, ConsumedOutput>::exist(storage, &output_id).map_err(|e| Error::Storage(Box::new(e)))? {
+            report
+                .inconsistencies
+                .push(Inconsistency::UnspentWithConsumedOutput(output_id));
+        }
+
+        let output = match storage::fetch_output(storage, &output_id)? {
+            Some(output) => output,
+            None => {
+                report
+                    .inconsistencies
+                    .push(Inconsistency::UnspentWithoutCreatedOutput(output_id));
+                continue;
+            }
+        };
+
+        supply = supply.saturating_add(output.inner().amount());
+
+        for key in output_addresses(output.inner()) {
+            let entry = (key, output_id);
+
+            if !Exist::<((Address, AddressRole), OutputId), ()>::exist(storage, &entry)
+                .map_err(|e| Error::Storage(Box::new(e)))?
+            {
+                if repair {
+                    Batch::<((Address, AddressRole), OutputId), ()>::batch_insert(storage, batch, &entry, &())
+                        .map_err(|e| Error::Storage(Box::new(e)))?;
+                    report.repaired += 1;
+                }
+                report
+                    .inconsistencies
+                    .push(Inconsistency::MissingAddressIndexEntry(entry.0, entry.1));
+            }
+        }
+
+        if let Some(chain_id) = IndexedChainId::from_output(&output_id, output.inner()) {
+            let indexed_output_id = match chain_id {
+                IndexedChainId::Alias(alias_id) => Fetch::<AliasId, OutputId>::fetch(storage, &alias_id),
+                IndexedChainId::Nft(nft_id) => Fetch::<NftId, OutputId>::fetch(storage, &nft_id),
+                IndexedChainId::Foundry(foundry_id) => Fetch::<FoundryId, OutputId>::fetch(storage, &foundry_id),
+            }
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+
+            if indexed_output_id != Some(output_id) {
+                if repair {
+                    storage::insert_chain_output_id_batch(storage, batch, &output_id, output.inner())?;
+                    report.repaired += 1;
+                }
+                report
+                    .inconsistencies
+                    .push(Inconsistency::MissingChainIdIndexEntry(chain_id, output_id));
+            }
+        }
+
+        for feature_block in output_feature_blocks(output.inner()) {
+            match feature_block {
+                FeatureBlock::Tag(tag) if filled_indexes.tag => {
+                    let entry = (tag.clone(), output_id);
+
+                    if !Exist::<(TagFeatureBlock, OutputId), ()>::exist(storage, &entry)
+                        .map_err(|e| Error::Storage(Box::new(e)))?
+                    {
+                        if repair {
+                            Batch::<(TagFeatureBlock, OutputId), ()>::batch_insert(storage, batch, &entry, &())
+                                .map_err(|e| Error::Storage(Box::new(e)))?;
+                            report.repaired += 1;
+                        }
+                        report
+                            .inconsistencies
+                            .push(Inconsistency::MissingTagIndexEntry(entry.0, entry.1));
+                    }
+                }
+                FeatureBlock::Sender(sender) if filled_indexes.sender => {
+                    let entry = (sender.clone(), output_id);
+
+                    if !Exist::<(SenderFeatureBlock, OutputId), ()>::exist(storage, &entry)
+                        .map_err(|e| Error::Storage(Box::new(e)))?
+                    {
+                        if repair {
+                            Batch::<(SenderFeatureBlock, OutputId), ()>::batch_insert(storage, batch, &entry, &())
+                                .map_err(|e| Error::Storage(Box::new(e)))?;
+                            report.repaired += 1;
+                        }
+                        report
+                            .inconsistencies
+                            .push(Inconsistency::MissingSenderIndexEntry(entry.0, entry.1));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let treasury_outputs = Fetch::<bool, Vec<TreasuryOutput>>::fetch(storage, &false)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .unwrap_or_default();
+
+    if treasury_outputs.len() != 1 {
+        report
+            .inconsistencies
+            .push(Inconsistency::InvalidUnspentTreasuryOutputCount(treasury_outputs.len()));
+    }
+
+    for treasury_output in treasury_outputs.iter() {
+        supply = supply.saturating_add(treasury_output.inner().amount());
+    }
+
+    if supply != IOTA_SUPPLY {
+        report.inconsistencies.push(Inconsistency::SupplyMismatch(supply));
+    }
+
+    Ok(())
+}
+
+/// Checks that every address index entry refers to an unspent output owned by the address with this role.
+fn check_address_index<B: StorageBackend>(
+    storage: &B,
+    batch: &mut B::Batch,
+    repair: bool,
+    report: &mut CheckReport,
+) -> Result<(), Error> {
+    for result in
+        AsIterator::<((Address, AddressRole), OutputId), ()>::iter(storage).map_err(|e| Error::Storage(Box::new(e)))?
+    {
+        let (entry, ()) = result.map_err(|e| Error::Storage(Box::new(e)))?;
+
+        let valid = matches!(
+            fetch_unspent_output(storage, &entry.1)?,
+            Some(output) if output_addresses(output.inner()).contains(&entry.0)
+        );
+
+        if !valid {
+            if repair {
+                Batch::<((Address, AddressRole), OutputId), ()>::batch_delete(storage, batch, &entry)
+                    .map_err(|e| Error::Storage(Box::new(e)))?;
+                report.repaired += 1;
+            }
+            report
+                .inconsistencies
+                .push(Inconsistency::DanglingAddressIndexEntry(entry.0, entry.1));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that every entry of one of the chain ID index tables refers to an unspent output with this chain ID.
+fn check_chain_id_index_table<B, K>(
+    storage: &B,
+    batch: &mut B::Batch,
+    repair: bool,
+    report: &mut CheckReport,
+    chain_id: impl Fn(K) -> IndexedChainId,
+) -> Result<(), Error>
+where
+    B: StorageBackend + for<'a> AsIterator<'a, K, OutputId> + Batch<K, OutputId>,
+    K: Copy,
+{
+    for result in AsIterator::<K, OutputId>::iter(storage).map_err(|e| Error::Storage(Box::new(e)))? {
+        let (key, output_id) = result.map_err(|e| Error::Storage(Box::new(e)))?;
+        let chain_id = chain_id(key);
+
+        let valid = matches!(
+            fetch_unspent_output(storage, &output_id)?,
+            Some(output) if IndexedChainId::from_output(&output_id, output.inner()) == Some(chain_id)
+        );
+
+        if !valid {
+            if repair {
+                Batch::<K, OutputId>::batch_delete(storage, batch, &key).map_err(|e| Error::Storage(Box::new(e)))?;
+                report.repaired += 1;
+            }
+            report
+                .inconsistencies
+                .push(Inconsistency::DanglingChainIdIndexEntry(chain_id, output_id));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that every chain ID index entry refers to an unspent output with this chain ID.
+fn check_chain_id_index<B: StorageBackend>(
+    storage: &B,
+    batch: &mut B::Batch,
+    repair: bool,
+    report: &mut CheckReport,
+) -> Result<(), Error> {
+    check_chain_id_index_table::<B, AliasId>(storage, batch, repair, report, IndexedChainId::Alias)?;
+    check_chain_id_index_table::<B, NftId>(storage, batch, repair, report, IndexedChainId::Nft)?;
+    check_chain_id_index_table::<B, FoundryId>(storage, batch, repair, report, IndexedChainId::Foundry)
+}
+
+/// Checks that every tag and sender index entry refers to an unspent output with this feature block.
+fn check_feature_block_indexes<B: StorageBackend>(
+    storage: &B,
+    batch: &mut B::Batch,
+    repair: bool,
+    report: &mut CheckReport,
+) -> Result<(), Error> {
+    for result in
+        AsIterator::<(TagFeatureBlock, OutputId), ()>::iter(storage).map_err(|e| Error::Storage(Box::new(e)))?
+    {
+        let (entry, ()) = result.map_err(|e| Error::Storage(Box::new(e)))?;
+
+        let valid = matches!(
+            fetch_unspent_output(storage, &entry.1)?,
+            Some(output) if output_feature_blocks(output.inner()).contains(&FeatureBlock::Tag(entry.0.clone()))
+        );
+
+        if !valid {
+            if repair {
+                Batch::<(TagFeatureBlock, OutputId), ()>::batch_delete(storage, batch, &entry)
+                    .map_err(|e| Error::Storage(Box::new(e)))?;
+                report.repaired += 1;
+            }
+            report
+                .inconsistencies
+                .push(Inconsistency::DanglingTagIndexEntry(entry.0, entry.1));
+        }
+    }
+
+    for result in
+        AsIterator::<(SenderFeatureBlock, OutputId), ()>::iter(storage).map_err(|e| Error::Storage(Box::new(e)))?
+    {
+        let (entry, ()) = result.map_err(|e| Error::Storage(Box::new(e)))?;
+
+        let valid = matches!(
+            fetch_unspent_output(storage, &entry.1)?,
+            Some(output) if output_feature_blocks(output.inner()).contains(&FeatureBlock::Sender(entry.0.clone()))
+        );
+
+        if !valid {
+            if repair {
+                Batch::<(SenderFeatureBlock, OutputId), ()>::batch_delete(storage, batch, &entry)
+                    .map_err(|e| Error::Storage(Box::new(e)))?;
+                report.repaired += 1;
+            }
+            report
+                .inconsistencies
+                .push(Inconsistency::DanglingSenderIndexEntry(entry.0, entry.1));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that every message edge refers to a stored child message referencing the parent.
+/// Parents are not required to be stored since solid entry points and pruned messages keep their edges to the children.
+fn check_message_edges<B: StorageBackend>(
+    storage: &B,
+    batch: &mut B::Batch,
+    repair: bool,
+    report: &mut CheckReport,
+) -> Result<(), Error> {
+    for result in AsIterator::<(MessageId, MessageId), ()>::iter(storage).map_err(|e| Error::Storage(Box::new(e)))? {
+        let ((parent, child), ()) = result.map_err(|e| Error::Storage(Box::new(e)))?;
+
+        let valid = matches!(
+            Fetch::<MessageId, Message>::fetch(storage, &child).map_err(|e| Error::Storage(Box::new(e)))?,
+            Some(message) if message.parents().iter().any(|id| *id == parent)
+        );
+
+        if !valid {
+            if repair {
+                Batch::<(MessageId, MessageId), ()>::batch_delete(storage, batch, &(parent, child))
+                    .map_err(|e| Error::Storage(Box::new(e)))?;
+                report.repaired += 1;
+            }
+            report
+                .inconsistencies
+                .push(Inconsistency::DanglingMessageEdge(parent, child));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns whether the receipts included in a milestone match the treasury transition of its output diff.
+///
+/// A milestone without treasury diff includes no receipt, and a milestone with a treasury diff includes exactly one
+/// receipt, which validly consumes the treasury output consumed by the diff and creates the one created by the diff.
+pub(crate) fn receipts_match_treasury_diff<B: StorageBackend>(
+    storage: &B,
+    output_diff: &OutputDiff,
+    receipts: &[Receipt],
+) -> Result<bool, Error> {
+    let (treasury_diff, receipt) = match (output_diff.treasury_diff(), receipts) {
+        (None, []) => return Ok(true),
+        (Some(treasury_diff), [receipt]) => (treasury_diff, receipt),
+        _ => return Ok(false),
+    };
+
+    let (consumed_treasury, created_treasury) = match (
+        storage::fetch_treasury_output(storage, treasury_diff.consumed())?,
+        storage::fetch_treasury_output(storage, treasury_diff.created())?,
+    ) {
+        (Some(consumed_treasury), Some(created_treasury)) => (consumed_treasury, created_treasury),
+        _ => return Ok(false),
+    };
+
+    Ok(receipt.validate(&consumed_treasury).is_ok()
+        && matches!(
+            receipt.inner().transaction(),
+            Payload::TreasuryTransaction(transaction)
+                if matches!(transaction.output(), Output::Treasury(output) if output == created_treasury.inner())
+        ))
+}
+
+/// Checks that the receipts included in every milestone that still has an output diff match its treasury transition.
+/// Receipts of pruned milestones are not checked since they may be kept without their output diff.
+fn check_receipts<B: StorageBackend>(storage: &B, report: &mut CheckReport) -> Result<(), Error> {
+    let mut receipts = storage::fetch_receipts_by_milestone(storage)?;
+
+    for result in AsIterator::<MilestoneIndex, OutputDiff>::iter(storage).map_err(|e| Error::Storage(Box::new(e)))? {
+        let (index, output_diff) = result.map_err(|e| Error::Storage(Box::new(e)))?;
+
+        if !receipts_match_treasury_diff(storage, &output_diff, &receipts.remove(&index).unwrap_or_default())? {
+            report
+                .inconsistencies
+                .push(Inconsistency::ReceiptTreasuryMismatch(index));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the ledger index matches the index of the last output diff, if any.
+fn check_ledger_index<B: StorageBackend>(storage: &B, report: &mut CheckReport) -> Result<(), Error> {
+    let mut last_diff_index = None;
+
+    for result in AsIterator::<MilestoneIndex, OutputDiff>::iter(storage).map_err(|e| Error::Storage(Box::new(e)))? {
+        let (index, _) = result.map_err(|e| Error::Storage(Box::new(e)))?;
+
+        last_diff_index = last_diff_index.max(Some(index));
+    }
+
+    let ledger_index = storage::fetch_ledger_index(storage)?.map(|index| MilestoneIndex(*index));
+
+    if let Some(last_diff_index) = last_diff_index {
+        if ledger_index != Some(last_diff_index) {
+            report
+                .inconsistencies
+                .push(Inconsistency::LedgerIndexMismatch(ledger_index, last_diff_index));
+        }
+    }
+
+    Ok(())
+}
+
+/// Cross-checks the ledger and tangle tables of the storage and reports the inconsistencies.
+///
+/// If `repair` is set, the inconsistencies of the secondary indexes are repaired: dangling index entries and message
+/// edges are deleted and missing index entries are inserted. The tag and sender indexes are only checked for missing
+/// entries when they are not empty, since they are then enabled.
+pub fn check_storage<B: StorageBackend>(storage: &B, repair: bool) -> Result<CheckReport, Error> {
+    let mut report = CheckReport::default();
+    let mut batch = B::batch_begin();
+    let filled_indexes = FilledIndexes::new(storage)?;
+
+    // Dangling index entries are deleted before inserting the missing ones, since a missing chain ID index entry
+    // replaces the dangling entry of the same chain ID.
+    info!("Checking address index...");
+    check_address_index(storage, &mut batch, repair, &mut report)?;
+    info!("Checking chain ID index...");
+    check_chain_id_index(storage, &mut batch, repair, &mut report)?;
+    info!("Checking tag and sender indexes...");
+    check_feature_block_indexes(storage, &mut batch, repair, &mut report)?;
+    info!("Checking unspent outputs...");
+    check_unspent_outputs(storage, &mut batch, repair, &filled_indexes, &mut report)?;
+    info!("Checking message edges...");
+    check_message_edges(storage, &mut batch, repair, &mut report)?;
+    info!("Checking receipts...");
+    check_receipts(storage, &mut report)?;
+    info!("Checking ledger index...");
+    check_ledger_index(storage, &mut report)?;
+
+    if report.repaired > 0 {
+        storage
+            .batch_commit(batch, true)
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    Ok(report)
+}
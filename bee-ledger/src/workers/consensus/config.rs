@@ -0,0 +1,84 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module containing consensus configuration.
+
+use bee_message::{
+    output::{ByteCostConfig, ByteCostConfigBuilder},
+    Error,
+};
+
+use serde::Deserialize;
+
+/// Builder for a [`ConsensusConfig`].
+#[derive(Default, Deserialize)]
+#[must_use]
+pub struct ConsensusConfigBuilder {
+    byte_cost: Option<u64>,
+    byte_factor_key: Option<u64>,
+    byte_factor_data: Option<u64>,
+}
+
+impl ConsensusConfigBuilder {
+    /// Creates a new [`ConsensusConfigBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the byte cost used to compute the minimum deposit of outputs.
+    pub fn byte_cost(mut self, byte_cost: u64) -> Self {
+        self.byte_cost.replace(byte_cost);
+        self
+    }
+
+    /// Sets the virtual byte weight of key fields used to compute the minimum deposit of outputs.
+    pub fn byte_factor_key(mut self, byte_factor_key: u64) -> Self {
+        self.byte_factor_key.replace(byte_factor_key);
+        self
+    }
+
+    /// Sets the virtual byte weight of data fields used to compute the minimum deposit of outputs.
+    pub fn byte_factor_data(mut self, byte_factor_data: u64) -> Self {
+        self.byte_factor_data.replace(byte_factor_data);
+        self
+    }
+
+    /// Finishes the builder into a [`ConsensusConfig`].
+    ///
+    /// Fails if the byte cost parameters would overflow the computation of the minimum deposit of outputs.
+    pub fn finish(self) -> Result<ConsensusConfig, Error> {
+        let mut byte_cost_builder = ByteCostConfigBuilder::new();
+
+        if let Some(byte_cost) = self.byte_cost {
+            byte_cost_builder = byte_cost_builder.byte_cost(byte_cost);
+        }
+        if let Some(byte_factor_key) = self.byte_factor_key {
+            byte_cost_builder = byte_cost_builder.key_factor(byte_factor_key);
+        }
+        if let Some(byte_factor_data) = self.byte_factor_data {
+            byte_cost_builder = byte_cost_builder.data_factor(byte_factor_data);
+        }
+
+        Ok(ConsensusConfig {
+            byte_cost: byte_cost_builder.finish()?,
+        })
+    }
+}
+
+/// The consensus configuration.
+#[derive(Clone)]
+pub struct ConsensusConfig {
+    byte_cost: ByteCostConfig,
+}
+
+impl ConsensusConfig {
+    /// Returns a builder to create a [`ConsensusConfig`].
+    pub fn build() -> ConsensusConfigBuilder {
+        ConsensusConfigBuilder::new()
+    }
+
+    /// Returns the byte cost configuration used to compute the minimum deposit of outputs.
+    pub fn byte_cost(&self) -> &ByteCostConfig {
+        &self.byte_cost
+    }
+}
@@ -3,7 +3,11 @@
 
 use crate::types::{ConsumedOutput, CreatedOutput};
 
-use bee_message::{milestone::MilestoneIndex, output::OutputId, MessageId};
+use bee_message::{
+    milestone::MilestoneIndex,
+    output::{ByteCostConfig, OutputId},
+    MessageId,
+};
 use bee_tangle::ConflictReason;
 
 use std::collections::HashMap;
@@ -14,6 +18,8 @@ pub struct WhiteFlagMetadata {
     pub(crate) milestone_index: MilestoneIndex,
     /// Timestamp of the confirmed milestone.
     pub(crate) milestone_timestamp: u64,
+    /// The byte cost configuration used to compute the minimum deposit of created outputs.
+    pub(crate) byte_cost_config: ByteCostConfig,
     /// The number of messages which were referenced by the confirmed milestone.
     pub(crate) referenced_messages: usize,
     /// The messages which were excluded because they did not include a transaction.
@@ -32,10 +38,15 @@ pub struct WhiteFlagMetadata {
 
 impl WhiteFlagMetadata {
     /// Creates a new [`WhiteFlagMetadata`].
-    pub fn new(milestone_index: MilestoneIndex, milestone_timestamp: u64) -> WhiteFlagMetadata {
+    pub fn new(
+        milestone_index: MilestoneIndex,
+        milestone_timestamp: u64,
+        byte_cost_config: ByteCostConfig,
+    ) -> WhiteFlagMetadata {
         WhiteFlagMetadata {
             milestone_index,
             milestone_timestamp,
+            byte_cost_config,
             referenced_messages: 0,
             excluded_no_transaction_messages: Vec::new(),
             excluded_conflicting_messages: Vec::new(),
@@ -46,6 +57,16 @@ impl WhiteFlagMetadata {
         }
     }
 
+    /// Returns the messages which were excluded because they were conflicting with the ledger state.
+    pub fn excluded_conflicting_messages(&self) -> &[(MessageId, ConflictReason)] {
+        &self.excluded_conflicting_messages
+    }
+
+    /// Returns the messages which mutate the ledger in the order in which they were applied.
+    pub fn included_messages(&self) -> &[MessageId] {
+        &self.included_messages
+    }
+
     /// Returns the merkle proof of a [`WhiteFlagMetadata`].
     pub fn merkle_proof(&self) -> &[u8] {
         &self.merkle_proof
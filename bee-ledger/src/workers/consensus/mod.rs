@@ -9,6 +9,8 @@ pub(crate) mod state;
 pub(crate) mod white_flag;
 pub(crate) mod worker;
 
+pub mod config;
+
 pub use metadata::WhiteFlagMetadata;
 pub use white_flag::white_flag;
 pub use worker::{ConsensusWorker, ConsensusWorkerCommand};
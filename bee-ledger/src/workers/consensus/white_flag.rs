@@ -11,44 +11,477 @@ use crate::{
 };
 
 use bee_message::{
+    address::{Address, AliasAddress, NftAddress},
     input::Input,
-    output::{Output, OutputId},
+    milestone::MilestoneIndex,
+    output::{
+        AliasId, AliasOutput, FeatureBlock, FoundryOutput, NativeToken, Output, OutputId, TokenId, UnlockCondition,
+    },
     payload::{
         transaction::{RegularTransactionEssence, TransactionEssence, TransactionId, TransactionPayload},
         Payload,
     },
-    unlock_block::UnlockBlocks,
+    signature::Signature,
+    unlock_block::{UnlockBlock, UnlockBlocks},
     Message, MessageId,
 };
 use bee_tangle::{ConflictReason, Tangle};
 
 use crypto::hashes::blake2b::Blake2b256;
+use primitive_types::U256;
 
 use std::collections::{HashMap, HashSet};
 
-// TODO
-// fn _verify_signature(_address: &Address, unlock_blocks: &UnlockBlocks, index: usize, _essence_hash: &[u8; 32]) ->
-// bool {     if let Some(UnlockBlock::Signature(_signature)) = unlock_blocks.get(index) {
-//         true
-//         // address.verify(essence_hash, signature).is_ok()
-//     } else {
-//         false
-//     }
-// }
+// Transitions an alias output can undergo within a transaction.
+enum AliasTransition {
+    State,
+    Governance,
+    Destruction,
+}
+
+fn alias_transition(alias_id: &AliasId, current: &AliasOutput, created_outputs: &[Output]) -> AliasTransition {
+    let next = created_outputs.iter().find_map(|output| match output {
+        Output::Alias(output) if output.alias_id() == alias_id => Some(output),
+        _ => None,
+    });
+
+    match next {
+        Some(next) if current.state_index().checked_add(1) == Some(next.state_index()) => AliasTransition::State,
+        Some(_) => AliasTransition::Governance,
+        None => AliasTransition::Destruction,
+    }
+}
+
+fn issuer(feature_blocks: &[FeatureBlock]) -> Option<&FeatureBlock> {
+    feature_blocks
+        .iter()
+        .find(|feature_block| matches!(feature_block, FeatureBlock::Issuer(_)))
+}
+
+fn verify_alias_transition(current: &AliasOutput, next: &AliasOutput) -> Result<(), ConflictReason> {
+    if current.state_index().checked_add(1) == Some(next.state_index()) {
+        // A state transition can't change the controllers nor the feature blocks, and can't decrease the foundry
+        // counter.
+        if current.unlock_conditions() != next.unlock_conditions()
+            || current.feature_blocks() != next.feature_blocks()
+            || current.foundry_counter() > next.foundry_counter()
+        {
+            return Err(ConflictReason::InvalidAliasStateTransition);
+        }
+    } else if current.state_index() == next.state_index() {
+        // A governance transition can only change the controllers and the feature blocks, except for the issuer.
+        if current.amount() != next.amount()
+            || current.native_tokens() != next.native_tokens()
+            || current.state_metadata() != next.state_metadata()
+            || current.foundry_counter() != next.foundry_counter()
+            || issuer(current.feature_blocks()) != issuer(next.feature_blocks())
+        {
+            return Err(ConflictReason::InvalidAliasGovernanceTransition);
+        }
+    } else {
+        return Err(ConflictReason::InvalidAliasStateIndex);
+    }
+
+    Ok(())
+}
+
+// Checks that every created alias output is either a new alias or a valid transition of a consumed one.
+fn verify_alias_transitions(
+    consumed_outputs: &[(OutputId, CreatedOutput)],
+    created_outputs: &[Output],
+) -> Result<(), ConflictReason> {
+    let consumed_aliases = consumed_outputs
+        .iter()
+        .filter_map(|(output_id, output)| match output.inner() {
+            Output::Alias(output) => Some((output.alias_id().or_from_output_id(*output_id), output)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    let mut created_aliases = HashSet::new();
+
+    for created_output in created_outputs {
+        if let Output::Alias(next) = created_output {
+            // A new alias has a null ID, its actual ID is derived from its output ID.
+            if next.alias_id().is_null() {
+                // A new alias starts from a zero state index and foundry counter.
+                if next.state_index() != 0 || next.foundry_counter() != 0 {
+                    return Err(ConflictReason::InvalidAliasCreation);
+                }
+
+                continue;
+            }
+
+            // An alias can only be transitioned once, any other output with its ID would be a copy.
+            if !created_aliases.insert(next.alias_id()) {
+                return Err(ConflictReason::InvalidAliasCreation);
+            }
+
+            match consumed_aliases.get(next.alias_id()) {
+                Some(current) => verify_alias_transition(current, next)?,
+                None => return Err(ConflictReason::InvalidAliasCreation),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Checks that new foundries are only created by a state transition of their alias, which increases its foundry counter
+// by the number of new foundries and gives them the serial numbers in between.
+fn verify_foundry_creations(
+    consumed_outputs: &[(OutputId, CreatedOutput)],
+    created_outputs: &[Output],
+) -> Result<(), ConflictReason> {
+    let consumed_foundries = consumed_outputs
+        .iter()
+        .filter_map(|(_, output)| match output.inner() {
+            Output::Foundry(output) => Some(output.id()),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+    // Serial numbers of the new foundries, indexed by the alias controlling them.
+    let mut new_foundries = HashMap::<AliasId, HashSet<u32>>::new();
+
+    for created_output in created_outputs {
+        if let Output::Foundry(output) = created_output {
+            if consumed_foundries.contains(&output.id()) {
+                continue;
+            }
+
+            match output.address() {
+                Address::Alias(address) => {
+                    if !new_foundries
+                        .entry(*address.id())
+                        .or_default()
+                        .insert(output.serial_number())
+                    {
+                        return Err(ConflictReason::InvalidFoundryCreation);
+                    }
+                }
+                _ => return Err(ConflictReason::InvalidFoundryCreation),
+            }
+        }
+    }
+
+    let consumed_aliases = consumed_outputs
+        .iter()
+        .filter_map(|(output_id, output)| match output.inner() {
+            Output::Alias(output) => Some((output.alias_id().or_from_output_id(*output_id), output)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    for created_output in created_outputs {
+        if let Output::Alias(next) = created_output {
+            // New aliases have a zero foundry counter and can't have foundries yet.
+            let current = match consumed_aliases.get(next.alias_id()) {
+                Some(current) => current,
+                None => continue,
+            };
+            let serial_numbers = new_foundries.remove(next.alias_id()).unwrap_or_default();
+
+            if next.foundry_counter().checked_sub(current.foundry_counter()) != Some(serial_numbers.len() as u32)
+                || serial_numbers.iter().any(|serial_number| {
+                    *serial_number <= current.foundry_counter() || *serial_number > next.foundry_counter()
+                })
+            {
+                return Err(ConflictReason::InvalidFoundryCreation);
+            }
+        }
+    }
+
+    // The remaining foundries are controlled by aliases that are not transitioned.
+    if !new_foundries.is_empty() {
+        return Err(ConflictReason::InvalidFoundryCreation);
+    }
+
+    Ok(())
+}
+
+// Checks that the unlock block at the given index, or the one it references, unlocks the given address.
+fn verify_unlock_block(
+    address: &Address,
+    index: usize,
+    unlock_blocks: &UnlockBlocks,
+    consumed_outputs: &[(OutputId, CreatedOutput)],
+    state_transitioned_aliases: &HashSet<AliasId>,
+    essence_hash: &[u8; 32],
+) -> Result<(), ConflictReason> {
+    match (address, unlock_blocks.get(index)) {
+        (Address::Ed25519(address), Some(UnlockBlock::Signature(unlock_block))) => {
+            let Signature::Ed25519(signature) = unlock_block.signature();
+
+            address
+                .verify(essence_hash, signature)
+                .map_err(|_| ConflictReason::InvalidSignature)
+        }
+        (Address::Alias(address), Some(UnlockBlock::Alias(unlock_block))) => {
+            // The referenced input has already been unlocked since unlock blocks can only reference previous ones.
+            match consumed_outputs.get(unlock_block.index() as usize) {
+                Some((output_id, output)) => match output.inner() {
+                    Output::Alias(output) if output.alias_id().or_from_output_id(*output_id) == *address.id() => {
+                        // Only the state controller can unlock the outputs owned by the alias, not the governor.
+                        if state_transitioned_aliases.contains(address.id()) {
+                            Ok(())
+                        } else {
+                            Err(ConflictReason::InvalidAliasUnlock)
+                        }
+                    }
+                    _ => Err(ConflictReason::InvalidSignature),
+                },
+                None => Err(ConflictReason::InvalidSignature),
+            }
+        }
+        (Address::Nft(address), Some(UnlockBlock::Nft(unlock_block))) => {
+            // The referenced input has already been unlocked since unlock blocks can only reference previous ones.
+            match consumed_outputs.get(unlock_block.index() as usize) {
+                Some((output_id, output)) => match output.inner() {
+                    Output::Nft(output) if output.nft_id().or_from_output_id(*output_id) == *address.id() => Ok(()),
+                    _ => Err(ConflictReason::InvalidNftUnlockBlock),
+                },
+                None => Err(ConflictReason::InvalidNftUnlockBlock),
+            }
+        }
+        (Address::Nft(_), _) => Err(ConflictReason::InvalidNftUnlockBlock),
+        _ => Err(ConflictReason::InvalidSignature),
+    }
+}
+
+// Checks that every created NFT output is either a new NFT or a valid transition of a consumed one.
+fn verify_nft_transitions(
+    consumed_outputs: &[(OutputId, CreatedOutput)],
+    created_outputs: &[Output],
+    unlocked_addresses: &HashSet<Address>,
+) -> Result<(), ConflictReason> {
+    let consumed_nfts = consumed_outputs
+        .iter()
+        .filter_map(|(output_id, output)| match output.inner() {
+            Output::Nft(output) => Some((output.nft_id().or_from_output_id(*output_id), output)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    let mut created_nfts = HashSet::new();
+
+    for created_output in created_outputs {
+        if let Output::Nft(next) = created_output {
+            // A new NFT has a null ID, its actual ID is derived from its output ID.
+            if next.nft_id().is_null() {
+                // The issuer of a new NFT has to be unlocked by the transaction.
+                match issuer(next.feature_blocks()) {
+                    Some(FeatureBlock::Issuer(issuer)) if !unlocked_addresses.contains(issuer.address()) => {
+                        return Err(ConflictReason::InvalidNftCreation);
+                    }
+                    _ => continue,
+                }
+            }
+
+            // An NFT can only be transitioned once, any other output with its ID would be a copy.
+            if !created_nfts.insert(next.nft_id()) {
+                return Err(ConflictReason::InvalidNftCreation);
+            }
+
+            match consumed_nfts.get(next.nft_id()) {
+                Some(current) => {
+                    // The immutable metadata and the issuer of an NFT can't be changed.
+                    if current.immutable_metadata() != next.immutable_metadata()
+                        || issuer(current.feature_blocks()) != issuer(next.feature_blocks())
+                    {
+                        return Err(ConflictReason::InvalidNftTransition);
+                    }
+                }
+                None => return Err(ConflictReason::InvalidNftCreation),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Checks if a milestone index and/or timestamp condition is met by the confirming milestone, a zero value is ignored.
+fn is_reached(milestone_index: MilestoneIndex, timestamp: u32, metadata: &WhiteFlagMetadata) -> bool {
+    (*milestone_index == 0 || metadata.milestone_index >= milestone_index)
+        && (timestamp == 0 || metadata.milestone_timestamp >= timestamp as u64)
+}
+
+// Evaluates the timelock, expiration and dust deposit return unlock conditions of a consumed output against the
+// confirming milestone and returns the address that has to unlock it.
+fn verify_unlock_conditions<'a>(
+    address: &'a Address,
+    unlock_conditions: &'a [UnlockCondition],
+    metadata: &WhiteFlagMetadata,
+    dust_deposit_returns: &mut HashMap<Address, u64>,
+) -> Result<&'a Address, ConflictReason> {
+    let mut expired_return_address = None;
+    let mut dust_deposit_return = None;
+
+    for unlock_condition in unlock_conditions {
+        match unlock_condition {
+            UnlockCondition::Timelock(timelock)
+                if !is_reached(timelock.milestone_index(), timelock.timestamp(), metadata) =>
+            {
+                return Err(ConflictReason::TimelockNotExpired);
+            }
+            UnlockCondition::Expiration(expiration)
+                if is_reached(expiration.milestone_index(), expiration.timestamp(), metadata) =>
+            {
+                expired_return_address = Some(expiration.return_address());
+            }
+            UnlockCondition::DustDepositReturn(condition) => dust_deposit_return = Some(condition),
+            _ => {}
+        }
+    }
+
+    // Once expired, only the return address can unlock the output and the dust deposit doesn't have to be returned.
+    if let Some(return_address) = expired_return_address {
+        return Ok(return_address);
+    }
+
+    if let Some(dust_deposit_return) = dust_deposit_return {
+        let amount = dust_deposit_returns
+            .entry(*dust_deposit_return.return_address())
+            .or_default();
+        *amount = amount.saturating_add(dust_deposit_return.amount());
+    }
+
+    Ok(address)
+}
+
+// Checks that the dust deposits to return are paid back to their return addresses by the created outputs.
+fn verify_dust_deposit_returns(
+    dust_deposit_returns: &HashMap<Address, u64>,
+    created_outputs: &[Output],
+) -> Result<(), ConflictReason> {
+    if dust_deposit_returns.is_empty() {
+        return Ok(());
+    }
+
+    let mut returned_amounts = HashMap::<&Address, u64>::new();
+
+    for created_output in created_outputs {
+        // Only basic outputs that are unconditionally unlockable by the return address count as a return.
+        if let Output::Basic(output) = created_output {
+            if let [UnlockCondition::Address(address)] = output.unlock_conditions() {
+                let amount = returned_amounts.entry(address.address()).or_default();
+                *amount = amount.saturating_add(output.amount());
+            }
+        }
+    }
+
+    for (address, amount) in dust_deposit_returns {
+        if returned_amounts.get(address).copied().unwrap_or_default() < *amount {
+            return Err(ConflictReason::DustDepositReturnMismatch);
+        }
+    }
+
+    Ok(())
+}
+
+fn add_native_tokens(
+    native_tokens: &mut HashMap<TokenId, U256>,
+    other: Option<&[NativeToken]>,
+) -> Result<(), ConflictReason> {
+    for native_token in other.unwrap_or_default() {
+        let amount = native_tokens.entry(*native_token.token_id()).or_default();
+
+        *amount = amount
+            .checked_add(*native_token.amount())
+            .ok_or(ConflictReason::NativeTokensSumMismatch)?;
+    }
+
+    Ok(())
+}
+
+// Checks that native tokens are balanced, unless the difference is minted or melted by the controlling foundry.
+fn verify_native_tokens(
+    consumed_outputs: &[(OutputId, CreatedOutput)],
+    created_outputs: &[Output],
+) -> Result<(), ConflictReason> {
+    let mut consumed_native_tokens = HashMap::new();
+    let mut created_native_tokens = HashMap::new();
+    // Consumed and created foundries, indexed by the native token they control.
+    let mut foundries = HashMap::<TokenId, (Option<&FoundryOutput>, Option<&FoundryOutput>)>::new();
+
+    for (_, consumed_output) in consumed_outputs {
+        add_native_tokens(&mut consumed_native_tokens, consumed_output.native_tokens())?;
+
+        if let Output::Foundry(foundry) = consumed_output.inner() {
+            foundries.entry(foundry.token_id()).or_default().0 = Some(foundry);
+        }
+    }
+
+    for created_output in created_outputs {
+        add_native_tokens(&mut created_native_tokens, created_output.native_tokens())?;
+
+        if let Output::Foundry(foundry) = created_output {
+            let (consumed_foundry, created_foundry) = foundries.entry(foundry.token_id()).or_default();
+
+            // A foundry can't be duplicated.
+            if created_foundry.is_some() {
+                return Err(ConflictReason::InvalidFoundrySupplyChange);
+            }
+
+            // The maximum supply of a foundry can't be changed.
+            if let Some(consumed_foundry) = consumed_foundry {
+                if consumed_foundry.maximum_supply() != foundry.maximum_supply() {
+                    return Err(ConflictReason::InvalidFoundrySupplyChange);
+                }
+            }
+
+            *created_foundry = Some(foundry);
+        }
+    }
+
+    let token_ids = consumed_native_tokens
+        .keys()
+        .chain(created_native_tokens.keys())
+        .chain(foundries.keys())
+        .collect::<HashSet<_>>();
+
+    for token_id in token_ids {
+        let consumed_amount = consumed_native_tokens.get(token_id).copied().unwrap_or_default();
+        let created_amount = created_native_tokens.get(token_id).copied().unwrap_or_default();
+        let (consumed_foundry, created_foundry) = foundries.get(token_id).copied().unwrap_or_default();
+        let consumed_supply = consumed_foundry.map_or_else(U256::zero, |foundry| *foundry.circulating_supply());
+        let created_supply = created_foundry.map_or_else(U256::zero, |foundry| *foundry.circulating_supply());
+
+        // The amount of minted or melted native tokens has to match the change of circulating supply.
+        let balanced = match (
+            created_amount.checked_add(consumed_supply),
+            consumed_amount.checked_add(created_supply),
+        ) {
+            (Some(created), Some(consumed)) => created == consumed,
+            _ => false,
+        };
+
+        if !balanced {
+            return Err(if consumed_supply == created_supply {
+                ConflictReason::NativeTokensSumMismatch
+            } else {
+                ConflictReason::InvalidFoundrySupplyChange
+            });
+        }
+    }
+
+    Ok(())
+}
 
 fn apply_regular_essence<B: StorageBackend>(
     storage: &B,
     message_id: &MessageId,
     transaction_id: &TransactionId,
     essence: &RegularTransactionEssence,
-    _unlock_blocks: &UnlockBlocks,
+    unlock_blocks: &UnlockBlocks,
     metadata: &mut WhiteFlagMetadata,
 ) -> Result<ConflictReason, Error> {
-    let mut consumed_outputs = HashMap::with_capacity(essence.inputs().len());
-    let consumed_amount: u64 = 0;
-    let created_amount: u64 = 0;
+    let mut consumed_outputs = Vec::<(OutputId, CreatedOutput)>::with_capacity(essence.inputs().len());
+    let mut unlocked_addresses = HashSet::new();
+    let mut state_transitioned_aliases = HashSet::new();
+    let mut dust_deposit_returns = HashMap::new();
+    let mut consumed_amount: u64 = 0;
+    let mut created_amount: u64 = 0;
+    let essence_hash = TransactionEssence::from(essence.clone()).hash();
 
-    for (_index, input) in essence.inputs().iter().enumerate() {
+    for (index, input) in essence.inputs().iter().enumerate() {
         let (output_id, consumed_output) = match input {
             Input::Utxo(input) => {
                 let output_id = input.output_id();
@@ -73,49 +506,130 @@ fn apply_regular_essence<B: StorageBackend>(
             }
         };
 
-        let _essence_hash = TransactionEssence::from(essence.clone()).hash();
-
-        match consumed_output.inner() {
+        // The conflict to report if the output is not unlocked, if different from an invalid signature.
+        let mut unlock_conflict = None;
+        let (amount, address) = match consumed_output.inner() {
             Output::Treasury(_) => return Err(Error::UnsupportedOutputKind(consumed_output.inner().kind())),
-            Output::Basic(_) => {
-                // TODO
+            Output::Basic(output) => {
+                match verify_unlock_conditions(
+                    output.address(),
+                    output.unlock_conditions(),
+                    metadata,
+                    &mut dust_deposit_returns,
+                ) {
+                    Ok(address) => (output.amount(), address),
+                    Err(conflict) => return Ok(conflict),
+                }
             }
-            Output::Alias(_) => {
-                // TODO
+            Output::Alias(output) => {
+                let alias_id = output.alias_id().or_from_output_id(*output_id);
+
+                match alias_transition(&alias_id, output, essence.outputs()) {
+                    AliasTransition::State => {
+                        state_transitioned_aliases.insert(alias_id);
+                        (output.amount(), output.state_controller())
+                    }
+                    AliasTransition::Governance => (output.amount(), output.governor()),
+                    AliasTransition::Destruction => {
+                        unlock_conflict = Some(ConflictReason::InvalidAliasDestruction);
+                        (output.amount(), output.governor())
+                    }
+                }
             }
-            Output::Foundry(_) => {
-                // TODO
+            Output::Foundry(output) => (output.amount(), output.address()),
+            Output::Nft(output) => {
+                match verify_unlock_conditions(
+                    output.address(),
+                    output.unlock_conditions(),
+                    metadata,
+                    &mut dust_deposit_returns,
+                ) {
+                    Ok(address) => (output.amount(), address),
+                    Err(conflict) => return Ok(conflict),
+                }
             }
-            Output::Nft(_) => {
-                // TODO
+        };
+
+        consumed_amount = consumed_amount
+            .checked_add(amount)
+            .ok_or(Error::ConsumedAmountOverflow(consumed_amount as u128 + amount as u128))?;
+
+        if let Err(conflict) = verify_unlock_block(
+            address,
+            index,
+            unlock_blocks,
+            &consumed_outputs,
+            &state_transitioned_aliases,
+            &essence_hash,
+        ) {
+            return Ok(unlock_conflict.unwrap_or(conflict));
+        }
+
+        unlocked_addresses.insert(*address);
+
+        match consumed_output.inner() {
+            // Like for alias unlock blocks, the alias address is only unlocked by a state transition.
+            Output::Alias(output) => {
+                let alias_id = output.alias_id().or_from_output_id(*output_id);
+
+                if state_transitioned_aliases.contains(&alias_id) {
+                    unlocked_addresses.insert(Address::from(AliasAddress::new(alias_id)));
+                }
             }
+            Output::Nft(output) => {
+                unlocked_addresses.insert(Address::from(NftAddress::new(
+                    output.nft_id().or_from_output_id(*output_id),
+                )));
+            }
+            _ => {}
         }
 
-        consumed_outputs.insert(*output_id, consumed_output);
+        consumed_outputs.push((*output_id, consumed_output));
     }
 
     for created_output in essence.outputs() {
-        match created_output {
+        let amount = match created_output {
             Output::Treasury(_) => return Err(Error::UnsupportedOutputKind(created_output.kind())),
-            Output::Basic(_) => {
-                // TODO
-            }
-            Output::Alias(_) => {
-                // TODO
-            }
-            Output::Foundry(_) => {
-                // TODO
-            }
-            Output::Nft(_) => {
-                // TODO
-            }
+            Output::Basic(output) => output.amount(),
+            Output::Alias(output) => output.amount(),
+            Output::Foundry(output) => output.amount(),
+            Output::Nft(output) => output.amount(),
+        };
+
+        // Every created output has to cover the deposit required to store it.
+        if amount < created_output.byte_cost(&metadata.byte_cost_config) {
+            return Ok(ConflictReason::InsufficientDustDeposit);
         }
+
+        created_amount = created_amount
+            .checked_add(amount)
+            .ok_or(Error::CreatedAmountOverflow(created_amount as u128 + amount as u128))?;
     }
 
     if created_amount != consumed_amount {
         return Ok(ConflictReason::InputOutputSumMismatch);
     }
 
+    if let Err(conflict) = verify_dust_deposit_returns(&dust_deposit_returns, essence.outputs()) {
+        return Ok(conflict);
+    }
+
+    if let Err(conflict) = verify_native_tokens(&consumed_outputs, essence.outputs()) {
+        return Ok(conflict);
+    }
+
+    if let Err(conflict) = verify_alias_transitions(&consumed_outputs, essence.outputs()) {
+        return Ok(conflict);
+    }
+
+    if let Err(conflict) = verify_foundry_creations(&consumed_outputs, essence.outputs()) {
+        return Ok(conflict);
+    }
+
+    if let Err(conflict) = verify_nft_transitions(&consumed_outputs, essence.outputs(), &unlocked_addresses) {
+        return Ok(conflict);
+    }
+
     for (output_id, created_output) in consumed_outputs {
         metadata.consumed_outputs.insert(
             output_id,
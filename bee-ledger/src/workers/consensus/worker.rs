@@ -2,13 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    types::{CreatedOutput, LedgerIndex, Migration, Receipt, TreasuryOutput},
+    types::{AddressRole, CreatedOutput, LedgerIndex, Migration, Receipt, TreasuryOutput},
     workers::{
-        consensus::{metadata::WhiteFlagMetadata, state::validate_ledger_state, white_flag},
+        consensus::{config::ConsensusConfig, metadata::WhiteFlagMetadata, state::validate_ledger_state, white_flag},
         error::Error,
         event::{MessageReferenced, MilestoneConfirmed, OutputConsumed, OutputCreated},
+        indexation::config::IndexationConfig,
         pruning::{condition::should_prune, config::PruningConfig, prune},
-        snapshot::{condition::should_snapshot, config::SnapshotConfig, worker::SnapshotWorker},
+        snapshot::{
+            condition::should_snapshot, config::SnapshotConfig, creation::create_snapshot, worker::SnapshotWorker,
+        },
         storage::{self, StorageBackend},
     },
 };
@@ -16,7 +19,7 @@ use crate::{
 use bee_message::{
     address::Address,
     milestone::MilestoneIndex,
-    output::{unlock_condition::AddressUnlockCondition, BasicOutput, Output, OutputId},
+    output::{unlock_condition::AddressUnlockCondition, BasicOutput, ByteCostConfig, Output, OutputId},
     payload::{milestone::MilestoneId, receipt::ReceiptPayload, transaction::TransactionId, Payload},
     MessageId,
 };
@@ -29,7 +32,13 @@ use log::{debug, error, info, warn};
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
-use std::any::TypeId;
+use std::{
+    any::TypeId,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 pub(crate) const EXTRA_SNAPSHOT_DEPTH: u32 = 5;
 pub(crate) const EXTRA_PRUNING_DEPTH: u32 = 5;
@@ -44,10 +53,13 @@ pub enum ConsensusWorkerCommand {
         OutputId,
         oneshot::Sender<(Result<Option<CreatedOutput>, Error>, LedgerIndex)>,
     ),
-    /// Command to fetch the outputs of an address.
+    /// Command to fetch a page of the unspent outputs of an address with a given role, starting at a cursor and
+    /// limited to a page size, along with the cursor of the next page.
     FetchOutputs(
-        Address,
-        oneshot::Sender<(Result<Option<Vec<OutputId>>, Error>, LedgerIndex)>,
+        (Address, AddressRole),
+        Option<OutputId>,
+        usize,
+        oneshot::Sender<(Result<(Vec<OutputId>, Option<OutputId>), Error>, LedgerIndex)>,
     ),
 }
 
@@ -83,6 +95,7 @@ pub(crate) async fn migration_from_milestone(
     Ok(Migration::new(receipt, consumed_treasury, created_treasury))
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn confirm<N: Node>(
     tangle: &Tangle<N::Backend>,
     storage: &N::Backend,
@@ -90,6 +103,8 @@ async fn confirm<N: Node>(
     message_id: MessageId,
     ledger_index: &mut LedgerIndex,
     receipt_migrated_at: &mut MilestoneIndex,
+    byte_cost_config: &ByteCostConfig,
+    indexation_config: &IndexationConfig,
 ) -> Result<(), Error>
 where
     N::Backend: StorageBackend,
@@ -111,7 +126,11 @@ where
         ));
     }
 
-    let mut metadata = WhiteFlagMetadata::new(milestone.essence().index(), milestone.essence().timestamp());
+    let mut metadata = WhiteFlagMetadata::new(
+        milestone.essence().index(),
+        milestone.essence().timestamp(),
+        *byte_cost_config,
+    );
 
     white_flag(tangle, storage, message.parents(), &mut metadata).await?;
 
@@ -185,6 +204,7 @@ where
         &metadata.created_outputs,
         &metadata.consumed_outputs,
         &migration,
+        indexation_config,
     )?;
 
     *ledger_index = LedgerIndex(milestone.essence().index());
@@ -275,7 +295,7 @@ impl<N: Node> Worker<N> for ConsensusWorker
 where
     N::Backend: StorageBackend,
 {
-    type Config = (SnapshotConfig, PruningConfig);
+    type Config = (SnapshotConfig, PruningConfig, ConsensusConfig, IndexationConfig);
     type Error = Error;
 
     fn dependencies() -> &'static [TypeId] {
@@ -283,7 +303,7 @@ where
     }
 
     async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
-        let (snapshot_config, pruning_config) = config;
+        let (snapshot_config, pruning_config, consensus_config, indexation_config) = config;
         let (tx, rx) = mpsc::unbounded_channel();
         let tangle = node.resource::<Tangle<N::Backend>>();
         let storage = node.storage();
@@ -321,6 +341,7 @@ where
         // Unwrap is fine because ledger index was already in storage or just added by the snapshot worker.
         let mut ledger_index = storage::fetch_ledger_index(&*storage)?.unwrap();
         let mut receipt_migrated_at = MilestoneIndex(0);
+        let snapshotting = Arc::new(AtomicBool::new(false));
 
         node.spawn::<Self, _, _>(|shutdown| async move {
             info!("Running.");
@@ -337,6 +358,8 @@ where
                             message_id,
                             &mut ledger_index,
                             &mut receipt_migrated_at,
+                            consensus_config.byte_cost(),
+                            &indexation_config,
                         )
                         .await
                         {
@@ -348,23 +371,63 @@ where
                             continue;
                         }
 
+                        // Pruning could remove data that the snapshot being created still needs, it catches up once the
+                        // snapshot is created.
+                        if snapshotting.load(Ordering::Acquire) {
+                            debug!("Snapshotting and pruning skipped: snapshot creation in progress.");
+                            continue;
+                        }
+
                         match should_snapshot(&tangle, ledger_index, snapshot_depth, &snapshot_config) {
                             Ok(()) => {
-                                // TODO
-                                // if let Err(e) = snapshot(snapshot_config.path(), event.index - snapshot_depth) {
-                                //     error!("Failed to create snapshot: {:?}.", e);
-                                // }
+                                let target_index = MilestoneIndex(*ledger_index - snapshot_depth);
+                                let tangle = tangle.clone();
+                                let storage = storage.clone();
+                                let snapshot_config = snapshot_config.clone();
+                                let snapshotting = snapshotting.clone();
+
+                                snapshotting.store(true, Ordering::Release);
+
+                                // Snapshot creation runs in the background to not block confirmation.
+                                tokio::task::spawn_blocking(move || {
+                                    info!("Creating snapshot with target index {}...", target_index);
+
+                                    match create_snapshot(&*storage, &snapshot_config, bmd, target_index) {
+                                        Ok(()) => tangle.update_snapshot_index(target_index),
+                                        Err(e) => error!("Failed to create snapshot: {:?}.", e),
+                                    }
+
+                                    snapshotting.store(false, Ordering::Release);
+                                });
+
+                                continue;
                             }
                             Err(reason) => {
                                 debug!("Snapshotting skipped: {:?}", reason);
                             }
                         }
 
-                        match should_prune(&tangle, ledger_index, pruning_delay, &pruning_config) {
-                            Ok((start_index, target_index)) => {
-                                if let Err(e) =
-                                    prune::prune(&tangle, &storage, &bus, start_index, target_index, &pruning_config)
-                                        .await
+                        match should_prune(
+                            &tangle,
+                            &storage,
+                            ledger_index,
+                            pruning_delay,
+                            pruning_delay_min,
+                            &pruning_config,
+                        )
+                        .await
+                        {
+                            Ok((start_index, target_index, reason)) => {
+                                if let Err(e) = prune::prune(
+                                    &tangle,
+                                    &storage,
+                                    &bus,
+                                    start_index,
+                                    target_index,
+                                    reason,
+                                    &pruning_config,
+                                )
+                                .await
                                 {
                                     error!("Pruning failed: {:?}.", e);
                                 }
@@ -379,18 +442,14 @@ where
                             error!("Error while sending output: {:?}", e);
                         }
                     }
-                    ConsensusWorkerCommand::FetchOutputs(address, sender) => match address {
-                        Address::Ed25519(address) => {
-                            if let Err(e) = sender.send((
-                                storage::fetch_outputs_for_ed25519_address(&*storage, &address),
-                                ledger_index,
-                            )) {
-                                error!("Error while sending output: {:?}", e);
-                            }
+                    ConsensusWorkerCommand::FetchOutputs(address, cursor, page_size, sender) => {
+                        if let Err(e) = sender.send((
+                            storage::fetch_output_ids_page(&*storage, &address, cursor.as_ref(), page_size),
+                            ledger_index,
+                        )) {
+                            error!("Error while sending outputs: {:?}", e);
                         }
-                        Address::Alias(_address) => todo!(),
-                        Address::Nft(_address) => todo!(),
-                    },
+                    }
                 }
             }
 
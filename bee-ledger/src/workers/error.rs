@@ -74,6 +74,18 @@ pub enum Error {
     /// Missing unspent output.
     #[error("missing unspent output {0}")]
     MissingUnspentOutput(Unspent),
+    /// Missing ledger index.
+    #[error("missing ledger index")]
+    MissingLedgerIndex,
+    /// Rollback target index above the ledger index.
+    #[error("rollback target index {0} is above the ledger index {1}")]
+    InvalidRollbackTargetIndex(MilestoneIndex, MilestoneIndex),
+    /// Receipts not matching the treasury transition of their milestone.
+    #[error("receipts included in milestone {0} don't match its treasury transition")]
+    ReceiptTreasuryMismatch(MilestoneIndex),
+    /// Invalid archive record.
+    #[error("invalid archive record")]
+    InvalidArchiveRecord,
     /// Storage backend error.
     #[error("storage backend error: {0}")]
     Storage(Box<dyn std::error::Error + Send>),
@@ -0,0 +1,69 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module containing indexation configuration.
+
+use serde::Deserialize;
+
+const DEFAULT_TAG: bool = false;
+const DEFAULT_SENDER: bool = false;
+
+/// Builder for an [`IndexationConfig`].
+#[derive(Default, Deserialize)]
+#[must_use]
+pub struct IndexationConfigBuilder {
+    tag: Option<bool>,
+    sender: Option<bool>,
+}
+
+impl IndexationConfigBuilder {
+    /// Creates a new [`IndexationConfigBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether unspent outputs are indexed by their tag feature block.
+    pub fn tag(mut self, tag: bool) -> Self {
+        self.tag.replace(tag);
+        self
+    }
+
+    /// Sets whether unspent outputs are indexed by their sender feature block.
+    pub fn sender(mut self, sender: bool) -> Self {
+        self.sender.replace(sender);
+        self
+    }
+
+    /// Finishes the builder into an [`IndexationConfig`].
+    #[must_use]
+    pub fn finish(self) -> IndexationConfig {
+        IndexationConfig {
+            tag: self.tag.unwrap_or(DEFAULT_TAG),
+            sender: self.sender.unwrap_or(DEFAULT_SENDER),
+        }
+    }
+}
+
+/// The indexation configuration.
+#[derive(Clone)]
+pub struct IndexationConfig {
+    tag: bool,
+    sender: bool,
+}
+
+impl IndexationConfig {
+    /// Returns a builder to create an [`IndexationConfig`].
+    pub fn build() -> IndexationConfigBuilder {
+        IndexationConfigBuilder::new()
+    }
+
+    /// Returns whether unspent outputs are indexed by their tag feature block.
+    pub fn tag(&self) -> bool {
+        self.tag
+    }
+
+    /// Returns whether unspent outputs are indexed by their sender feature block.
+    pub fn sender(&self) -> bool {
+        self.sender
+    }
+}
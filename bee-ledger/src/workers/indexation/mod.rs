@@ -0,0 +1,61 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module containing the optional indexation of outputs by their feature blocks.
+
+pub mod config;
+
+use crate::workers::{
+    error::Error,
+    indexation::config::IndexationConfig,
+    storage::{self, StorageBackend},
+};
+
+use bee_message::output::{
+    feature_block::{SenderFeatureBlock, TagFeatureBlock},
+    OutputId,
+};
+use bee_storage::access::{AsIterator, Truncate};
+
+use log::info;
+
+/// Aligns the optional indexes of a storage with the indexation configuration.
+///
+/// Outputs are only added to the enabled indexes, so an index that was disabled so far misses outputs. Disabled indexes
+/// are therefore truncated, and enabled indexes that are still empty are backfilled from the unspent outputs.
+pub fn synchronize_indexes<B: StorageBackend>(storage: &B, config: &IndexationConfig) -> Result<(), Error> {
+    if !config.tag() {
+        Truncate::<(TagFeatureBlock, OutputId), ()>::truncate(storage).map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+    if !config.sender() {
+        Truncate::<(SenderFeatureBlock, OutputId), ()>::truncate(storage).map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    let tag_index_empty = AsIterator::<(TagFeatureBlock, OutputId), ()>::iter(storage)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .next()
+        .is_none();
+    let sender_index_empty = AsIterator::<(SenderFeatureBlock, OutputId), ()>::iter(storage)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .next()
+        .is_none();
+
+    let backfill = IndexationConfig::build()
+        .tag(config.tag() && tag_index_empty)
+        .sender(config.sender() && sender_index_empty)
+        .finish();
+
+    if backfill.tag() || backfill.sender() {
+        info!(
+            "Indexing unspent outputs by {}...",
+            match (backfill.tag(), backfill.sender()) {
+                (true, true) => "tag and sender",
+                (true, false) => "tag",
+                _ => "sender",
+            }
+        );
+        storage::insert_feature_block_output_ids(storage, &backfill)?;
+    }
+
+    Ok(())
+}
@@ -3,16 +3,20 @@
 
 //! Module containing workers required to create and maintain the ledger state.
 
+pub mod check;
 pub mod consensus;
 pub mod error;
 pub mod event;
+pub mod indexation;
 pub mod pruning;
+pub mod rollback;
 pub mod snapshot;
 pub mod storage;
 
 pub use storage::StorageBackend;
 
-use consensus::ConsensusWorker;
+use consensus::{config::ConsensusConfig, ConsensusWorker};
+use indexation::config::IndexationConfig;
 use pruning::config::PruningConfig;
 use snapshot::{config::SnapshotConfig, worker::SnapshotWorker};
 
@@ -24,12 +28,14 @@ pub fn init<N>(
     network_id: u64,
     snapshot_config: SnapshotConfig,
     pruning_config: PruningConfig,
+    consensus_config: ConsensusConfig,
+    indexation_config: IndexationConfig,
 ) -> N::Builder
 where
     N: Node,
     N::Backend: StorageBackend,
 {
     node_builder
-        .with_worker_cfg::<SnapshotWorker>((network_id, snapshot_config.clone()))
-        .with_worker_cfg::<ConsensusWorker>((snapshot_config, pruning_config))
+        .with_worker_cfg::<SnapshotWorker>((network_id, snapshot_config.clone(), indexation_config.clone()))
+        .with_worker_cfg::<ConsensusWorker>((snapshot_config, pruning_config, consensus_config, indexation_config))
 }
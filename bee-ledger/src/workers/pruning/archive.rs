@@ -0,0 +1,362 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module containing the archiving of pruned data.
+//!
+//! Each pruning run appends the data of its milestones to a gzip compressed file named after its milestone range, e.g.
+//! `0000000010-0000000020.archive.gz`. Every milestone is written as its own gzip member right before its data is
+//! deleted. If pruning is interrupted while a member is written, the file ends with a truncated member that is ignored
+//! on import, the file then holds all records up to the last complete one. A milestone whose pruning was interrupted
+//! is archived again by the next pruning run, which is harmless since importing is idempotent.
+
+use crate::{
+    types::{ConsumedOutput, CreatedOutput, OutputDiff, Receipt, TreasuryOutput},
+    workers::{error::Error as WorkersError, pruning::error::Error, storage::StorageBackend},
+};
+
+use bee_message::{
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    Message, MessageId,
+};
+use bee_storage::access::{Batch, Fetch};
+use bee_tangle::metadata::MessageMetadata;
+
+use flate2::{bufread::GzDecoder, write::GzEncoder, Compression};
+use packable::{
+    packer::{IoPacker, Packer},
+    unpacker::{IoUnpacker, Unpacker},
+    Packable, PackableExt,
+};
+
+use std::{
+    collections::HashSet,
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+};
+
+const ARCHIVE_FILE_EXTENSION: &str = ".archive.gz";
+
+/// Data of a milestone that is deleted by pruning.
+///
+/// Edges are not archived as they are rebuilt from the parents of the messages.
+pub struct ArchivedMilestone {
+    index: MilestoneIndex,
+    milestone: Milestone,
+    messages: Vec<(MessageId, Message, MessageMetadata)>,
+    output_diff: Option<OutputDiff>,
+    consumed_outputs: Vec<(OutputId, CreatedOutput, ConsumedOutput)>,
+    receipts: Vec<Receipt>,
+    treasury_outputs: Vec<TreasuryOutput>,
+}
+
+impl ArchivedMilestone {
+    /// Creates an [`ArchivedMilestone`] from the milestone data that is about to be pruned.
+    pub fn new<S: StorageBackend>(storage: &S, index: MilestoneIndex, with_receipts: bool) -> Result<Self, Error> {
+        let milestone = Fetch::<MilestoneIndex, Milestone>::fetch(storage, &index)
+            .map_err(|e| Error::Storage(Box::new(e)))?
+            .ok_or(Error::MissingMilestone(index))?;
+        let output_diff =
+            Fetch::<MilestoneIndex, OutputDiff>::fetch(storage, &index).map_err(|e| Error::Storage(Box::new(e)))?;
+
+        let mut consumed_outputs = Vec::new();
+        let mut treasury_outputs = Vec::new();
+
+        if let Some(output_diff) = &output_diff {
+            for output_id in output_diff.consumed_outputs() {
+                let created_output = Fetch::<OutputId, CreatedOutput>::fetch(storage, output_id)
+                    .map_err(|e| Error::Storage(Box::new(e)))?;
+                let consumed_output = Fetch::<OutputId, ConsumedOutput>::fetch(storage, output_id)
+                    .map_err(|e| Error::Storage(Box::new(e)))?;
+
+                if let (Some(created_output), Some(consumed_output)) = (created_output, consumed_output) {
+                    consumed_outputs.push((*output_id, created_output, consumed_output));
+                }
+            }
+
+            if let Some(treasury_diff) = output_diff.treasury_diff() {
+                treasury_outputs = Fetch::<bool, Vec<TreasuryOutput>>::fetch(storage, &true)
+                    .map_err(|e| Error::Storage(Box::new(e)))?
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|treasury_output| treasury_output.milestone_id() == treasury_diff.consumed())
+                    .collect();
+            }
+        }
+
+        let receipts = if with_receipts {
+            Fetch::<MilestoneIndex, Vec<Receipt>>::fetch(storage, &index)
+                .map_err(|e| Error::Storage(Box::new(e)))?
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            index,
+            milestone,
+            messages: Vec::new(),
+            output_diff,
+            consumed_outputs,
+            receipts,
+            treasury_outputs,
+        })
+    }
+
+    /// Adds a message that is about to be pruned.
+    pub fn add_message<S: StorageBackend>(&mut self, storage: &S, message_id: &MessageId) -> Result<(), Error> {
+        let message = Fetch::<MessageId, Message>::fetch(storage, message_id)
+            .map_err(|e| Error::Storage(Box::new(e)))?
+            .ok_or(Error::MissingMessage(*message_id))?;
+        let metadata = Fetch::<MessageId, MessageMetadata>::fetch(storage, message_id)
+            .map_err(|e| Error::Storage(Box::new(e)))?
+            .ok_or(Error::MissingMetadata(*message_id))?;
+
+        self.messages.push((*message_id, message, metadata));
+
+        Ok(())
+    }
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.index.pack(packer)?;
+        self.milestone.pack(packer)?;
+
+        (self.messages.len() as u64).pack(packer)?;
+        for (message_id, message, metadata) in &self.messages {
+            // Messages reject trailing bytes when unpacked, they are therefore prefixed by their length.
+            let message_bytes = message.pack_to_vec();
+
+            message_id.pack(packer)?;
+            (message_bytes.len() as u32).pack(packer)?;
+            packer.pack_bytes(message_bytes)?;
+            metadata.pack(packer)?;
+        }
+
+        self.output_diff.pack(packer)?;
+
+        (self.consumed_outputs.len() as u64).pack(packer)?;
+        for (output_id, created_output, consumed_output) in &self.consumed_outputs {
+            output_id.pack(packer)?;
+            created_output.pack(packer)?;
+            consumed_output.pack(packer)?;
+        }
+
+        (self.receipts.len() as u64).pack(packer)?;
+        for receipt in &self.receipts {
+            receipt.pack(packer)?;
+        }
+
+        (self.treasury_outputs.len() as u64).pack(packer)?;
+        for treasury_output in &self.treasury_outputs {
+            treasury_output.pack(packer)?;
+        }
+
+        Ok(())
+    }
+
+    fn unpack<U: Unpacker>(unpacker: &mut U) -> Result<Self, WorkersError> {
+        fn unpack<T: Packable, U: Unpacker>(unpacker: &mut U) -> Result<T, WorkersError> {
+            T::unpack::<_, true>(unpacker).map_err(|_| WorkersError::InvalidArchiveRecord)
+        }
+
+        let index = unpack(unpacker)?;
+        let milestone = unpack(unpacker)?;
+
+        let messages = (0..unpack::<u64, _>(unpacker)?)
+            .map(|_| {
+                let message_id = unpack(unpacker)?;
+                let mut message_bytes = vec![0u8; unpack::<u32, _>(unpacker)? as usize];
+                unpacker
+                    .unpack_bytes(&mut message_bytes)
+                    .map_err(|_| WorkersError::InvalidArchiveRecord)?;
+                let message = Message::unpack_verified(message_bytes.as_slice())
+                    .map_err(|_| WorkersError::InvalidArchiveRecord)?;
+
+                Ok((message_id, message, unpack(unpacker)?))
+            })
+            .collect::<Result<_, WorkersError>>()?;
+
+        let output_diff = unpack(unpacker)?;
+
+        let consumed_outputs = (0..unpack::<u64, _>(unpacker)?)
+            .map(|_| Ok((unpack(unpacker)?, unpack(unpacker)?, unpack(unpacker)?)))
+            .collect::<Result<_, WorkersError>>()?;
+
+        let receipts = (0..unpack::<u64, _>(unpacker)?)
+            .map(|_| unpack(unpacker))
+            .collect::<Result<_, _>>()?;
+
+        let treasury_outputs = (0..unpack::<u64, _>(unpacker)?)
+            .map(|_| unpack(unpacker))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            index,
+            milestone,
+            messages,
+            output_diff,
+            consumed_outputs,
+            receipts,
+            treasury_outputs,
+        })
+    }
+
+    fn import<S: StorageBackend>(&self, storage: &S) -> Result<(), WorkersError> {
+        let mut batch = S::batch_begin();
+
+        Batch::<MilestoneIndex, Milestone>::batch_insert(storage, &mut batch, &self.index, &self.milestone)
+            .map_err(|e| WorkersError::Storage(Box::new(e)))?;
+
+        for (message_id, message, metadata) in &self.messages {
+            Batch::<MessageId, Message>::batch_insert(storage, &mut batch, message_id, message)
+                .map_err(|e| WorkersError::Storage(Box::new(e)))?;
+            Batch::<MessageId, MessageMetadata>::batch_insert(storage, &mut batch, message_id, metadata)
+                .map_err(|e| WorkersError::Storage(Box::new(e)))?;
+
+            for parent in message.parents().iter() {
+                Batch::<(MessageId, MessageId), ()>::batch_insert(storage, &mut batch, &(*parent, *message_id), &())
+                    .map_err(|e| WorkersError::Storage(Box::new(e)))?;
+            }
+        }
+
+        if let Some(output_diff) = &self.output_diff {
+            Batch::<MilestoneIndex, OutputDiff>::batch_insert(storage, &mut batch, &self.index, output_diff)
+                .map_err(|e| WorkersError::Storage(Box::new(e)))?;
+        }
+
+        for (output_id, created_output, consumed_output) in &self.consumed_outputs {
+            Batch::<OutputId, CreatedOutput>::batch_insert(storage, &mut batch, output_id, created_output)
+                .map_err(|e| WorkersError::Storage(Box::new(e)))?;
+            Batch::<OutputId, ConsumedOutput>::batch_insert(storage, &mut batch, output_id, consumed_output)
+                .map_err(|e| WorkersError::Storage(Box::new(e)))?;
+        }
+
+        for receipt in &self.receipts {
+            Batch::<(MilestoneIndex, Receipt), ()>::batch_insert(
+                storage,
+                &mut batch,
+                &(self.index, receipt.clone()),
+                &(),
+            )
+            .map_err(|e| WorkersError::Storage(Box::new(e)))?;
+        }
+
+        for treasury_output in &self.treasury_outputs {
+            Batch::<(bool, TreasuryOutput), ()>::batch_insert(
+                storage,
+                &mut batch,
+                &(true, treasury_output.clone()),
+                &(),
+            )
+            .map_err(|e| WorkersError::Storage(Box::new(e)))?;
+        }
+
+        storage
+            .batch_commit(batch, true)
+            .map_err(|e| WorkersError::Storage(Box::new(e)))?;
+
+        Ok(())
+    }
+}
+
+/// Appends the data of a pruned milestone range to its archive file.
+pub struct ArchiveWriter {
+    file: File,
+}
+
+impl ArchiveWriter {
+    /// Opens the archive file of a milestone range in the given directory.
+    pub fn new(directory: &Path, start_index: MilestoneIndex, target_index: MilestoneIndex) -> Result<Self, Error> {
+        fs::create_dir_all(directory)?;
+
+        let file = OpenOptions::new().create(true).append(true).open(archive_file_path(
+            directory,
+            start_index,
+            target_index,
+        ))?;
+
+        Ok(Self { file })
+    }
+
+    /// Appends a milestone to the archive file and makes sure it is persisted.
+    pub fn write(&mut self, archived_milestone: &ArchivedMilestone) -> Result<(), Error> {
+        let mut packer = IoPacker::new(GzEncoder::new(BufWriter::new(&mut self.file), Compression::default()));
+
+        archived_milestone.pack(&mut packer)?;
+        packer.into_inner().finish()?.flush()?;
+        self.file.sync_data()?;
+
+        Ok(())
+    }
+}
+
+fn archive_file_path(directory: &Path, start_index: MilestoneIndex, target_index: MilestoneIndex) -> PathBuf {
+    directory.join(format!(
+        "{:010}-{:010}{}",
+        *start_index, *target_index, ARCHIVE_FILE_EXTENSION
+    ))
+}
+
+/// Parses the milestone range from the name of an archive file.
+fn archive_file_range(path: &Path) -> Option<(MilestoneIndex, MilestoneIndex)> {
+    let (start_index, target_index) = path
+        .file_name()?
+        .to_str()?
+        .strip_suffix(ARCHIVE_FILE_EXTENSION)?
+        .split_once('-')?;
+
+    Some((
+        MilestoneIndex(start_index.parse().ok()?),
+        MilestoneIndex(target_index.parse().ok()?),
+    ))
+}
+
+/// Imports the archived milestones from `start_index` to `end_index` of an archive directory back into a storage.
+///
+/// A truncated gzip member at the end of an archive file, left by an interrupted pruning, is ignored.
+///
+/// Returns the number of imported milestones.
+pub fn import_archive<S: StorageBackend>(
+    storage: &S,
+    directory: &Path,
+    start_index: MilestoneIndex,
+    end_index: MilestoneIndex,
+) -> Result<usize, WorkersError> {
+    let mut paths = fs::read_dir(directory)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.sort();
+
+    let mut imported = HashSet::new();
+
+    for path in paths {
+        match archive_file_range(&path) {
+            Some((file_start_index, file_target_index))
+                if file_start_index <= end_index && file_target_index >= start_index => {}
+            _ => continue,
+        }
+
+        let mut reader = BufReader::new(File::open(&path)?);
+
+        while !reader.fill_buf()?.is_empty() {
+            // Every milestone is its own gzip member, a member is only unpacked once it has been entirely decoded.
+            let mut bytes = Vec::new();
+
+            match GzDecoder::new(&mut reader).read_to_end(&mut bytes) {
+                Ok(_) => {}
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(e.into()),
+            }
+
+            let archived_milestone = ArchivedMilestone::unpack(&mut IoUnpacker::new(bytes.as_slice()))?;
+
+            if archived_milestone.index >= start_index && archived_milestone.index <= end_index {
+                archived_milestone.import(storage)?;
+                imported.insert(archived_milestone.index);
+            }
+        }
+    }
+
+    Ok(imported.len())
+}
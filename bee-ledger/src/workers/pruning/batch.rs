@@ -2,10 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    types::{ConsumedOutput, CreatedOutput, OutputDiff, Receipt},
+    types::{ConsumedOutput, CreatedOutput, OutputDiff, Receipt, TreasuryOutput},
     workers::{
         consensus::worker::EXTRA_PRUNING_DEPTH,
         pruning::{
+            archive::ArchivedMilestone,
             error::Error,
             metrics::{ConfirmedDataPruningMetrics, MilestoneDataPruningMetrics, UnconfirmedDataPruningMetrics},
         },
@@ -16,6 +17,7 @@ use crate::{
 use bee_message::{
     milestone::{Milestone, MilestoneIndex},
     output::OutputId,
+    payload::milestone::MilestoneId,
     Message, MessageId,
 };
 use bee_storage::access::{Batch, Fetch};
@@ -44,6 +46,7 @@ pub async fn prune_confirmed_data<S: StorageBackend>(
     batch: &mut S::Batch,
     prune_index: MilestoneIndex,
     current_seps: &Seps,
+    archive: &mut Option<ArchivedMilestone>,
 ) -> Result<(Seps, ConfirmedDataPruningMetrics), Error> {
     // We keep a list of already visited messages.
     let mut visited = Messages::with_capacity(512);
@@ -114,7 +117,7 @@ pub async fn prune_confirmed_data<S: StorageBackend>(
         visited.insert(message_id);
 
         // Delete its associated data.
-        prune_message_and_metadata(storage, batch, &message_id)?;
+        prune_message_and_metadata(storage, batch, &message_id, archive)?;
 
         // ---
         // Everything that follows is required to decide whether this message's id should be kept as a solid entry
@@ -212,6 +215,7 @@ pub async fn prune_unconfirmed_data<S: StorageBackend>(
     storage: &S,
     batch: &mut S::Batch,
     prune_index: MilestoneIndex,
+    archive: &mut Option<ArchivedMilestone>,
 ) -> Result<UnconfirmedDataPruningMetrics, Error> {
     let mut metrics = UnconfirmedDataPruningMetrics::default();
 
@@ -289,7 +293,7 @@ pub async fn prune_unconfirmed_data<S: StorageBackend>(
                 let parents = msg.parents();
 
                 // Add message data to the delete batch.
-                prune_message_and_metadata(storage, batch, unconf_msg_id)?;
+                prune_message_and_metadata(storage, batch, unconf_msg_id, archive)?;
 
                 log::trace!("Pruned unconfirmed msg {} at {}.", unconf_msg_id, prune_index);
 
@@ -329,7 +333,7 @@ pub async fn prune_milestone_data<S: StorageBackend>(
 
     prune_milestone(storage, batch, prune_index).await?;
 
-    prune_output_diff(storage, batch, prune_index).await?;
+    metrics.treasury_outputs = prune_output_diff(storage, batch, prune_index).await?;
 
     if should_prune_receipts {
         metrics.receipts = prune_receipts(storage, batch, prune_index).await?;
@@ -342,7 +346,12 @@ fn prune_message_and_metadata<S: StorageBackend>(
     storage: &S,
     batch: &mut S::Batch,
     message_id: &MessageId,
+    archive: &mut Option<ArchivedMilestone>,
 ) -> Result<(), Error> {
+    if let Some(archive) = archive {
+        archive.add_message(storage, message_id)?;
+    }
+
     Batch::<MessageId, Message>::batch_delete(storage, batch, message_id).map_err(|e| Error::Storage(Box::new(e)))?;
     Batch::<MessageId, MessageMetadata>::batch_delete(storage, batch, message_id)
         .map_err(|e| Error::Storage(Box::new(e)))?;
@@ -375,10 +384,14 @@ async fn prune_output_diff<S: StorageBackend>(
     storage: &S,
     batch: &mut S::Batch,
     index: MilestoneIndex,
-) -> Result<(), Error> {
+) -> Result<usize, Error> {
+    let mut num_treasury_outputs = 0;
+
     if let Some(output_diff) =
         Fetch::<MilestoneIndex, OutputDiff>::fetch(storage, &index).map_err(|e| Error::Storage(Box::new(e)))?
     {
+        // Addresses, chain IDs, tags and senders only index unspent outputs so consumed outputs don't need to be
+        // removed from these tables.
         for consumed_output in output_diff.consumed_outputs() {
             Batch::<OutputId, ConsumedOutput>::batch_delete(storage, batch, consumed_output)
                 .map_err(|e| Error::Storage(Box::new(e)))?;
@@ -386,15 +399,56 @@ async fn prune_output_diff<S: StorageBackend>(
                 .map_err(|e| Error::Storage(Box::new(e)))?;
         }
 
-        if let Some(_treasury_diff) = output_diff.treasury_diff() {
-            // TODO
+        if let Some(treasury_diff) = output_diff.treasury_diff() {
+            num_treasury_outputs = prune_spent_treasury_output(storage, batch, treasury_diff.consumed())?;
         }
     }
 
     Batch::<MilestoneIndex, OutputDiff>::batch_delete(storage, batch, &index)
         .map_err(|e| Error::Storage(Box::new(e)))?;
 
-    Ok(())
+    Ok(num_treasury_outputs)
+}
+
+/// Prunes the treasury output created by the given milestone once it has been spent.
+///
+/// The treasury output consumed by a milestone can't be needed anymore once that milestone is pruned, the unspent
+/// treasury output however has to survive pruning at all times.
+fn prune_spent_treasury_output<S: StorageBackend>(
+    storage: &S,
+    batch: &mut S::Batch,
+    milestone_id: &MilestoneId,
+) -> Result<usize, Error> {
+    let unspent_treasury_outputs = Fetch::<bool, Vec<TreasuryOutput>>::fetch(storage, &false)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .unwrap_or_default();
+
+    match unspent_treasury_outputs.as_slice() {
+        [unspent_treasury_output] if unspent_treasury_output.milestone_id() == milestone_id => {
+            return Err(Error::UnspentTreasuryOutputPruned(*milestone_id));
+        }
+        [_] => {}
+        unspent_treasury_outputs => {
+            return Err(Error::InvalidUnspentTreasuryOutputCount(unspent_treasury_outputs.len()));
+        }
+    }
+
+    let spent_treasury_outputs = Fetch::<bool, Vec<TreasuryOutput>>::fetch(storage, &true)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .unwrap_or_default();
+
+    let mut num = 0;
+    for treasury_output in spent_treasury_outputs
+        .into_iter()
+        .filter(|treasury_output| treasury_output.milestone_id() == milestone_id)
+    {
+        Batch::<(bool, TreasuryOutput), ()>::batch_delete(storage, batch, &(true, treasury_output))
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+
+        num += 1;
+    }
+
+    Ok(num)
 }
 
 async fn prune_receipts<S: StorageBackend>(
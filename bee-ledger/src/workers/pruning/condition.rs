@@ -6,6 +6,11 @@ use crate::{types::LedgerIndex, workers::pruning::config::PruningConfig};
 use bee_message::milestone::MilestoneIndex;
 use bee_tangle::{storage::StorageBackend, Tangle};
 
+use std::{
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
 const PRUNING_BATCH_SIZE_MAX: u32 = 200;
 
 /// Reasons for skipping pruning.
@@ -17,33 +22,108 @@ pub enum PruningSkipReason {
     BelowThreshold { reached_in: u32 },
 }
 
-pub(crate) fn should_prune<B: StorageBackend>(
+/// Reasons for pruning, i.e. the pruning policy that selected the most data to be pruned.
+#[derive(Clone, Copy, Debug)]
+pub enum PruningReason {
+    /// The pruning delay was reached.
+    Delay,
+    /// Milestones are older than the maximum age.
+    MaxAge,
+    /// The database size exceeds its target size.
+    TargetSize { size: u64 },
+}
+
+impl fmt::Display for PruningReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Delay => write!(f, "pruning delay reached"),
+            Self::MaxAge => write!(f, "maximum age exceeded"),
+            Self::TargetSize { size } => write!(f, "target size exceeded, database size is {} bytes", size),
+        }
+    }
+}
+
+/// Returns the index of the last consecutive milestone, starting from `start_index`, that is older than the maximum
+/// age.
+async fn max_age_target_index<B: StorageBackend>(
     tangle: &Tangle<B>,
+    start_index: u32,
+    end_index: u32,
+    max_age: u64,
+) -> Option<u32> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let threshold = now.saturating_sub(max_age);
+    let mut target_index = None;
+
+    for index in start_index..=end_index {
+        match tangle.get_milestone(MilestoneIndex(index)).await {
+            Some(milestone) if milestone.timestamp() < threshold => target_index = Some(index),
+            _ => break,
+        }
+    }
+
+    target_index
+}
+
+pub(crate) async fn should_prune<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    storage: &B,
     ledger_index: LedgerIndex,
     pruning_delay: u32,
+    pruning_delay_min: u32,
     config: &PruningConfig,
-) -> Result<(MilestoneIndex, MilestoneIndex), PruningSkipReason> {
+) -> Result<(MilestoneIndex, MilestoneIndex, PruningReason), PruningSkipReason> {
     if config.disabled() {
         return Err(PruningSkipReason::Disabled);
     }
 
     let pruning_index = *tangle.get_pruning_index() + 1;
     let pruning_threshold = pruning_index + pruning_delay;
+    let below_threshold = PruningSkipReason::BelowThreshold {
+        reached_in: pruning_threshold.saturating_sub(*ledger_index),
+    };
+
+    // Whatever the policy, the minimum pruning delay is always kept.
+    let max_target_index = match (*ledger_index).checked_sub(pruning_delay_min) {
+        Some(max_target_index) if max_target_index >= pruning_index => max_target_index,
+        _ => return Err(below_threshold),
+    };
+    let batch_target_index = max_target_index.min(pruning_index + PRUNING_BATCH_SIZE_MAX);
 
-    if *ledger_index < pruning_threshold {
-        Err(PruningSkipReason::BelowThreshold {
-            reached_in: pruning_threshold - *ledger_index,
-        })
-    } else {
-        let target_pruning_index = *ledger_index - pruning_delay;
+    let mut target: Option<(u32, PruningReason)> = None;
+    let is_more_aggressive =
+        |target: &Option<(u32, PruningReason)>, index: u32| !matches!(target, Some((current, _)) if *current >= index);
+
+    if *ledger_index >= pruning_threshold {
+        target = Some((*ledger_index - pruning_delay, PruningReason::Delay));
+    }
+
+    if let Some(max_age) = config.max_age() {
+        if let Some(index) = max_age_target_index(tangle, pruning_index, batch_target_index, max_age).await {
+            if is_more_aggressive(&target, index) {
+                target = Some((index, PruningReason::MaxAge));
+            }
+        }
+    }
+
+    if let Some(target_size) = config.target_size() {
+        // Backends that can't report their size don't trigger size based pruning.
+        if let Ok(Some(size)) = storage.size() {
+            if size as u64 > target_size && is_more_aggressive(&target, max_target_index) {
+                target = Some((max_target_index, PruningReason::TargetSize { size: size as u64 }));
+            }
+        }
+    }
 
-        Ok((
+    match target {
+        Some((target_index, reason)) => Ok((
             pruning_index.into(),
-            if target_pruning_index > pruning_index + PRUNING_BATCH_SIZE_MAX {
-                (pruning_index + PRUNING_BATCH_SIZE_MAX).into()
-            } else {
-                target_pruning_index.into()
-            },
-        ))
+            target_index.min(pruning_index + PRUNING_BATCH_SIZE_MAX).into(),
+            reason,
+        )),
+        None => Err(below_threshold),
     }
 }
@@ -5,6 +5,8 @@
 
 use serde::Deserialize;
 
+use std::path::{Path, PathBuf};
+
 const DEFAULT_ENABLED: bool = true;
 const DEFAULT_DELAY: u32 = 60480;
 const DEFAULT_PRUNE_RECEIPTS: bool = false;
@@ -16,6 +18,9 @@ pub struct PruningConfigBuilder {
     enabled: Option<bool>,
     delay: Option<u32>,
     prune_receipts: Option<bool>,
+    target_size: Option<u64>,
+    max_age: Option<u64>,
+    archive_path: Option<PathBuf>,
 }
 
 impl PruningConfigBuilder {
@@ -42,6 +47,24 @@ impl PruningConfigBuilder {
         self
     }
 
+    /// Sets the database size, in bytes, above which pruning is triggered.
+    pub fn target_size(mut self, target_size: u64) -> Self {
+        self.target_size.replace(target_size);
+        self
+    }
+
+    /// Sets the age, in seconds, above which milestones are pruned.
+    pub fn max_age(mut self, max_age: u64) -> Self {
+        self.max_age.replace(max_age);
+        self
+    }
+
+    /// Sets the directory in which pruned data is archived before being deleted.
+    pub fn archive_path(mut self, archive_path: PathBuf) -> Self {
+        self.archive_path.replace(archive_path);
+        self
+    }
+
     /// Finishes the builder into a [`PruningConfig`].
     #[must_use]
     pub fn finish(self) -> PruningConfig {
@@ -49,6 +72,9 @@ impl PruningConfigBuilder {
             enabled: self.enabled.unwrap_or(DEFAULT_ENABLED),
             delay: self.delay.unwrap_or(DEFAULT_DELAY),
             prune_receipts: self.prune_receipts.unwrap_or(DEFAULT_PRUNE_RECEIPTS),
+            target_size: self.target_size,
+            max_age: self.max_age,
+            archive_path: self.archive_path,
         }
     }
 }
@@ -59,6 +85,9 @@ pub struct PruningConfig {
     enabled: bool,
     delay: u32,
     prune_receipts: bool,
+    target_size: Option<u64>,
+    max_age: Option<u64>,
+    archive_path: Option<PathBuf>,
 }
 
 impl PruningConfig {
@@ -86,4 +115,19 @@ impl PruningConfig {
     pub fn prune_receipts(&self) -> bool {
         self.prune_receipts
     }
+
+    /// Returns the database size, in bytes, above which pruning is triggered, if any.
+    pub fn target_size(&self) -> Option<u64> {
+        self.target_size
+    }
+
+    /// Returns the age, in seconds, above which milestones are pruned, if any.
+    pub fn max_age(&self) -> Option<u64> {
+        self.max_age
+    }
+
+    /// Returns the directory in which pruned data is archived before being deleted, if any.
+    pub fn archive_path(&self) -> Option<&Path> {
+        self.archive_path.as_deref()
+    }
 }
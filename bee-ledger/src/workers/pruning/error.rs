@@ -1,7 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_message::{milestone::MilestoneIndex, MessageId};
+use bee_message::{milestone::MilestoneIndex, payload::milestone::MilestoneId, MessageId};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -20,6 +20,12 @@ pub enum Error {
     MissingMetadata(MessageId),
     #[error("missing approvers for message {0}")]
     MissingApprovers(MessageId),
+    #[error("unspent treasury output created by milestone {0} would be pruned")]
+    UnspentTreasuryOutputPruned(MilestoneId),
+    #[error("expected one unspent treasury output, found {0}")]
+    InvalidUnspentTreasuryOutputCount(usize),
+    #[error("archiving failed: {0}")]
+    Archive(#[from] std::io::Error),
     #[error("storage operation failed due to: {0:?}")]
     Storage(Box<dyn std::error::Error + Send>),
 }
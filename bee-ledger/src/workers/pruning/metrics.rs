@@ -13,6 +13,7 @@ pub struct PruningMetrics {
     pub edges: usize,
     pub output_diffs: bool,
     pub receipts: usize,
+    pub treasury_outputs: usize,
 }
 
 #[derive(Debug, Default)]
@@ -41,6 +42,7 @@ pub struct UnconfirmedDataPruningMetrics {
 #[derive(Debug, Default)]
 pub struct MilestoneDataPruningMetrics {
     pub receipts: usize,
+    pub treasury_outputs: usize,
 }
 
 #[derive(Debug, Default)]
@@ -53,6 +55,7 @@ pub struct Timings {
     pub batch_unconfirmed_data: Duration,
     pub batch_milestone_data: Duration,
     pub batch_new_seps: Duration,
+    pub archive: Duration,
     pub truncate_curr_seps: Duration,
     pub batch_commit: Duration,
 }
@@ -10,4 +10,5 @@ mod metrics;
 pub(crate) mod condition;
 pub(crate) mod prune;
 
+pub mod archive;
 pub mod config;
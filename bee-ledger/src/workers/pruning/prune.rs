@@ -4,7 +4,9 @@
 use crate::workers::{
     event::PrunedIndex,
     pruning::{
+        archive::{ArchiveWriter, ArchivedMilestone},
         batch,
+        condition::PruningReason,
         config::PruningConfig,
         error::Error,
         metrics::{PruningMetrics, Timings},
@@ -35,6 +37,7 @@ pub async fn prune<S: StorageBackend>(
     bus: &Bus<'_>,
     start_index: MilestoneIndex,
     target_index: MilestoneIndex,
+    reason: PruningReason,
     config: &PruningConfig,
 ) -> Result<(), Error> {
     let mut timings = Timings::default();
@@ -47,10 +50,15 @@ pub async fn prune<S: StorageBackend>(
         });
     }
 
+    let mut archive_writer = config
+        .archive_path()
+        .map(|archive_path| ArchiveWriter::new(archive_path, start_index, target_index))
+        .transpose()?;
+
     if start_index != target_index {
         info!(
-            "Pruning from milestone {} to milestone {}...",
-            start_index, target_index
+            "Pruning from milestone {} to milestone {} ({})...",
+            start_index, target_index, reason
         );
     }
 
@@ -74,11 +82,19 @@ pub async fn prune<S: StorageBackend>(
         // Start a batch to make changes to the storage in a single atomic step.
         let mut batch = S::batch_begin();
 
+        // Collect the data to archive alongside the delete batch.
+        let mut archived_milestone = if archive_writer.is_some() {
+            Some(ArchivedMilestone::new(storage, index, config.prune_receipts())?)
+        } else {
+            None
+        };
+
         // Add confirmed data to the delete batch.
         // NOTE: This is the most costly thing during pruning, because it has to perform a past-cone traversal.
         let batch_confirmed_data = Instant::now();
         let (mut new_seps, confirmed_data_metrics) =
-            batch::prune_confirmed_data(tangle, storage, &mut batch, index, &curr_seps).await?;
+            batch::prune_confirmed_data(tangle, storage, &mut batch, index, &curr_seps, &mut archived_milestone)
+                .await?;
         timings.batch_confirmed_data = batch_confirmed_data.elapsed();
 
         metrics.new_seps = new_seps.len();
@@ -133,15 +149,26 @@ pub async fn prune<S: StorageBackend>(
         timings.batch_milestone_data = batch_milestones.elapsed();
 
         metrics.receipts = milestone_data_metrics.receipts;
+        metrics.treasury_outputs = milestone_data_metrics.treasury_outputs;
 
         // Add unconfirmed data to the delete batch.
         let batch_unconfirmed_data = Instant::now();
-        let unconfirmed_data_metrics = batch::prune_unconfirmed_data(storage, &mut batch, index).await?;
+        let unconfirmed_data_metrics =
+            batch::prune_unconfirmed_data(storage, &mut batch, index, &mut archived_milestone).await?;
         timings.batch_unconfirmed_data = batch_unconfirmed_data.elapsed();
 
         metrics.messages += unconfirmed_data_metrics.prunable_messages;
         metrics.edges += unconfirmed_data_metrics.prunable_edges;
 
+        // Archive the data before it gets deleted.
+        //
+        // **WARNING**: This operation must come before the batch is committed!
+        let archive = Instant::now();
+        if let (Some(archive_writer), Some(archived_milestone)) = (&mut archive_writer, &archived_milestone) {
+            archive_writer.write(archived_milestone)?;
+        }
+        timings.archive = archive.elapsed();
+
         // Remove old SEPs from the storage.
         //
         // **WARNING**: This operation must come before the batch is committed!
@@ -190,9 +217,12 @@ pub async fn prune<S: StorageBackend>(
     }
 
     if start_index == target_index {
-        info!("Pruned milestone {}.", start_index);
+        info!("Pruned milestone {} ({}).", start_index, reason);
     } else {
-        info!("Pruned from milestone {} to milestone {}.", start_index, target_index);
+        info!(
+            "Pruned from milestone {} to milestone {} ({}).",
+            start_index, target_index, reason
+        );
     }
 
     Ok(())
@@ -0,0 +1,157 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module containing the rollback of the ledger to an earlier milestone.
+
+use crate::{
+    types::{Migration, Receipt, TreasuryOutput},
+    workers::{
+        check::receipts_match_treasury_diff,
+        error::Error,
+        indexation::config::IndexationConfig,
+        snapshot::{
+            creation::{fetch_milestone, fetch_milestone_diff},
+            error::Error as SnapshotError,
+        },
+        storage::{self, StorageBackend},
+    },
+};
+
+use bee_message::{milestone::MilestoneIndex, payload::Payload, Message, MessageId};
+use bee_storage::access::{Batch, Fetch};
+use bee_tangle::metadata::MessageMetadata;
+
+use log::info;
+
+use std::collections::{HashSet, VecDeque};
+
+/// Resets the confirmation state of the messages referenced by the milestone with the given index, so that they can be
+/// referenced again when the milestone is confirmed again.
+///
+/// Returns the number of unreferenced messages.
+fn unreference_milestone_cone<B: StorageBackend>(
+    storage: &B,
+    batch: &mut B::Batch,
+    index: MilestoneIndex,
+    milestone_message_id: MessageId,
+) -> Result<usize, Error> {
+    let mut visited = HashSet::new();
+    let mut unreferenced = 0;
+    let mut to_visit: VecDeque<_> = vec![milestone_message_id].into_iter().collect();
+
+    while let Some(message_id) = to_visit.pop_front() {
+        if !visited.insert(message_id) {
+            continue;
+        }
+
+        // Solid entry points and pruned messages are not part of the cone anymore.
+        let mut metadata = match Fetch::<MessageId, MessageMetadata>::fetch(storage, &message_id)
+            .map_err(|e| Error::Storage(Box::new(e)))?
+        {
+            Some(metadata) => metadata,
+            None => continue,
+        };
+
+        // Messages referenced by earlier milestones are left untouched.
+        if !metadata.flags().is_referenced() || matches!(metadata.milestone_index(), Some(i) if i < index) {
+            continue;
+        }
+
+        // The milestone index has to be reset along with the flag, otherwise it would not be set again when the
+        // message is referenced again and would be mistaken for the one of an earlier milestone by a later rollback.
+        metadata.reset_confirmation();
+        Batch::<MessageId, MessageMetadata>::batch_insert(storage, batch, &message_id, &metadata)
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+        unreferenced += 1;
+
+        if let Some(message) =
+            Fetch::<MessageId, Message>::fetch(storage, &message_id).map_err(|e| Error::Storage(Box::new(e)))?
+        {
+            to_visit.extend(message.parents().iter());
+        }
+    }
+
+    Ok(unreferenced)
+}
+
+/// Rolls the ledger back from the current ledger index to the given target index by reverting the output diffs of the
+/// milestones above it, and unreferences the messages of these milestones so that they can be confirmed again.
+///
+/// Every milestone is rolled back in a single atomic step, so that an interrupted rollback can simply be resumed.
+/// Nothing is written if the output diff of any milestone of the range is missing or if its receipts don't match its
+/// treasury transition.
+///
+/// Returns the number of rolled back milestones.
+pub fn rollback_ledger<B: StorageBackend>(
+    storage: &B,
+    target_index: MilestoneIndex,
+    indexation_config: &IndexationConfig,
+) -> Result<usize, Error> {
+    let ledger_index = MilestoneIndex(*storage::fetch_ledger_index(storage)?.ok_or(Error::MissingLedgerIndex)?);
+
+    if target_index > ledger_index {
+        return Err(Error::InvalidRollbackTargetIndex(target_index, ledger_index));
+    }
+
+    // A pruned milestone or a milestone whose receipts don't match its treasury transition can't be rolled back, so the
+    // whole range is checked before rolling back its first milestone.
+    let mut receipts = storage::fetch_receipts_by_milestone(storage)?;
+
+    for index in *target_index + 1..=*ledger_index {
+        let index = MilestoneIndex(index);
+        let output_diff = storage::fetch_output_diff(storage, index)?
+            .ok_or(Error::Snapshot(SnapshotError::MissingOutputDiff(index)))?;
+
+        if !receipts_match_treasury_diff(storage, &output_diff, &receipts.remove(&index).unwrap_or_default())? {
+            return Err(Error::ReceiptTreasuryMismatch(index));
+        }
+    }
+
+    for index in (*target_index + 1..=*ledger_index).rev() {
+        let index = MilestoneIndex(index);
+        let diff = fetch_milestone_diff(storage, index)?;
+
+        let migration = match (diff.milestone().essence().receipt(), diff.consumed_treasury()) {
+            (Some(Payload::Receipt(receipt)), Some((consumed_treasury, consumed_milestone_id))) => {
+                let created_treasury = storage::fetch_treasury_output(storage, &diff.milestone().id())?.ok_or(
+                    Error::Snapshot(SnapshotError::MissingTreasuryOutput(diff.milestone().id())),
+                )?;
+
+                Some(Migration::new(
+                    Receipt::new(receipt.as_ref().clone(), index),
+                    TreasuryOutput::new(consumed_treasury.clone(), *consumed_milestone_id),
+                    created_treasury,
+                ))
+            }
+            (Some(_), _) => return Err(Error::Snapshot(SnapshotError::MissingConsumedTreasury)),
+            (None, _) => None,
+        };
+
+        let milestone_message_id = *fetch_milestone(storage, index)?.message_id();
+
+        let mut batch = B::batch_begin();
+
+        let unreferenced = unreference_milestone_cone(storage, &mut batch, index, milestone_message_id)?;
+
+        storage::rollback_milestone_batch(
+            storage,
+            &mut batch,
+            index,
+            diff.created(),
+            diff.consumed(),
+            &migration,
+            indexation_config,
+        )?;
+
+        storage
+            .batch_commit(batch, true)
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+
+        info!(
+            "Rolled back milestone {}, unreferenced {} messages.",
+            index, unreferenced
+        );
+    }
+
+    Ok((*ledger_index - *target_index) as usize)
+}
@@ -13,15 +13,28 @@ const DEFAULT_DOWNLOAD_URLS: Vec<DownloadUrls> = Vec::new();
 const DEFAULT_DEPTH: u32 = 50;
 const DEFAULT_INTERVAL_SYNCED: u32 = 50;
 const DEFAULT_INTERVAL_UNSYNCED: u32 = 1000;
+const DEFAULT_DELTA_SIZE_THRESHOLD: f64 = 50.0;
 
-/// Contains URLs to download the full and delta snapshot files.
+/// Contains URLs to download the full and delta snapshot files, along with their optional published SHA-256 checksums.
 #[derive(Clone, Deserialize)]
 pub struct DownloadUrls {
     full: Url,
     delta: Url,
+    full_sha256: Option<String>,
+    delta_sha256: Option<String>,
 }
 
 impl DownloadUrls {
+    /// Creates a new `DownloadUrls`.
+    pub fn new(full: Url, delta: Url, full_sha256: Option<String>, delta_sha256: Option<String>) -> Self {
+        Self {
+            full,
+            delta,
+            full_sha256,
+            delta_sha256,
+        }
+    }
+
     /// Returns the download URL for the full snapshot.
     pub fn full(&self) -> &str {
         self.full.as_str()
@@ -31,6 +44,16 @@ impl DownloadUrls {
     pub fn delta(&self) -> &str {
         self.delta.as_str()
     }
+
+    /// Returns the hex encoded SHA-256 checksum of the full snapshot, if published.
+    pub fn full_sha256(&self) -> Option<&str> {
+        self.full_sha256.as_deref()
+    }
+
+    /// Returns the hex encoded SHA-256 checksum of the delta snapshot, if published.
+    pub fn delta_sha256(&self) -> Option<&str> {
+        self.delta_sha256.as_deref()
+    }
 }
 
 /// Builder for a `SnapshotConfig`.
@@ -43,6 +66,7 @@ pub struct SnapshotConfigBuilder {
     depth: Option<u32>,
     interval_synced: Option<u32>,
     interval_unsynced: Option<u32>,
+    delta_size_threshold: Option<f64>,
 }
 
 impl SnapshotConfigBuilder {
@@ -87,6 +111,12 @@ impl SnapshotConfigBuilder {
         self
     }
 
+    /// Sets the delta size threshold of the `SnapshotConfigBuilder`.
+    pub fn delta_size_threshold(mut self, delta_size_threshold: f64) -> Self {
+        self.delta_size_threshold.replace(delta_size_threshold);
+        self
+    }
+
     /// Finishes the `SnapshotConfigBuilder` into a `SnapshotConfig`.
     #[must_use]
     pub fn finish(self) -> SnapshotConfig {
@@ -99,6 +129,7 @@ impl SnapshotConfigBuilder {
             depth: self.depth.unwrap_or(DEFAULT_DEPTH),
             interval_synced: self.interval_synced.unwrap_or(DEFAULT_INTERVAL_SYNCED),
             interval_unsynced: self.interval_unsynced.unwrap_or(DEFAULT_INTERVAL_UNSYNCED),
+            delta_size_threshold: self.delta_size_threshold.unwrap_or(DEFAULT_DELTA_SIZE_THRESHOLD),
         }
     }
 }
@@ -112,6 +143,7 @@ pub struct SnapshotConfig {
     depth: u32,
     interval_synced: u32,
     interval_unsynced: u32,
+    delta_size_threshold: f64,
 }
 
 impl SnapshotConfig {
@@ -149,4 +181,12 @@ impl SnapshotConfig {
     pub fn interval_unsynced(&self) -> u32 {
         self.interval_unsynced
     }
+
+    /// Returns the delta size threshold of the `SnapshotConfig`.
+    ///
+    /// A delta snapshot is only written if its size doesn't exceed this percentage of the full snapshot size, a new
+    /// full snapshot is written otherwise.
+    pub fn delta_size_threshold(&self) -> f64 {
+        self.delta_size_threshold
+    }
 }
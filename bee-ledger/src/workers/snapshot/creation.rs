@@ -0,0 +1,573 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    types::{
+        snapshot::{DeltaSnapshotHeader, FullSnapshotHeader, MilestoneDiff, SnapshotHeader, SnapshotKind},
+        TreasuryOutput, Unspent,
+    },
+    workers::{
+        error::Error,
+        snapshot::{config::SnapshotConfig, error::Error as SnapshotError},
+        storage::{self, StorageBackend},
+    },
+};
+
+use bee_message::{
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::Payload,
+    Message, MessageId,
+};
+use bee_storage::access::{AsIterator, Fetch};
+use bee_tangle::{metadata::MessageMetadata, solid_entry_point::SolidEntryPoint};
+
+use log::info;
+use packable::{
+    packer::{IoPacker, Packer},
+    unpacker::IoUnpacker,
+    Packable, PackableExt,
+};
+use ref_cast::RefCast;
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fs::{self, File, OpenOptions},
+    io::{self, BufReader, BufWriter},
+    path::{Path, PathBuf},
+};
+
+fn snapshot_writer(path: &Path) -> Result<IoPacker<BufWriter<File>>, Error> {
+    fs::create_dir_all(
+        path.parent()
+            .ok_or_else(|| SnapshotError::InvalidFilePath(format!("{}", path.display())))?,
+    )
+    .map_err(|e| Error::Snapshot(SnapshotError::Io(e)))?;
+
+    Ok(IoPacker::new(BufWriter::new(
+        OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .map_err(|e| Error::Snapshot(SnapshotError::Io(e)))?,
+    )))
+}
+
+fn finish_snapshot_writer(packer: IoPacker<BufWriter<File>>) -> Result<(), Error> {
+    let file = packer
+        .into_inner()
+        .into_inner()
+        .map_err(|e| Error::Snapshot(SnapshotError::Io(e.into_error())))?;
+
+    file.sync_all().map_err(|e| Error::Snapshot(SnapshotError::Io(e)))
+}
+
+/// Returns the path a snapshot file is written to before being moved to its final location.
+fn temporary_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".tmp");
+
+    path.with_file_name(file_name)
+}
+
+fn remove_file_if_exists(path: &Path) -> Result<(), Error> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(Error::Snapshot(SnapshotError::Io(e))),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the solid entry point index of the full snapshot file a delta snapshot file can be based on, if any.
+fn read_full_snapshot_sep_index(path: &Path, network_id: u64) -> Result<Option<MilestoneIndex>, Error> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let mut unpacker = IoUnpacker::new(BufReader::new(
+        OpenOptions::new()
+            .read(true)
+            .open(path)
+            .map_err(|e| Error::Snapshot(SnapshotError::Io(e)))?,
+    ));
+    let header = SnapshotHeader::unpack::<_, true>(&mut unpacker)?;
+
+    if header.kind() == SnapshotKind::Full && header.network_id() == network_id {
+        Ok(Some(header.sep_index()))
+    } else {
+        Ok(None)
+    }
+}
+
+pub(crate) fn fetch_milestone<B: StorageBackend>(storage: &B, index: MilestoneIndex) -> Result<Milestone, Error> {
+    Fetch::<MilestoneIndex, Milestone>::fetch(storage, &index)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .ok_or(Error::Snapshot(SnapshotError::MissingMilestone(index)))
+}
+
+/// Collects the solid entry points of a snapshot with the given target index.
+///
+/// These are the messages confirmed within the last `below_max_depth` milestones up to the target index that are still
+/// referenced by messages confirmed after it, as well as the current solid entry points that are still referenced.
+fn collect_solid_entry_points<B: StorageBackend>(
+    storage: &B,
+    below_max_depth: u32,
+    target_index: MilestoneIndex,
+) -> Result<HashSet<SolidEntryPoint>, Error> {
+    let current_seps = AsIterator::<SolidEntryPoint, MilestoneIndex>::iter(storage)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .map(|result| result.map_err(|e| Error::Storage(Box::new(e))))
+        .collect::<Result<HashMap<SolidEntryPoint, MilestoneIndex>, _>>()?;
+    let mut seps = current_seps
+        .iter()
+        .filter(|(_, index)| **index > target_index)
+        .map(|(sep, _)| *sep)
+        .collect::<HashSet<_>>();
+    let mut visited = HashSet::new();
+
+    for index in (*target_index).saturating_sub(below_max_depth)..=*target_index {
+        let index = MilestoneIndex(index);
+        let milestone = match Fetch::<MilestoneIndex, Milestone>::fetch(storage, &index)
+            .map_err(|e| Error::Storage(Box::new(e)))?
+        {
+            Some(milestone) => milestone,
+            None => continue,
+        };
+        let mut to_visit: VecDeque<_> = vec![*milestone.message_id()].into_iter().collect();
+
+        while let Some(message_id) = to_visit.pop_front() {
+            if !visited.insert(message_id) || current_seps.contains_key(SolidEntryPoint::ref_cast(&message_id)) {
+                continue;
+            }
+
+            // Messages confirmed by an older milestone are not part of the cone of this one.
+            match Fetch::<MessageId, MessageMetadata>::fetch(storage, &message_id)
+                .map_err(|e| Error::Storage(Box::new(e)))?
+            {
+                Some(metadata) if metadata.milestone_index() == Some(index) => {}
+                _ => continue,
+            }
+
+            if let Some(message) =
+                Fetch::<MessageId, Message>::fetch(storage, &message_id).map_err(|e| Error::Storage(Box::new(e)))?
+            {
+                to_visit.extend(message.parents().iter());
+            }
+
+            let approvers = Fetch::<MessageId, Vec<MessageId>>::fetch(storage, &message_id)
+                .map_err(|e| Error::Storage(Box::new(e)))?
+                .unwrap_or_default();
+
+            for approver in approvers {
+                let approver_index = Fetch::<MessageId, MessageMetadata>::fetch(storage, &approver)
+                    .map_err(|e| Error::Storage(Box::new(e)))?
+                    .and_then(|metadata| metadata.milestone_index());
+
+                if matches!(approver_index, Some(approver_index) if approver_index > target_index) {
+                    seps.insert(message_id.into());
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(seps)
+}
+
+/// Collects the unspent outputs and the unspent treasury output along with the ledger index they are consistent with.
+///
+/// Confirmation goes on while the unspent outputs are being read, the changes of milestones confirmed in the meantime
+/// are therefore reverted. If a ledger index is given, the changes of all milestones confirmed after it are reverted as
+/// well.
+fn collect_ledger_state<B: StorageBackend>(
+    storage: &B,
+    ledger_index: Option<MilestoneIndex>,
+) -> Result<(MilestoneIndex, Vec<OutputId>, TreasuryOutput), Error> {
+    // Unwrap is fine because ledger index was already in storage or just added by the snapshot worker.
+    let read_ledger_index = MilestoneIndex(*storage::fetch_ledger_index(storage)?.unwrap());
+    let ledger_index = match ledger_index {
+        Some(ledger_index) if ledger_index > read_ledger_index => {
+            return Err(Error::Snapshot(SnapshotError::LedgerSepIndexesInconsistency(
+                read_ledger_index,
+                ledger_index,
+            )));
+        }
+        Some(ledger_index) => ledger_index,
+        None => read_ledger_index,
+    };
+
+    let mut output_ids = AsIterator::<Unspent, ()>::iter(storage)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .map(|result| {
+            result
+                .map(|(unspent, _)| *unspent)
+                .map_err(|e| Error::Storage(Box::new(e)))
+        })
+        .collect::<Result<HashSet<_>, _>>()?;
+    let mut treasury_output = storage::fetch_unspent_treasury_output(storage)?;
+
+    // Unwrap is fine because ledger index was already in storage or just added by the snapshot worker.
+    let current_ledger_index = MilestoneIndex(*storage::fetch_ledger_index(storage)?.unwrap());
+
+    let mut created_outputs = HashSet::new();
+    let mut consumed_outputs = Vec::new();
+    let mut treasury_reverted = false;
+
+    for index in *ledger_index + 1..=*current_ledger_index {
+        let index = MilestoneIndex(index);
+        let output_diff = storage::fetch_output_diff(storage, index)?
+            .ok_or(Error::Snapshot(SnapshotError::MissingOutputDiff(index)))?;
+
+        created_outputs.extend(output_diff.created_outputs().iter().copied());
+        consumed_outputs.extend(output_diff.consumed_outputs().iter().copied());
+
+        if let Some(treasury_diff) = output_diff.treasury_diff() {
+            if !treasury_reverted {
+                treasury_output = storage::fetch_treasury_output(storage, treasury_diff.consumed())?.ok_or(
+                    Error::Snapshot(SnapshotError::MissingTreasuryOutput(*treasury_diff.consumed())),
+                )?;
+                treasury_reverted = true;
+            }
+        }
+    }
+
+    output_ids.retain(|output_id| !created_outputs.contains(output_id));
+    output_ids.extend(
+        consumed_outputs
+            .into_iter()
+            .filter(|output_id| !created_outputs.contains(output_id)),
+    );
+
+    Ok((ledger_index, output_ids.into_iter().collect(), treasury_output))
+}
+
+pub(crate) fn fetch_milestone_diff<B: StorageBackend>(
+    storage: &B,
+    index: MilestoneIndex,
+) -> Result<MilestoneDiff, Error> {
+    let output_diff =
+        storage::fetch_output_diff(storage, index)?.ok_or(Error::Snapshot(SnapshotError::MissingOutputDiff(index)))?;
+    let message_id = *fetch_milestone(storage, index)?.message_id();
+    let message = Fetch::<MessageId, Message>::fetch(storage, &message_id)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .ok_or(Error::MilestoneMessageNotFound(message_id))?;
+    let milestone = match message.payload() {
+        Some(Payload::Milestone(milestone)) => milestone.as_ref().clone(),
+        _ => return Err(Error::NoMilestonePayload),
+    };
+
+    let created_outputs = output_diff
+        .created_outputs()
+        .iter()
+        .map(|output_id| {
+            let created_output = storage::fetch_output(storage, output_id)?
+                .ok_or(Error::Snapshot(SnapshotError::MissingOutput(*output_id)))?;
+            Ok((*output_id, created_output))
+        })
+        .collect::<Result<HashMap<_, _>, Error>>()?;
+
+    let consumed_outputs = output_diff
+        .consumed_outputs()
+        .iter()
+        .map(|output_id| {
+            let created_output = storage::fetch_output(storage, output_id)?
+                .ok_or(Error::Snapshot(SnapshotError::MissingOutput(*output_id)))?;
+            let consumed_output = storage::fetch_consumed_output(storage, output_id)?
+                .ok_or(Error::Snapshot(SnapshotError::MissingOutput(*output_id)))?;
+            Ok((*output_id, (created_output, consumed_output)))
+        })
+        .collect::<Result<HashMap<_, _>, Error>>()?;
+
+    let consumed_treasury = match output_diff.treasury_diff() {
+        Some(treasury_diff) => {
+            let treasury_output = storage::fetch_treasury_output(storage, treasury_diff.consumed())?.ok_or(
+                Error::Snapshot(SnapshotError::MissingTreasuryOutput(*treasury_diff.consumed())),
+            )?;
+            Some((treasury_output.inner().clone(), *treasury_output.milestone_id()))
+        }
+        None => None,
+    };
+
+    Ok(MilestoneDiff::new(
+        milestone,
+        consumed_treasury,
+        created_outputs,
+        consumed_outputs,
+    )?)
+}
+
+fn write_solid_entry_points<P: Packer<Error = io::Error>>(
+    packer: &mut P,
+    seps: &HashSet<SolidEntryPoint>,
+) -> Result<(), Error> {
+    for sep in seps {
+        sep.pack(packer)?;
+    }
+
+    Ok(())
+}
+
+fn write_outputs<P: Packer<Error = io::Error>, B: StorageBackend>(
+    packer: &mut P,
+    storage: &B,
+    output_ids: &[OutputId],
+) -> Result<(), Error> {
+    for output_id in output_ids {
+        let output = storage::fetch_output(storage, output_id)?
+            .ok_or(Error::Snapshot(SnapshotError::MissingOutput(*output_id)))?;
+
+        output.message_id().pack(packer)?;
+        output_id.pack(packer)?;
+        output.milestone_index().pack(packer)?;
+        output.milestone_timestamp().pack(packer)?;
+        // This length prefix is currently needed for hornet/iota.go and not for bee but still needs to be written.
+        (output.inner().packed_len() as u32).pack(packer)?;
+        output.inner().pack(packer)?;
+    }
+
+    Ok(())
+}
+
+fn write_milestone_diffs<P: Packer<Error = io::Error>, B: StorageBackend>(
+    packer: &mut P,
+    storage: &B,
+    indexes: impl Iterator<Item = u32>,
+) -> Result<(), Error> {
+    for index in indexes {
+        fetch_milestone_diff(storage, MilestoneIndex(index))?.pack(packer)?;
+    }
+
+    Ok(())
+}
+
+fn write_full_snapshot<B: StorageBackend>(
+    storage: &B,
+    path: &Path,
+    network_id: u64,
+    timestamp: u64,
+    target_index: MilestoneIndex,
+    ledger_index: Option<MilestoneIndex>,
+    seps: &HashSet<SolidEntryPoint>,
+) -> Result<(), Error> {
+    let (ledger_index, output_ids, treasury_output) = collect_ledger_state(storage, ledger_index)?;
+
+    if ledger_index < target_index {
+        return Err(Error::Snapshot(SnapshotError::LedgerSepIndexesInconsistency(
+            ledger_index,
+            target_index,
+        )));
+    }
+
+    let header = SnapshotHeader::new(SnapshotKind::Full, timestamp, network_id, target_index, ledger_index);
+    let full_header = FullSnapshotHeader::new(
+        seps.len() as u64,
+        output_ids.len() as u64,
+        *(ledger_index - target_index) as u64,
+        *treasury_output.milestone_id(),
+        treasury_output.inner().amount(),
+    );
+
+    let mut packer = snapshot_writer(path)?;
+
+    header.pack(&mut packer)?;
+    full_header.pack(&mut packer)?;
+    write_solid_entry_points(&mut packer, seps)?;
+    write_outputs(&mut packer, storage, &output_ids)?;
+    // Full snapshots contain the milestone diffs to roll the ledger back from the ledger index to the target index.
+    write_milestone_diffs(&mut packer, storage, (*target_index + 1..=*ledger_index).rev())?;
+
+    finish_snapshot_writer(packer)?;
+
+    info!(
+        "Created full snapshot file {} with sep index {}, ledger index {}, {} solid entry points, {} outputs and {} milestone diffs.",
+        path.display(),
+        *target_index,
+        *ledger_index,
+        full_header.sep_count(),
+        full_header.output_count(),
+        full_header.milestone_diff_count()
+    );
+
+    Ok(())
+}
+
+fn write_delta_snapshot<B: StorageBackend>(
+    storage: &B,
+    path: &Path,
+    network_id: u64,
+    timestamp: u64,
+    full_sep_index: MilestoneIndex,
+    target_index: MilestoneIndex,
+    seps: &HashSet<SolidEntryPoint>,
+) -> Result<(), Error> {
+    let header = SnapshotHeader::new(SnapshotKind::Delta, timestamp, network_id, target_index, full_sep_index);
+    let delta_header = DeltaSnapshotHeader::new(seps.len() as u64, *(target_index - full_sep_index) as u64);
+
+    let mut packer = snapshot_writer(path)?;
+
+    header.pack(&mut packer)?;
+    delta_header.pack(&mut packer)?;
+    write_solid_entry_points(&mut packer, seps)?;
+    // Delta snapshots contain the milestone diffs to apply on top of the full snapshot up to the target index.
+    write_milestone_diffs(&mut packer, storage, *full_sep_index + 1..=*target_index)?;
+
+    finish_snapshot_writer(packer)?;
+
+    info!(
+        "Created delta snapshot file {} with sep index {}, ledger index {}, {} solid entry points and {} milestone diffs.",
+        path.display(),
+        *target_index,
+        *full_sep_index,
+        delta_header.sep_count(),
+        delta_header.milestone_diff_count()
+    );
+
+    Ok(())
+}
+
+/// Tries to write a delta snapshot on top of the existing full snapshot and returns whether it was kept.
+fn try_delta_snapshot<B: StorageBackend>(
+    storage: &B,
+    config: &SnapshotConfig,
+    delta_path: &Path,
+    network_id: u64,
+    timestamp: u64,
+    target_index: MilestoneIndex,
+    seps: &HashSet<SolidEntryPoint>,
+) -> Result<bool, Error> {
+    let full_sep_index = match read_full_snapshot_sep_index(config.full_path(), network_id)? {
+        Some(full_sep_index) if full_sep_index < target_index => full_sep_index,
+        _ => return Ok(false),
+    };
+
+    // The milestone diffs following the full snapshot may already have been pruned.
+    if storage::fetch_output_diff(storage, full_sep_index + 1)?.is_none() {
+        return Ok(false);
+    }
+
+    let temporary_delta_path = temporary_path(delta_path);
+
+    write_delta_snapshot(
+        storage,
+        &temporary_delta_path,
+        network_id,
+        timestamp,
+        full_sep_index,
+        target_index,
+        seps,
+    )?;
+
+    let full_size = fs::metadata(config.full_path())
+        .map_err(|e| Error::Snapshot(SnapshotError::Io(e)))?
+        .len();
+    let delta_size = fs::metadata(&temporary_delta_path)
+        .map_err(|e| Error::Snapshot(SnapshotError::Io(e)))?
+        .len();
+
+    if delta_size as f64 > full_size as f64 * config.delta_size_threshold() / 100.0 {
+        info!(
+            "Delta snapshot size exceeds {}% of the full snapshot size, creating a full snapshot instead.",
+            config.delta_size_threshold()
+        );
+        remove_file_if_exists(&temporary_delta_path)?;
+
+        return Ok(false);
+    }
+
+    fs::rename(&temporary_delta_path, delta_path).map_err(|e| Error::Snapshot(SnapshotError::Io(e)))?;
+
+    Ok(true)
+}
+
+/// Creates a snapshot file with the given target index.
+///
+/// A delta snapshot is created if a delta path is configured and it can be based on the existing full snapshot, a full
+/// snapshot is created otherwise. Files are first written to a temporary location and then moved so that an existing
+/// snapshot is never left partially written.
+pub(crate) fn create_snapshot<B: StorageBackend>(
+    storage: &B,
+    config: &SnapshotConfig,
+    below_max_depth: u32,
+    target_index: MilestoneIndex,
+) -> Result<(), Error> {
+    let mut snapshot_info =
+        storage::fetch_snapshot_info(storage)?.ok_or(Error::Snapshot(SnapshotError::MissingSnapshotInfo))?;
+    let network_id = snapshot_info.network_id();
+    let timestamp = fetch_milestone(storage, target_index)?.timestamp();
+    let seps = collect_solid_entry_points(storage, below_max_depth, target_index)?;
+
+    let delta_created = match config.delta_path() {
+        Some(delta_path) => {
+            try_delta_snapshot(storage, config, delta_path, network_id, timestamp, target_index, &seps)?
+        }
+        None => false,
+    };
+
+    if !delta_created {
+        let temporary_full_path = temporary_path(config.full_path());
+
+        write_full_snapshot(
+            storage,
+            &temporary_full_path,
+            network_id,
+            timestamp,
+            target_index,
+            None,
+            &seps,
+        )?;
+
+        // A delta snapshot is only valid on top of the full snapshot it was created from.
+        if let Some(delta_path) = config.delta_path() {
+            remove_file_if_exists(delta_path)?;
+        }
+
+        fs::rename(&temporary_full_path, config.full_path()).map_err(|e| Error::Snapshot(SnapshotError::Io(e)))?;
+    }
+
+    snapshot_info.update_snapshot_index(target_index);
+    snapshot_info.update_timestamp(timestamp);
+    storage::insert_snapshot_info(storage, &snapshot_info)?;
+
+    Ok(())
+}
+
+/// Returns the unspent outputs of a storage at the given index.
+///
+/// The ledger state is rolled back from the current ledger index to the given index using the stored output diffs.
+pub fn unspent_outputs_at<B: StorageBackend>(storage: &B, index: MilestoneIndex) -> Result<Vec<OutputId>, Error> {
+    let (_, output_ids, _) = collect_ledger_state(storage, Some(index))?;
+
+    Ok(output_ids)
+}
+
+/// Generates a full snapshot file at the given index from the content of a storage.
+///
+/// The ledger state is rolled back from the current ledger index to the given index using the stored output diffs, the
+/// resulting snapshot therefore has the given index as both its sep index and ledger index. This is meant to be used on
+/// the storage of a stopped node and never writes to it.
+pub fn generate_full_snapshot<B: StorageBackend>(
+    storage: &B,
+    path: &Path,
+    below_max_depth: u32,
+    index: MilestoneIndex,
+) -> Result<(), Error> {
+    let snapshot_info =
+        storage::fetch_snapshot_info(storage)?.ok_or(Error::Snapshot(SnapshotError::MissingSnapshotInfo))?;
+    let timestamp = fetch_milestone(storage, index)?.timestamp();
+    let seps = collect_solid_entry_points(storage, below_max_depth, index)?;
+    let temporary_path = temporary_path(path);
+
+    write_full_snapshot(
+        storage,
+        &temporary_path,
+        snapshot_info.network_id(),
+        timestamp,
+        index,
+        Some(index),
+        &seps,
+    )?;
+
+    fs::rename(&temporary_path, path).map_err(|e| Error::Snapshot(SnapshotError::Io(e)))?;
+
+    Ok(())
+}
@@ -9,12 +9,20 @@ use crate::{
 use bee_message::milestone::MilestoneIndex;
 
 use bytes::Buf;
+use crypto::hashes::sha::Sha256;
+use digest::Digest;
 use futures::{future::join_all, StreamExt};
 use log::{debug, info, warn};
 use packable::{error::UnpackError, PackableExt};
-use reqwest::Response;
+use reqwest::{header::RANGE, Response, StatusCode};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
-use std::{io::Read, path::Path};
+use std::{
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+const DOWNLOAD_ATTEMPTS: usize = 3;
 
 async fn download_snapshot_header(download_url: &str) -> Result<SnapshotHeader, Error> {
     debug!("Downloading snapshot header {}...", download_url);
@@ -71,10 +79,16 @@ impl<'a> SourceInformation<'a> {
         full_snapshot_path: &Path,
         delta_snapshot_path: Option<&Path>,
     ) -> Result<(), Error> {
-        download_snapshot_file(full_snapshot_path, self.urls.full()).await?;
-
-        if let (Some(delta_path), Some(_)) = (delta_snapshot_path, &self.delta_header) {
-            download_snapshot_file(delta_path, self.urls.delta()).await?;
+        download_snapshot_file(
+            full_snapshot_path,
+            self.urls.full(),
+            &self.full_header,
+            self.urls.full_sha256(),
+        )
+        .await?;
+
+        if let (Some(delta_path), Some(delta_header)) = (delta_snapshot_path, &self.delta_header) {
+            download_snapshot_file(delta_path, self.urls.delta(), delta_header, self.urls.delta_sha256()).await?;
         }
 
         Ok(())
@@ -155,7 +169,123 @@ async fn gather_source_information(
     })
 }
 
-async fn download_snapshot_file(path: &Path, download_url: &str) -> Result<(), Error> {
+/// Returns the path a snapshot file is downloaded to before being verified and moved to its final location.
+fn partial_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".part");
+
+    path.with_file_name(file_name)
+}
+
+/// Returns the length of the partially downloaded file if it can be resumed, i.e. if it starts with the expected
+/// header.
+async fn resumable_length(partial_path: &Path, header: &SnapshotHeader) -> Result<u64, Error> {
+    let mut file = match tokio::fs::File::open(partial_path).await {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(Error::Io(e)),
+    };
+    let length = file.metadata().await?.len();
+
+    if length < SnapshotHeader::LENGTH as u64 {
+        return Ok(0);
+    }
+
+    let mut bytes = vec![0u8; SnapshotHeader::LENGTH];
+    file.read_exact(&mut bytes).await?;
+
+    // The file behind the URL has changed since the partial download, it can't be resumed.
+    if bytes != header.pack_to_vec() {
+        return Ok(0);
+    }
+
+    Ok(length)
+}
+
+/// Downloads a snapshot file to its partial path, resuming from the already downloaded bytes if possible.
+async fn download_snapshot_file_part(
+    partial_path: &Path,
+    download_url: &str,
+    header: &SnapshotHeader,
+) -> Result<(), Error> {
+    let offset = resumable_length(partial_path, header).await?;
+    let mut request = reqwest::Client::new().get(download_url);
+
+    if offset > 0 {
+        debug!(
+            "Resuming download of snapshot file {} from byte {}.",
+            download_url, offset
+        );
+        request = request.header(RANGE, format!("bytes={}-", offset));
+    }
+
+    let res = request.send().await.map_err(|e| {
+        warn!("Downloading snapshot file failed: {:?}.", e.to_string());
+        Error::DownloadingFailed
+    })?;
+
+    let mut file = match res.status() {
+        StatusCode::PARTIAL_CONTENT if offset > 0 => {
+            tokio::fs::OpenOptions::new().append(true).open(partial_path).await?
+        }
+        // The partial file was already complete.
+        StatusCode::RANGE_NOT_SATISFIABLE if offset > 0 => return Ok(()),
+        status if status.is_success() => tokio::fs::File::create(partial_path).await?,
+        status => {
+            warn!("Downloading snapshot file failed with status code {:?}.", status);
+            return Err(Error::DownloadingFailed);
+        }
+    };
+
+    let mut stream = res.bytes_stream();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| {
+            warn!("Downloading snapshot file interrupted: {:?}.", e.to_string());
+            Error::DownloadingFailed
+        })?;
+        file.write_all(&chunk).await?;
+    }
+
+    file.flush().await?;
+
+    Ok(())
+}
+
+async fn verify_sha256(path: &Path, download_url: &str, expected: &str) -> Result<(), Error> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buffer).await?;
+
+        if read == 0 {
+            break;
+        }
+
+        hasher.update(&buffer[..read]);
+    }
+
+    let computed = hex::encode(hasher.finalize());
+
+    if !computed.eq_ignore_ascii_case(expected) {
+        return Err(Error::ChecksumMismatch(
+            download_url.to_owned(),
+            expected.to_owned(),
+            computed,
+        ));
+    }
+
+    Ok(())
+}
+
+async fn download_snapshot_file(
+    path: &Path,
+    download_url: &str,
+    header: &SnapshotHeader,
+    sha256: Option<&str>,
+) -> Result<(), Error> {
     tokio::fs::create_dir_all(
         path.parent()
             .ok_or_else(|| Error::InvalidFilePath(format!("{}", path.display())))?,
@@ -165,24 +295,40 @@ async fn download_snapshot_file(path: &Path, download_url: &str) -> Result<(), E
 
     info!("Downloading snapshot file {}...", download_url);
 
-    match reqwest::get(download_url).await {
-        Ok(res) => {
-            if res.status().is_success() {
-                tokio::io::copy(
-                    &mut res.bytes().await.map_err(|_| Error::DownloadingFailed)?.as_ref(),
-                    &mut tokio::fs::File::create(path).await?,
-                )
-                .await?;
-            } else {
-                warn!("Downloading snapshot file failed with status code {:?}.", res.status());
-            }
+    let partial_path = partial_path(path);
+    let mut attempt = 1;
+
+    while let Err(e) = download_snapshot_file_part(&partial_path, download_url, header).await {
+        if attempt == DOWNLOAD_ATTEMPTS {
+            return Err(e);
         }
-        Err(e) => warn!("Downloading snapshot failed: {:?}.", e.to_string()),
+
+        attempt += 1;
+        info!(
+            "Retrying download of snapshot file {} ({}/{})...",
+            download_url, attempt, DOWNLOAD_ATTEMPTS
+        );
     }
 
+    if let Some(sha256) = sha256 {
+        if let Err(e) = verify_sha256(&partial_path, download_url, sha256).await {
+            remove_file_if_exists(&partial_path).await?;
+            return Err(e);
+        }
+    }
+
+    tokio::fs::rename(&partial_path, path).await?;
+
     Ok(())
 }
 
+async fn remove_file_if_exists(path: &Path) -> Result<(), Error> {
+    match tokio::fs::remove_file(path).await {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(Error::Io(e)),
+        _ => Ok(()),
+    }
+}
+
 /// Tries to download the latest snapshot files from the sources specified in the `SnapshotConfig`.
 ///
 /// * `wanted_network_id` - The id of the current network (typically the hash of the network name).
@@ -214,14 +360,206 @@ pub(crate) async fn download_latest_snapshot_files(
     available_sources.sort_by_key(SourceInformation::index);
 
     while let Some(source) = available_sources.pop() {
-        if source
-            .download_snapshots(full_snapshot_path, delta_snapshot_path)
-            .await
-            .is_ok()
-        {
-            return Ok(());
+        match source.download_snapshots(full_snapshot_path, delta_snapshot_path).await {
+            Ok(()) => return Ok(()),
+            Err(e) => {
+                warn!("Downloading snapshot files from {} failed: {}.", source.urls.full(), e);
+
+                // Partial downloads of this source can't be resumed from another one.
+                remove_file_if_exists(&partial_path(full_snapshot_path)).await?;
+                if let Some(delta_path) = delta_snapshot_path {
+                    remove_file_if_exists(&partial_path(delta_path)).await?;
+                }
+            }
         }
     }
 
     Err(Error::NoDownloadSourceAvailable)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::types::snapshot::SnapshotKind;
+
+    use tokio::net::TcpListener;
+
+    use std::{
+        collections::HashMap,
+        net::SocketAddr,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    const NETWORK_ID: u64 = 42;
+
+    /// A minimal HTTP server serving files with range requests, whose first response is cut in the middle.
+    struct Server {
+        address: SocketAddr,
+        range_requests: Arc<AtomicUsize>,
+    }
+
+    impl Server {
+        async fn start(files: HashMap<&'static str, Vec<u8>>, interrupt_first_response: bool) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let address = listener.local_addr().unwrap();
+            let range_requests = Arc::new(AtomicUsize::new(0));
+            let range_requests_clone = range_requests.clone();
+            let files = Arc::new(files);
+
+            tokio::spawn(async move {
+                let mut interrupt = interrupt_first_response;
+
+                while let Ok((mut stream, _)) = listener.accept().await {
+                    let mut request = Vec::new();
+                    let mut buffer = [0u8; 1024];
+
+                    while !request.ends_with(b"\r\n\r\n") {
+                        let read = stream.read(&mut buffer).await.unwrap();
+                        if read == 0 {
+                            break;
+                        }
+                        request.extend_from_slice(&buffer[..read]);
+                    }
+
+                    let request = String::from_utf8_lossy(&request).to_lowercase();
+                    let path = request.split_whitespace().nth(1).unwrap_or_default().to_owned();
+                    let offset = request
+                        .lines()
+                        .find_map(|line| line.strip_prefix("range: bytes="))
+                        .map(|range| range.trim_end_matches('-').parse::<usize>().unwrap());
+
+                    let response = match files.get(path.as_str()) {
+                        Some(file) => match offset {
+                            Some(offset) => {
+                                range_requests_clone.fetch_add(1, Ordering::SeqCst);
+                                if offset >= file.len() {
+                                    b"HTTP/1.1 416 Range Not Satisfiable\r\ncontent-length: 0\r\n\r\n".to_vec()
+                                } else {
+                                    let mut response = format!(
+                                        "HTTP/1.1 206 Partial Content\r\ncontent-length: {}\r\n\r\n",
+                                        file.len() - offset
+                                    )
+                                    .into_bytes();
+                                    response.extend_from_slice(&file[offset..]);
+                                    response
+                                }
+                            }
+                            None => {
+                                let mut response =
+                                    format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n", file.len()).into_bytes();
+                                if interrupt {
+                                    interrupt = false;
+                                    response.extend_from_slice(&file[..file.len() / 2]);
+                                } else {
+                                    response.extend_from_slice(file);
+                                }
+                                response
+                            }
+                        },
+                        None => b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\n\r\n".to_vec(),
+                    };
+
+                    stream.write_all(&response).await.unwrap();
+                    stream.shutdown().await.unwrap();
+                }
+            });
+
+            Self {
+                address,
+                range_requests,
+            }
+        }
+
+        fn url(&self, path: &str) -> String {
+            format!("http://{}{}", self.address, path)
+        }
+    }
+
+    fn snapshot_file(sep_index: u32, fill: u8) -> (SnapshotHeader, Vec<u8>) {
+        let header = SnapshotHeader::new(
+            SnapshotKind::Full,
+            0,
+            NETWORK_ID,
+            MilestoneIndex(sep_index),
+            MilestoneIndex(sep_index),
+        );
+        let mut bytes = header.pack_to_vec();
+        bytes.extend_from_slice(&[fill; 100_000]);
+
+        (header, bytes)
+    }
+
+    fn sha256(bytes: &[u8]) -> String {
+        hex::encode(Sha256::digest(bytes))
+    }
+
+    fn temporary_directory(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("bee-ledger-download-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        path
+    }
+
+    #[tokio::test]
+    async fn resume_interrupted_download() {
+        let (header, file) = snapshot_file(10, 1);
+        let server = Server::start(HashMap::from([("/full.bin", file.clone())]), true).await;
+        let path = temporary_directory("resume").join("full.bin");
+
+        download_snapshot_file(&path, &server.url("/full.bin"), &header, Some(&sha256(&file)))
+            .await
+            .unwrap();
+
+        assert_eq!(std::fs::read(&path).unwrap(), file);
+        assert_eq!(server.range_requests.load(Ordering::SeqCst), 1);
+        assert!(!partial_path(&path).exists());
+    }
+
+    #[tokio::test]
+    async fn reject_checksum_mismatch() {
+        let (header, file) = snapshot_file(10, 1);
+        let server = Server::start(HashMap::from([("/full.bin", file.clone())]), false).await;
+        let path = temporary_directory("mismatch").join("full.bin");
+
+        let result = download_snapshot_file(&path, &server.url("/full.bin"), &header, Some(&sha256(&[]))).await;
+
+        assert!(matches!(result, Err(Error::ChecksumMismatch(..))));
+        assert!(!path.exists());
+        assert!(!partial_path(&path).exists());
+    }
+
+    #[tokio::test]
+    async fn fall_back_to_next_source_on_checksum_mismatch() {
+        let (_, fresh_file) = snapshot_file(20, 1);
+        let (_, old_file) = snapshot_file(10, 2);
+        let server = Server::start(
+            HashMap::from([("/fresh/full.bin", fresh_file), ("/old/full.bin", old_file.clone())]),
+            false,
+        )
+        .await;
+        let path = temporary_directory("fallback").join("full.bin");
+        let download_urls = vec![
+            DownloadUrls::new(
+                server.url("/fresh/full.bin").parse().unwrap(),
+                server.url("/fresh/delta.bin").parse().unwrap(),
+                Some(sha256(&[])),
+                None,
+            ),
+            DownloadUrls::new(
+                server.url("/old/full.bin").parse().unwrap(),
+                server.url("/old/delta.bin").parse().unwrap(),
+                Some(sha256(&old_file)),
+                None,
+            ),
+        ];
+
+        download_latest_snapshot_files(NETWORK_ID, &path, None, &download_urls)
+            .await
+            .unwrap();
+
+        assert_eq!(std::fs::read(&path).unwrap(), old_file);
+    }
+}
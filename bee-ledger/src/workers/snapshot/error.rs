@@ -3,7 +3,7 @@
 
 use crate::types::{snapshot::SnapshotKind, Error as TypesError};
 
-use bee_message::milestone::MilestoneIndex;
+use bee_message::{milestone::MilestoneIndex, output::OutputId, payload::milestone::MilestoneId};
 
 use thiserror::Error;
 
@@ -22,6 +22,9 @@ pub enum Error {
     /// Downloading failed.
     #[error("downloading failed")]
     DownloadingFailed,
+    /// Checksum mismatch of a downloaded snapshot file.
+    #[error("checksum mismatch of downloaded snapshot file {0}: expected {1}, computed {2}")]
+    ChecksumMismatch(String, String, String),
     /// No snapshot download source available.
     #[error("no snapshot download source available")]
     NoDownloadSourceAvailable,
@@ -51,4 +54,19 @@ pub enum Error {
     /// Remaining bytes in file.
     #[error("remaining bytes in file")]
     RemainingBytes,
+    /// Missing snapshot info.
+    #[error("missing snapshot info")]
+    MissingSnapshotInfo,
+    /// Missing milestone.
+    #[error("missing milestone {0}")]
+    MissingMilestone(MilestoneIndex),
+    /// Missing output diff.
+    #[error("missing output diff of milestone {0}")]
+    MissingOutputDiff(MilestoneIndex),
+    /// Missing output.
+    #[error("missing output {0}")]
+    MissingOutput(OutputId),
+    /// Missing treasury output.
+    #[error("missing treasury output created by milestone {0}")]
+    MissingTreasuryOutput(MilestoneId),
 }
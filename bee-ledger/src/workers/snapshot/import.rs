@@ -11,6 +11,7 @@ use crate::{
     workers::{
         consensus::worker::migration_from_milestone,
         error::Error,
+        indexation::config::IndexationConfig,
         snapshot::{config::SnapshotConfig, download::download_latest_snapshot_files, error::Error as SnapshotError},
         storage::{self, apply_milestone, create_output, rollback_milestone, StorageBackend},
     },
@@ -71,6 +72,7 @@ fn import_outputs<U: Unpacker<Error = std::io::Error>, B: StorageBackend>(
     unpacker: &mut U,
     storage: &B,
     output_count: u64,
+    indexation_config: &IndexationConfig,
 ) -> Result<(), Error> {
     for _ in 0..output_count {
         let message_id = MessageId::unpack::<_, true>(unpacker)?;
@@ -82,7 +84,7 @@ fn import_outputs<U: Unpacker<Error = std::io::Error>, B: StorageBackend>(
         let output = Output::unpack::<_, true>(unpacker)?;
         let created_output = CreatedOutput::new(message_id, milestone_index, milestone_timestamp, output);
 
-        create_output(&*storage, &output_id, &created_output)?;
+        create_output(&*storage, &output_id, &created_output, indexation_config)?;
     }
 
     Ok(())
@@ -92,6 +94,7 @@ async fn import_milestone_diffs<U: Unpacker<Error = std::io::Error>, B: StorageB
     unpacker: &mut U,
     storage: &B,
     milestone_diff_count: u64,
+    indexation_config: &IndexationConfig,
 ) -> Result<(), Error> {
     for _ in 0..milestone_diff_count {
         let diff = MilestoneDiff::unpack::<_, true>(unpacker)?;
@@ -127,9 +130,23 @@ async fn import_milestone_diffs<U: Unpacker<Error = std::io::Error>, B: StorageB
         };
 
         if index == MilestoneIndex(ledger_index + 1) {
-            apply_milestone(&*storage, index, diff.created(), &consumed, &migration)?;
+            apply_milestone(
+                &*storage,
+                index,
+                diff.created(),
+                &consumed,
+                &migration,
+                indexation_config,
+            )?;
         } else if index == MilestoneIndex(ledger_index) {
-            rollback_milestone(&*storage, index, diff.created(), &consumed, &migration)?;
+            rollback_milestone(
+                &*storage,
+                index,
+                diff.created(),
+                &consumed,
+                &migration,
+                indexation_config,
+            )?;
         } else {
             return Err(Error::Snapshot(SnapshotError::UnexpectedMilestoneDiffIndex(index)));
         }
@@ -154,7 +171,12 @@ fn check_header(header: &SnapshotHeader, kind: SnapshotKind, network_id: u64) ->
     }
 }
 
-async fn import_full_snapshot<B: StorageBackend>(storage: &B, path: &Path, network_id: u64) -> Result<(), Error> {
+async fn import_full_snapshot<B: StorageBackend>(
+    storage: &B,
+    path: &Path,
+    network_id: u64,
+    indexation_config: &IndexationConfig,
+) -> Result<(), Error> {
     info!("Importing full snapshot file {}...", &path.to_string_lossy());
 
     let mut unpacker = IoUnpacker::new(snapshot_reader(path)?);
@@ -198,8 +220,14 @@ async fn import_full_snapshot<B: StorageBackend>(storage: &B, path: &Path, netwo
     )?;
 
     import_solid_entry_points(&mut unpacker, storage, full_header.sep_count(), header.sep_index())?;
-    import_outputs(&mut unpacker, storage, full_header.output_count())?;
-    import_milestone_diffs(&mut unpacker, storage, full_header.milestone_diff_count()).await?;
+    import_outputs(&mut unpacker, storage, full_header.output_count(), indexation_config)?;
+    import_milestone_diffs(
+        &mut unpacker,
+        storage,
+        full_header.milestone_diff_count(),
+        indexation_config,
+    )
+    .await?;
 
     if unpacker.into_inner().bytes().next().is_some() {
         return Err(Error::Snapshot(SnapshotError::RemainingBytes));
@@ -218,7 +246,12 @@ async fn import_full_snapshot<B: StorageBackend>(storage: &B, path: &Path, netwo
     Ok(())
 }
 
-async fn import_delta_snapshot<B: StorageBackend>(storage: &B, path: &Path, network_id: u64) -> Result<(), Error> {
+async fn import_delta_snapshot<B: StorageBackend>(
+    storage: &B,
+    path: &Path,
+    network_id: u64,
+    indexation_config: &IndexationConfig,
+) -> Result<(), Error> {
     info!("Importing delta snapshot file {}...", &path.to_string_lossy());
 
     let mut unpacker = IoUnpacker::new(snapshot_reader(path)?);
@@ -254,7 +287,13 @@ async fn import_delta_snapshot<B: StorageBackend>(storage: &B, path: &Path, netw
     )?;
 
     import_solid_entry_points(&mut unpacker, storage, delta_header.sep_count(), header.sep_index())?;
-    import_milestone_diffs(&mut unpacker, storage, delta_header.milestone_diff_count()).await?;
+    import_milestone_diffs(
+        &mut unpacker,
+        storage,
+        delta_header.milestone_diff_count(),
+        indexation_config,
+    )
+    .await?;
 
     if unpacker.into_inner().bytes().next().is_some() {
         return Err(Error::Snapshot(SnapshotError::RemainingBytes));
@@ -276,6 +315,7 @@ pub(crate) async fn import_snapshots<B: StorageBackend>(
     storage: &B,
     network_id: u64,
     config: &SnapshotConfig,
+    indexation_config: &IndexationConfig,
 ) -> Result<(), Error> {
     let full_exists = config.full_path().exists();
     let delta_exists = config.delta_path().map_or(false, Path::exists);
@@ -292,11 +332,11 @@ pub(crate) async fn import_snapshots<B: StorageBackend>(
         .await?;
     }
 
-    import_full_snapshot(storage, config.full_path(), network_id).await?;
+    import_full_snapshot(storage, config.full_path(), network_id, indexation_config).await?;
 
     if let Some(delta_path) = config.delta_path() {
         if delta_path.exists() {
-            import_delta_snapshot(storage, delta_path, network_id).await?;
+            import_delta_snapshot(storage, delta_path, network_id, indexation_config).await?;
         }
     }
 
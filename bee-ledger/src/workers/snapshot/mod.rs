@@ -4,9 +4,12 @@
 //! Module containing snapshot operations.
 
 pub(crate) mod condition;
+pub(crate) mod creation;
 pub(crate) mod download;
 pub(crate) mod error;
 pub(crate) mod import;
 pub(crate) mod worker;
 
 pub mod config;
+
+pub use creation::{generate_full_snapshot, unspent_outputs_at};
@@ -3,6 +3,7 @@
 
 use crate::workers::{
     error::Error,
+    indexation::{config::IndexationConfig, synchronize_indexes},
     snapshot::{config::SnapshotConfig, error::Error as SnapshotError, import::import_snapshots},
     storage::{self, StorageBackend},
 };
@@ -25,7 +26,7 @@ impl<N: Node> Worker<N> for SnapshotWorker
 where
     N::Backend: StorageBackend,
 {
-    type Config = (u64, SnapshotConfig);
+    type Config = (u64, SnapshotConfig, IndexationConfig);
     type Error = Error;
 
     fn dependencies() -> &'static [TypeId] {
@@ -33,7 +34,7 @@ where
     }
 
     async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
-        let (network_id, snapshot_config) = config;
+        let (network_id, snapshot_config, indexation_config) = config;
         let tangle = node.resource::<Tangle<N::Backend>>();
         let storage = node.storage();
 
@@ -52,7 +53,9 @@ where
                 *info.entry_point_index(),
                 *info.pruning_index(),
             );
-        } else if let Err(e) = import_snapshots(&*storage, network_id, &snapshot_config).await {
+
+            synchronize_indexes(&*storage, &indexation_config)?;
+        } else if let Err(e) = import_snapshots(&*storage, network_id, &snapshot_config, &indexation_config).await {
             (*storage)
                 .set_health(StorageHealth::Corrupted)
                 .map_err(|e| Error::Storage(Box::new(e)))?;
@@ -5,20 +5,24 @@
 
 use crate::{
     types::{
-        snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, Migration, OutputDiff, Receipt,
-        TreasuryDiff, TreasuryOutput, Unspent,
+        output_addresses, output_feature_blocks, snapshot::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput,
+        LedgerIndex, Migration, OutputDiff, Receipt, TreasuryDiff, TreasuryOutput, Unspent,
     },
-    workers::error::Error,
+    workers::{error::Error, indexation::config::IndexationConfig},
 };
 
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::{Output, OutputId},
+    output::{
+        feature_block::{FeatureBlock, SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, Output, OutputId,
+    },
+    payload::milestone::MilestoneId,
     Message, MessageId,
 };
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, AsRangeIterator, Batch, BatchBuilder, Exist, Fetch, Insert, IterDirection, Truncate},
     backend,
 };
 use bee_tangle::{
@@ -36,7 +40,12 @@ pub trait StorageBackend:
     + Batch<Unspent, ()>
     + Batch<(), LedgerIndex>
     + Batch<MilestoneIndex, OutputDiff>
-    + Batch<(Ed25519Address, OutputId), ()>
+    + Batch<((Address, AddressRole), OutputId), ()>
+    + Batch<AliasId, OutputId>
+    + Batch<NftId, OutputId>
+    + Batch<FoundryId, OutputId>
+    + Batch<(TagFeatureBlock, OutputId), ()>
+    + Batch<(SenderFeatureBlock, OutputId), ()>
     + Batch<(MilestoneIndex, Receipt), ()>
     + Batch<(bool, TreasuryOutput), ()>
     + Batch<SolidEntryPoint, MilestoneIndex>
@@ -46,11 +55,21 @@ pub trait StorageBackend:
     + Batch<MessageId, MessageMetadata>
     + Batch<MilestoneIndex, Milestone>
     + Exist<Unspent, ()>
+    + Exist<OutputId, ConsumedOutput>
+    + Exist<((Address, AddressRole), OutputId), ()>
+    + Exist<(TagFeatureBlock, OutputId), ()>
+    + Exist<(SenderFeatureBlock, OutputId), ()>
     + Fetch<(), SnapshotInfo>
     + Fetch<OutputId, CreatedOutput>
+    + Fetch<OutputId, ConsumedOutput>
     + Fetch<(), LedgerIndex>
     + Fetch<bool, Vec<TreasuryOutput>>
-    + Fetch<Ed25519Address, Vec<OutputId>>
+    + Fetch<(Address, AddressRole), Vec<OutputId>>
+    + Fetch<AliasId, OutputId>
+    + Fetch<NftId, OutputId>
+    + Fetch<FoundryId, OutputId>
+    + Fetch<TagFeatureBlock, Vec<OutputId>>
+    + Fetch<SenderFeatureBlock, Vec<OutputId>>
     + Fetch<MilestoneIndex, Milestone>
     + Fetch<MilestoneIndex, Vec<Receipt>>
     + Fetch<MilestoneIndex, Vec<UnreferencedMessage>>
@@ -59,8 +78,21 @@ pub trait StorageBackend:
     + Insert<(), LedgerIndex>
     + Insert<(bool, TreasuryOutput), ()>
     + Truncate<SolidEntryPoint, MilestoneIndex>
+    + Truncate<(TagFeatureBlock, OutputId), ()>
+    + Truncate<(SenderFeatureBlock, OutputId), ()>
     + for<'a> AsIterator<'a, Unspent, ()>
     + for<'a> AsIterator<'a, SolidEntryPoint, MilestoneIndex>
+    + for<'a> AsIterator<'a, ((Address, AddressRole), OutputId), ()>
+    + for<'a> AsIterator<'a, AliasId, OutputId>
+    + for<'a> AsIterator<'a, NftId, OutputId>
+    + for<'a> AsIterator<'a, FoundryId, OutputId>
+    + for<'a> AsIterator<'a, (TagFeatureBlock, OutputId), ()>
+    + for<'a> AsIterator<'a, (SenderFeatureBlock, OutputId), ()>
+    + for<'a> AsIterator<'a, (MessageId, MessageId), ()>
+    + for<'a> AsIterator<'a, MilestoneIndex, OutputDiff>
+    + for<'a> AsIterator<'a, (MilestoneIndex, Receipt), ()>
+    + for<'a> AsRangeIterator<'a, (Address, AddressRole), ((Address, AddressRole), OutputId), ()>
+    + for<'a> AsRangeIterator<'a, MessageId, (MessageId, MessageId), ()>
     + bee_tangle::storage::StorageBackend
 {
 }
@@ -73,7 +105,12 @@ impl<T> StorageBackend for T where
         + Batch<Unspent, ()>
         + Batch<(), LedgerIndex>
         + Batch<MilestoneIndex, OutputDiff>
-        + Batch<(Ed25519Address, OutputId), ()>
+        + Batch<((Address, AddressRole), OutputId), ()>
+        + Batch<AliasId, OutputId>
+        + Batch<NftId, OutputId>
+        + Batch<FoundryId, OutputId>
+        + Batch<(TagFeatureBlock, OutputId), ()>
+        + Batch<(SenderFeatureBlock, OutputId), ()>
         + Batch<(MilestoneIndex, Receipt), ()>
         + Batch<(bool, TreasuryOutput), ()>
         + Batch<SolidEntryPoint, MilestoneIndex>
@@ -83,11 +120,21 @@ impl<T> StorageBackend for T where
         + Batch<MessageId, MessageMetadata>
         + Batch<MilestoneIndex, Milestone>
         + Exist<Unspent, ()>
+        + Exist<OutputId, ConsumedOutput>
+        + Exist<((Address, AddressRole), OutputId), ()>
+        + Exist<(TagFeatureBlock, OutputId), ()>
+        + Exist<(SenderFeatureBlock, OutputId), ()>
         + Fetch<(), SnapshotInfo>
         + Fetch<OutputId, CreatedOutput>
+        + Fetch<OutputId, ConsumedOutput>
         + Fetch<(), LedgerIndex>
         + Fetch<bool, Vec<TreasuryOutput>>
-        + Fetch<Ed25519Address, Vec<OutputId>>
+        + Fetch<(Address, AddressRole), Vec<OutputId>>
+        + Fetch<AliasId, OutputId>
+        + Fetch<NftId, OutputId>
+        + Fetch<FoundryId, OutputId>
+        + Fetch<TagFeatureBlock, Vec<OutputId>>
+        + Fetch<SenderFeatureBlock, Vec<OutputId>>
         + Fetch<MilestoneIndex, Milestone>
         + Fetch<MilestoneIndex, Vec<Receipt>>
         + Fetch<MilestoneIndex, Vec<UnreferencedMessage>>
@@ -96,57 +143,175 @@ impl<T> StorageBackend for T where
         + Insert<(), LedgerIndex>
         + Insert<(bool, TreasuryOutput), ()>
         + Truncate<SolidEntryPoint, MilestoneIndex>
+        + Truncate<(TagFeatureBlock, OutputId), ()>
+        + Truncate<(SenderFeatureBlock, OutputId), ()>
         + for<'a> AsIterator<'a, Unspent, ()>
         + for<'a> AsIterator<'a, SolidEntryPoint, MilestoneIndex>
+        + for<'a> AsIterator<'a, ((Address, AddressRole), OutputId), ()>
+        + for<'a> AsIterator<'a, AliasId, OutputId>
+        + for<'a> AsIterator<'a, NftId, OutputId>
+        + for<'a> AsIterator<'a, FoundryId, OutputId>
+        + for<'a> AsIterator<'a, (TagFeatureBlock, OutputId), ()>
+        + for<'a> AsIterator<'a, (SenderFeatureBlock, OutputId), ()>
+        + for<'a> AsIterator<'a, (MessageId, MessageId), ()>
+        + for<'a> AsIterator<'a, MilestoneIndex, OutputDiff>
+        + for<'a> AsIterator<'a, (MilestoneIndex, Receipt), ()>
+        + for<'a> AsRangeIterator<'a, (Address, AddressRole), ((Address, AddressRole), OutputId), ()>
+        + for<'a> AsRangeIterator<'a, MessageId, (MessageId, MessageId), ()>
         + bee_tangle::storage::StorageBackend
 {
 }
 
-// pub(crate) fn insert_output_id_for_address_batch<B: StorageBackend>(
-//     storage: &B,
-//     batch: &mut <B as BatchBuilder>::Batch,
-//     address: &Address,
-//     output_id: &OutputId,
-// ) -> Result<(), Error> {
-//     match address {
-//         Address::Ed25519(address) => {
-//             Batch::<(Ed25519Address, OutputId), ()>::batch_insert(storage, batch, &(*address, *output_id), &())
-//                 .map_err(|e| Error::Storage(Box::new(e)))
-//         }
-//         Address::Alias(_address) => {
-//             todo!();
-//         }
-//         Address::Nft(_address) => {
-//             todo!();
-//         }
-//     }
-// }
-//
-// pub(crate) fn delete_output_id_for_address_batch<B: StorageBackend>(
-//     storage: &B,
-//     batch: &mut <B as BatchBuilder>::Batch,
-//     address: &Address,
-//     output_id: &OutputId,
-// ) -> Result<(), Error> {
-//     match address {
-//         Address::Ed25519(address) => {
-//             Batch::<(Ed25519Address, OutputId), ()>::batch_delete(storage, batch, &(*address, *output_id))
-//                 .map_err(|e| Error::Storage(Box::new(e)))
-//         }
-//         Address::Alias(_address) => {
-//             todo!();
-//         }
-//         Address::Nft(_address) => {
-//             todo!();
-//         }
-//     }
-// }
+pub(crate) fn insert_address_output_id_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    output_id: &OutputId,
+    output: &Output,
+) -> Result<(), Error> {
+    for key in output_addresses(output) {
+        Batch::<((Address, AddressRole), OutputId), ()>::batch_insert(storage, batch, &(key, *output_id), &())
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn delete_address_output_id_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    output_id: &OutputId,
+    output: &Output,
+) -> Result<(), Error> {
+    for key in output_addresses(output) {
+        Batch::<((Address, AddressRole), OutputId), ()>::batch_delete(storage, batch, &(key, *output_id))
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn insert_feature_block_output_id_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    output_id: &OutputId,
+    output: &Output,
+    config: &IndexationConfig,
+) -> Result<(), Error> {
+    for feature_block in output_feature_blocks(output) {
+        match feature_block {
+            FeatureBlock::Tag(tag) if config.tag() => {
+                Batch::<(TagFeatureBlock, OutputId), ()>::batch_insert(storage, batch, &(tag.clone(), *output_id), &())
+            }
+            FeatureBlock::Sender(sender) if config.sender() => {
+                Batch::<(SenderFeatureBlock, OutputId), ()>::batch_insert(
+                    storage,
+                    batch,
+                    &(sender.clone(), *output_id),
+                    &(),
+                )
+            }
+            _ => continue,
+        }
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn delete_feature_block_output_id_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    output_id: &OutputId,
+    output: &Output,
+) -> Result<(), Error> {
+    // Deletions are not gated by the indexation configuration so that entries written before an index was disabled
+    // don't outlive their output.
+    for feature_block in output_feature_blocks(output) {
+        match feature_block {
+            FeatureBlock::Tag(tag) => {
+                Batch::<(TagFeatureBlock, OutputId), ()>::batch_delete(storage, batch, &(tag.clone(), *output_id))
+            }
+            FeatureBlock::Sender(sender) => {
+                Batch::<(SenderFeatureBlock, OutputId), ()>::batch_delete(storage, batch, &(sender.clone(), *output_id))
+            }
+            _ => continue,
+        }
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    Ok(())
+}
+
+/// Indexes all the unspent outputs by their feature blocks, according to the given indexation configuration.
+pub(crate) fn insert_feature_block_output_ids<B: StorageBackend>(
+    storage: &B,
+    config: &IndexationConfig,
+) -> Result<(), Error> {
+    let mut batch = B::batch_begin();
+
+    for result in AsIterator::<Unspent, ()>::iter(storage).map_err(|e| Error::Storage(Box::new(e)))? {
+        let (unspent, ()) = result.map_err(|e| Error::Storage(Box::new(e)))?;
+
+        if let Some(output) = fetch_output(storage, unspent.id())? {
+            insert_feature_block_output_id_batch(storage, &mut batch, unspent.id(), output.inner(), config)?;
+        }
+    }
+
+    storage
+        .batch_commit(batch, true)
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
+pub(crate) fn insert_chain_output_id_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    output_id: &OutputId,
+    output: &Output,
+) -> Result<(), Error> {
+    match output {
+        Output::Alias(output) => Batch::<AliasId, OutputId>::batch_insert(
+            storage,
+            batch,
+            &output.alias_id().or_from_output_id(*output_id),
+            output_id,
+        ),
+        Output::Nft(output) => Batch::<NftId, OutputId>::batch_insert(
+            storage,
+            batch,
+            &output.nft_id().or_from_output_id(*output_id),
+            output_id,
+        ),
+        Output::Foundry(output) => Batch::<FoundryId, OutputId>::batch_insert(storage, batch, &output.id(), output_id),
+        _ => return Ok(()),
+    }
+    .map_err(|e| Error::Storage(Box::new(e)))
+}
+
+pub(crate) fn delete_chain_output_id_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    output_id: &OutputId,
+    output: &Output,
+) -> Result<(), Error> {
+    match output {
+        Output::Alias(output) => {
+            Batch::<AliasId, OutputId>::batch_delete(storage, batch, &output.alias_id().or_from_output_id(*output_id))
+        }
+        Output::Nft(output) => {
+            Batch::<NftId, OutputId>::batch_delete(storage, batch, &output.nft_id().or_from_output_id(*output_id))
+        }
+        Output::Foundry(output) => Batch::<FoundryId, OutputId>::batch_delete(storage, batch, &output.id()),
+        _ => return Ok(()),
+    }
+    .map_err(|e| Error::Storage(Box::new(e)))
+}
 
 pub(crate) fn insert_created_output_batch<B: StorageBackend>(
     storage: &B,
     batch: &mut <B as BatchBuilder>::Batch,
     output_id: &OutputId,
     output: &CreatedOutput,
+    config: &IndexationConfig,
 ) -> Result<(), Error> {
     Batch::<OutputId, CreatedOutput>::batch_insert(storage, batch, output_id, output)
         .map_err(|e| Error::Storage(Box::new(e)))?;
@@ -156,20 +321,13 @@ pub(crate) fn insert_created_output_batch<B: StorageBackend>(
     match output.inner() {
         Output::Treasury(_) => Err(Error::UnsupportedOutputKind(output.kind())),
         Output::Basic(_) => {
-            // TODO
-            Ok(())
-        }
-        Output::Alias(_) => {
-            // TODO
-            Ok(())
+            insert_address_output_id_batch(storage, batch, output_id, output.inner())?;
+            insert_feature_block_output_id_batch(storage, batch, output_id, output.inner(), config)
         }
-        Output::Foundry(_) => {
-            // TODO
-            Ok(())
-        }
-        Output::Nft(_) => {
-            // TODO
-            Ok(())
+        Output::Alias(_) | Output::Foundry(_) | Output::Nft(_) => {
+            insert_address_output_id_batch(storage, batch, output_id, output.inner())?;
+            insert_feature_block_output_id_batch(storage, batch, output_id, output.inner(), config)?;
+            insert_chain_output_id_batch(storage, batch, output_id, output.inner())
         }
     }
 }
@@ -187,10 +345,15 @@ pub(crate) fn delete_created_output_batch<B: StorageBackend>(
 
     match output.inner() {
         Output::Treasury(_) => Err(Error::UnsupportedOutputKind(output.kind())),
-        Output::Basic(_) => todo!(),
-        Output::Alias(_) => todo!(),
-        Output::Foundry(_) => todo!(),
-        Output::Nft(_) => todo!(),
+        Output::Basic(_) => {
+            delete_address_output_id_batch(storage, batch, output_id, output.inner())?;
+            delete_feature_block_output_id_batch(storage, batch, output_id, output.inner())
+        }
+        Output::Alias(_) | Output::Foundry(_) | Output::Nft(_) => {
+            delete_address_output_id_batch(storage, batch, output_id, output.inner())?;
+            delete_feature_block_output_id_batch(storage, batch, output_id, output.inner())?;
+            delete_chain_output_id_batch(storage, batch, output_id, output.inner())
+        }
     }
 }
 
@@ -198,10 +361,11 @@ pub(crate) fn create_output<B: StorageBackend>(
     storage: &B,
     output_id: &OutputId,
     output: &CreatedOutput,
+    config: &IndexationConfig,
 ) -> Result<(), Error> {
     let mut batch = B::batch_begin();
 
-    insert_created_output_batch(storage, &mut batch, output_id, output)?;
+    insert_created_output_batch(storage, &mut batch, output_id, output, config)?;
 
     storage
         .batch_commit(batch, true)
@@ -236,23 +400,35 @@ pub(crate) fn apply_milestone<B: StorageBackend>(
     created_outputs: &HashMap<OutputId, CreatedOutput>,
     consumed_outputs: &HashMap<OutputId, (CreatedOutput, ConsumedOutput)>,
     migration: &Option<Migration>,
+    config: &IndexationConfig,
 ) -> Result<(), Error> {
     let mut batch = B::batch_begin();
 
     insert_ledger_index_batch(storage, &mut batch, &index.into())?;
 
-    let created_output_ids = created_outputs
+    // Consumed outputs are applied first so that chains transitioned within the milestone end up indexed by their new
+    // output.
+    let consumed_output_ids = consumed_outputs
         .iter()
-        .map::<Result<_, Error>, _>(|(output_id, output)| {
-            insert_created_output_batch(storage, &mut batch, output_id, output)?;
+        .map::<Result<_, Error>, _>(|(output_id, (created_output, consumed_output))| {
+            insert_consumed_output_batch(storage, &mut batch, output_id, consumed_output)?;
+            delete_address_output_id_batch(storage, &mut batch, output_id, created_output.inner())?;
+            delete_feature_block_output_id_batch(storage, &mut batch, output_id, created_output.inner())?;
+            delete_chain_output_id_batch(storage, &mut batch, output_id, created_output.inner())?;
             Ok(*output_id)
         })
         .collect::<Result<Vec<_>, _>>()?;
 
-    let consumed_output_ids = consumed_outputs
+    let created_output_ids = created_outputs
         .iter()
-        .map::<Result<_, Error>, _>(|(output_id, (_, consumed_output))| {
-            insert_consumed_output_batch(storage, &mut batch, output_id, consumed_output)?;
+        .map::<Result<_, Error>, _>(|(output_id, output)| {
+            if consumed_outputs.contains_key(output_id) {
+                // An output consumed within the same milestone is neither unspent nor the current output of its chain.
+                Batch::<OutputId, CreatedOutput>::batch_insert(storage, &mut batch, output_id, output)
+                    .map_err(|e| Error::Storage(Box::new(e)))?;
+            } else {
+                insert_created_output_batch(storage, &mut batch, output_id, output, config)?;
+            }
             Ok(*output_id)
         })
         .collect::<Result<Vec<_>, _>>()?;
@@ -290,32 +466,58 @@ pub(crate) fn rollback_milestone<B: StorageBackend>(
     created_outputs: &HashMap<OutputId, CreatedOutput>,
     consumed_outputs: &HashMap<OutputId, (CreatedOutput, ConsumedOutput)>,
     migration: &Option<Migration>,
+    config: &IndexationConfig,
 ) -> Result<(), Error> {
     let mut batch = B::batch_begin();
 
-    insert_ledger_index_batch(storage, &mut batch, &((index - 1).into()))?;
+    rollback_milestone_batch(
+        storage,
+        &mut batch,
+        index,
+        created_outputs,
+        consumed_outputs,
+        migration,
+        config,
+    )?;
+
+    storage
+        .batch_commit(batch, true)
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
+pub(crate) fn rollback_milestone_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    index: MilestoneIndex,
+    created_outputs: &HashMap<OutputId, CreatedOutput>,
+    consumed_outputs: &HashMap<OutputId, (CreatedOutput, ConsumedOutput)>,
+    migration: &Option<Migration>,
+    config: &IndexationConfig,
+) -> Result<(), Error> {
+    insert_ledger_index_batch(storage, batch, &((index - 1).into()))?;
 
     for (output_id, created_output) in created_outputs.iter() {
-        delete_created_output_batch(storage, &mut batch, output_id, created_output)?;
+        delete_created_output_batch(storage, batch, output_id, created_output)?;
     }
 
     for (output_id, (created_output, _)) in consumed_outputs.iter() {
-        insert_created_output_batch(storage, &mut batch, output_id, created_output)?;
-        delete_consumed_output_batch(storage, &mut batch, output_id)?;
+        if created_outputs.contains_key(output_id) {
+            // An output created within the rolled back milestone doesn't exist anymore and must not become unspent.
+            Batch::<OutputId, ConsumedOutput>::batch_delete(storage, batch, output_id)
+                .map_err(|e| Error::Storage(Box::new(e)))?;
+        } else {
+            insert_created_output_batch(storage, batch, output_id, created_output, config)?;
+            delete_consumed_output_batch(storage, batch, output_id)?;
+        }
     }
 
     if let Some(migration) = migration {
-        delete_receipt_batch(storage, &mut batch, migration.receipt())?;
-        delete_treasury_output_batch(storage, &mut batch, migration.created_treasury())?;
-        unspend_treasury_output_batch(storage, &mut batch, migration.consumed_treasury())?;
+        delete_receipt_batch(storage, batch, migration.receipt())?;
+        delete_treasury_output_batch(storage, batch, migration.created_treasury())?;
+        unspend_treasury_output_batch(storage, batch, migration.consumed_treasury())?;
     }
 
-    Batch::<MilestoneIndex, OutputDiff>::batch_delete(storage, &mut batch, &index)
-        .map_err(|e| Error::Storage(Box::new(e)))?;
-
-    storage
-        .batch_commit(batch, true)
-        .map_err(|e| Error::Storage(Box::new(e)))
+    Batch::<MilestoneIndex, OutputDiff>::batch_delete(storage, batch, &index).map_err(|e| Error::Storage(Box::new(e)))
 }
 
 pub(crate) fn insert_ledger_index<B: StorageBackend>(storage: &B, index: &LedgerIndex) -> Result<(), Error> {
@@ -376,11 +578,78 @@ pub(crate) fn fetch_output<B: StorageBackend>(
     Fetch::<OutputId, CreatedOutput>::fetch(storage, output_id).map_err(|e| Error::Storage(Box::new(e)))
 }
 
-pub(crate) fn fetch_outputs_for_ed25519_address<B: StorageBackend>(
+pub(crate) fn fetch_consumed_output<B: StorageBackend>(
     storage: &B,
-    address: &Ed25519Address,
-) -> Result<Option<Vec<OutputId>>, Error> {
-    Fetch::<Ed25519Address, Vec<OutputId>>::fetch(&*storage, address).map_err(|e| Error::Storage(Box::new(e)))
+    output_id: &OutputId,
+) -> Result<Option<ConsumedOutput>, Error> {
+    Fetch::<OutputId, ConsumedOutput>::fetch(storage, output_id).map_err(|e| Error::Storage(Box::new(e)))
+}
+
+pub(crate) fn fetch_output_diff<B: StorageBackend>(
+    storage: &B,
+    index: MilestoneIndex,
+) -> Result<Option<OutputDiff>, Error> {
+    Fetch::<MilestoneIndex, OutputDiff>::fetch(storage, &index).map_err(|e| Error::Storage(Box::new(e)))
+}
+
+/// Fetches a page of at most `page_size` values stored under the given key, starting at the cursor if any.
+///
+/// Returns the values along with the cursor of the next page, if there is one.
+fn fetch_page<B, K, V>(storage: &B, key: &K, cursor: Option<&V>, page_size: usize) -> Result<(Vec<V>, Option<V>), Error>
+where
+    B: StorageBackend + for<'a> AsRangeIterator<'a, K, (K, V), ()>,
+    K: Clone + PartialEq,
+    V: Clone,
+{
+    let iter = match cursor {
+        Some(cursor) => {
+            AsRangeIterator::<K, (K, V), ()>::iter_from(storage, &(key.clone(), cursor.clone()), IterDirection::Forward)
+        }
+        None => AsRangeIterator::<K, (K, V), ()>::iter_prefix(storage, key, IterDirection::Forward),
+    }
+    .map_err(|e| Error::Storage(Box::new(e)))?;
+
+    let mut values = Vec::with_capacity(page_size);
+
+    for result in iter {
+        let ((other, value), ()) = result.map_err(|e| Error::Storage(Box::new(e)))?;
+
+        // Starting at a cursor, the iteration goes on past the values of the key.
+        if &other != key {
+            break;
+        }
+        if values.len() == page_size {
+            return Ok((values, Some(value)));
+        }
+
+        values.push(value);
+    }
+
+    Ok((values, None))
+}
+
+/// Fetches a page of at most `page_size` output ids of an address with a given role, starting at the cursor if any.
+///
+/// Returns the output ids along with the cursor of the next page, if there is one.
+pub fn fetch_output_ids_page<B: StorageBackend>(
+    storage: &B,
+    address: &(Address, AddressRole),
+    cursor: Option<&OutputId>,
+    page_size: usize,
+) -> Result<(Vec<OutputId>, Option<OutputId>), Error> {
+    fetch_page(storage, address, cursor, page_size)
+}
+
+/// Fetches a page of at most `page_size` children of a message, starting at the cursor if any.
+///
+/// Returns the children along with the cursor of the next page, if there is one.
+pub fn fetch_children_page<B: StorageBackend>(
+    storage: &B,
+    message_id: &MessageId,
+    cursor: Option<&MessageId>,
+    page_size: usize,
+) -> Result<(Vec<MessageId>, Option<MessageId>), Error> {
+    fetch_page(storage, message_id, cursor, page_size)
 }
 
 pub(crate) fn is_output_unspent<B: StorageBackend>(storage: &B, output_id: &OutputId) -> Result<bool, Error> {
@@ -435,6 +704,39 @@ pub(crate) fn unspend_treasury_output_batch<B: StorageBackend>(
         .map_err(|e| Error::Storage(Box::new(e)))
 }
 
+/// Fetches all the receipts from the storage, grouped by the index of the milestone that included them.
+pub(crate) fn fetch_receipts_by_milestone<B: StorageBackend>(
+    storage: &B,
+) -> Result<HashMap<MilestoneIndex, Vec<Receipt>>, Error> {
+    let mut receipts = HashMap::<_, Vec<_>>::new();
+
+    for result in AsIterator::<(MilestoneIndex, Receipt), ()>::iter(storage).map_err(|e| Error::Storage(Box::new(e)))? {
+        let ((_, receipt), ()) = result.map_err(|e| Error::Storage(Box::new(e)))?;
+
+        receipts.entry(*receipt.included_in()).or_default().push(receipt);
+    }
+
+    Ok(receipts)
+}
+
+/// Fetches the treasury output created by a given milestone from the storage, whether it is spent or not.
+pub(crate) fn fetch_treasury_output<B: StorageBackend>(
+    storage: &B,
+    milestone_id: &MilestoneId,
+) -> Result<Option<TreasuryOutput>, Error> {
+    for spent in [false, true] {
+        if let Some(outputs) =
+            Fetch::<bool, Vec<TreasuryOutput>>::fetch(storage, &spent).map_err(|e| Error::Storage(Box::new(e)))?
+        {
+            if let Some(output) = outputs.into_iter().find(|output| output.milestone_id() == milestone_id) {
+                return Ok(Some(output));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
 /// Fetches the unspent treasury output from the storage.
 pub fn fetch_unspent_treasury_output<B: StorageBackend>(storage: &B) -> Result<TreasuryOutput, Error> {
     if let Some(outputs) =
@@ -0,0 +1,127 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "workers")]
+
+use bee_ledger::workers::pruning::archive::{import_archive, ArchiveWriter, ArchivedMilestone};
+use bee_message::{
+    milestone::{Milestone, MilestoneIndex},
+    parent::Parents,
+    payload::tagged_data::TaggedDataPayload,
+    Message, MessageBuilder, MessageId,
+};
+use bee_storage::{
+    access::{Exist, Fetch, Insert},
+    backend::StorageBackend,
+};
+use bee_storage_memory::storage::Storage;
+use bee_tangle::metadata::MessageMetadata;
+
+use std::{
+    fs::{self, OpenOptions},
+    path::{Path, PathBuf},
+};
+
+fn archive_directory(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("bee-archive-{}-{}", name, std::process::id()))
+}
+
+fn message(index: MilestoneIndex) -> Message {
+    MessageBuilder::<u64>::new()
+        .with_network_id(0)
+        .with_parents(Parents::new(vec![MessageId::null()]).unwrap())
+        .with_payload(
+            TaggedDataPayload::new(b"archive".to_vec(), index.to_le_bytes().to_vec())
+                .unwrap()
+                .into(),
+        )
+        .with_nonce_provider(0, 0f64)
+        .finish()
+        .unwrap()
+}
+
+/// Archives milestones 1 and 2 with one message each and returns the ids of these messages.
+fn write_archive(directory: &Path) -> Vec<MessageId> {
+    let storage = Storage::start(()).unwrap();
+    let mut writer = ArchiveWriter::new(directory, MilestoneIndex(1), MilestoneIndex(2)).unwrap();
+    let mut message_ids = Vec::new();
+
+    for index in [MilestoneIndex(1), MilestoneIndex(2)] {
+        let message = message(index);
+        let message_id = message.id();
+
+        Insert::<MessageId, Message>::insert(&storage, &message_id, &message).unwrap();
+        Insert::<MessageId, MessageMetadata>::insert(&storage, &message_id, &MessageMetadata::arrived()).unwrap();
+        Insert::<MilestoneIndex, Milestone>::insert(&storage, &index, &Milestone::new(message_id, u64::from(*index)))
+            .unwrap();
+
+        let mut archived_milestone = ArchivedMilestone::new(&storage, index, false).unwrap();
+        archived_milestone.add_message(&storage, &message_id).unwrap();
+        writer.write(&archived_milestone).unwrap();
+
+        message_ids.push(message_id);
+    }
+
+    message_ids
+}
+
+fn is_imported(storage: &Storage, index: MilestoneIndex, message_id: &MessageId) -> bool {
+    Fetch::<MilestoneIndex, Milestone>::fetch(storage, &index)
+        .unwrap()
+        .map(|milestone| *milestone.message_id())
+        == Some(*message_id)
+        && Fetch::<MessageId, Message>::fetch(storage, message_id)
+            .unwrap()
+            .is_some()
+        && Fetch::<MessageId, MessageMetadata>::fetch(storage, message_id)
+            .unwrap()
+            .is_some()
+        && Exist::<(MessageId, MessageId), ()>::exist(storage, &(MessageId::null(), *message_id)).unwrap()
+}
+
+#[test]
+fn archive_round_trip() {
+    let directory = archive_directory("round-trip");
+    let message_ids = write_archive(&directory);
+    let storage = Storage::start(()).unwrap();
+
+    assert_eq!(
+        import_archive(&storage, &directory, MilestoneIndex(1), MilestoneIndex(2)).unwrap(),
+        2
+    );
+    assert!(is_imported(&storage, MilestoneIndex(1), &message_ids[0]));
+    assert!(is_imported(&storage, MilestoneIndex(2), &message_ids[1]));
+
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn archive_truncated_last_member() {
+    let directory = archive_directory("truncated-last-member");
+    let message_ids = write_archive(&directory);
+    let path = fs::read_dir(&directory).unwrap().next().unwrap().unwrap().path();
+    let len = fs::metadata(&path).unwrap().len();
+
+    // Pruning was interrupted while the last member was written.
+    for truncated_len in [len - 1, len - 8, len - 32] {
+        OpenOptions::new()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_len(truncated_len)
+            .unwrap();
+
+        let storage = Storage::start(()).unwrap();
+
+        assert_eq!(
+            import_archive(&storage, &directory, MilestoneIndex(1), MilestoneIndex(2)).unwrap(),
+            1
+        );
+        assert!(is_imported(&storage, MilestoneIndex(1), &message_ids[0]));
+        assert!(Fetch::<MilestoneIndex, Milestone>::fetch(&storage, &MilestoneIndex(2))
+            .unwrap()
+            .is_none());
+    }
+
+    fs::remove_dir_all(&directory).unwrap();
+}
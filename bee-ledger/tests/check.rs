@@ -0,0 +1,399 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "workers")]
+
+use bee_ledger::{
+    types::{
+        output_addresses, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryDiff,
+        TreasuryOutput, Unspent,
+    },
+    workers::{
+        check::{check_storage, Inconsistency, IndexedChainId},
+        indexation::{config::IndexationConfig, synchronize_indexes},
+    },
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    constant::IOTA_SUPPLY,
+    input::{Input, TreasuryInput},
+    milestone::MilestoneIndex,
+    output::{
+        self,
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        unlock_condition::{
+            AddressUnlockCondition, GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition,
+        },
+        AliasId, AliasOutputBuilder, BasicOutputBuilder, NftId, NftOutputBuilder, Output, OutputId,
+    },
+    payload::{
+        milestone::MilestoneId,
+        receipt::{MigratedFundsEntry, ReceiptPayload, TailTransactionHash},
+        transaction::TransactionId,
+        Payload, TreasuryTransactionPayload,
+    },
+    MessageId,
+};
+use bee_storage::{
+    access::{Delete, Fetch, Insert},
+    backend::StorageBackend,
+};
+use bee_storage_memory::storage::Storage;
+
+const AMOUNT: u64 = 1_000_000;
+const TREASURY_AMOUNT: u64 = IOTA_SUPPLY - 4 * AMOUNT;
+const TAIL_TRANSACTION_HASH_BYTES: [u8; 49] = [
+    222, 235, 107, 67, 2, 173, 253, 93, 165, 90, 166, 45, 102, 91, 19, 137, 71, 146, 156, 180, 248, 31, 56, 25, 68,
+    154, 98, 100, 64, 108, 203, 48, 76, 75, 114, 150, 34, 153, 203, 35, 225, 120, 194, 175, 169, 207, 80, 229, 10,
+];
+
+fn output_id(transaction: u8) -> OutputId {
+    OutputId::new(TransactionId::new([transaction; 32]), 0).unwrap()
+}
+
+fn address() -> Address {
+    Address::from(Ed25519Address::new([0x11; 32]))
+}
+
+fn tag() -> TagFeatureBlock {
+    TagFeatureBlock::new(b"tag".to_vec()).unwrap()
+}
+
+fn sender() -> SenderFeatureBlock {
+    SenderFeatureBlock::new(Address::from(Ed25519Address::new([0x22; 32])))
+}
+
+fn basic_output() -> Output {
+    Output::from(
+        BasicOutputBuilder::new(AMOUNT)
+            .unwrap()
+            .add_unlock_condition(AddressUnlockCondition::new(address()).into())
+            .add_feature_block(sender().into())
+            .add_feature_block(tag().into())
+            .finish()
+            .unwrap(),
+    )
+}
+
+fn alias_output(alias_id: AliasId) -> Output {
+    Output::from(
+        AliasOutputBuilder::new(AMOUNT, alias_id)
+            .unwrap()
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(address()).into())
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(address()).into())
+            .finish()
+            .unwrap(),
+    )
+}
+
+fn nft_output(nft_id: NftId) -> Output {
+    Output::from(
+        NftOutputBuilder::new(AMOUNT, nft_id, vec![0xff; 16])
+            .unwrap()
+            .add_unlock_condition(AddressUnlockCondition::new(address()).into())
+            .finish()
+            .unwrap(),
+    )
+}
+
+fn milestone_id(milestone: u8) -> MilestoneId {
+    MilestoneId::new([milestone; MilestoneId::LENGTH])
+}
+
+fn treasury_output(amount: u64, milestone: u8) -> TreasuryOutput {
+    TreasuryOutput::new(output::TreasuryOutput::new(amount).unwrap(), milestone_id(milestone))
+}
+
+/// Returns a receipt of milestone 1 migrating `AMOUNT` out of the treasury output created by milestone 0.
+fn receipt(created_treasury_amount: u64) -> Receipt {
+    Receipt::new(
+        ReceiptPayload::new(
+            MilestoneIndex(0),
+            true,
+            vec![MigratedFundsEntry::new(
+                TailTransactionHash::new(TAIL_TRANSACTION_HASH_BYTES).unwrap(),
+                address(),
+                AMOUNT,
+            )
+            .unwrap()],
+            Payload::from(
+                TreasuryTransactionPayload::new(
+                    Input::Treasury(TreasuryInput::new(milestone_id(0))),
+                    Output::Treasury(output::TreasuryOutput::new(created_treasury_amount).unwrap()),
+                )
+                .unwrap(),
+            ),
+        )
+        .unwrap(),
+        MilestoneIndex(1),
+    )
+}
+
+fn insert_receipt(storage: &Storage, receipt: &Receipt) {
+    Insert::<(MilestoneIndex, Receipt), ()>::insert(storage, &(receipt.inner().migrated_at(), receipt.clone()), &())
+        .unwrap();
+}
+
+fn insert_created_output(storage: &Storage, output_id: &OutputId, output: Output) {
+    Insert::<OutputId, CreatedOutput>::insert(
+        storage,
+        output_id,
+        &CreatedOutput::new(MessageId::null(), MilestoneIndex(0), 0, output),
+    )
+    .unwrap();
+}
+
+/// Stores an unspent output along with its address index entries.
+fn insert_unspent_output(storage: &Storage, output_id: &OutputId, output: Output) {
+    for address in output_addresses(&output) {
+        Insert::<((Address, AddressRole), OutputId), ()>::insert(storage, &(address, *output_id), &()).unwrap();
+    }
+    insert_created_output(storage, output_id, output);
+    Insert::<Unspent, ()>::insert(storage, &(*output_id).into(), &()).unwrap();
+}
+
+/// Stores a consistent ledger of two basic outputs with a tag and a sender, an alias output and an NFT output, at
+/// milestone 1 which migrated funds out of the treasury.
+fn consistent_storage() -> Storage {
+    let storage = Storage::start(()).unwrap();
+
+    insert_unspent_output(&storage, &output_id(1), basic_output());
+    insert_unspent_output(&storage, &output_id(2), basic_output());
+    insert_unspent_output(&storage, &output_id(3), alias_output(alias_id()));
+    Insert::<AliasId, OutputId>::insert(&storage, &alias_id(), &output_id(3)).unwrap();
+    insert_unspent_output(&storage, &output_id(4), nft_output(nft_id()));
+    Insert::<NftId, OutputId>::insert(&storage, &nft_id(), &output_id(4)).unwrap();
+    Insert::<(bool, TreasuryOutput), ()>::insert(&storage, &(true, treasury_output(TREASURY_AMOUNT + AMOUNT, 0)), &())
+        .unwrap();
+    Insert::<(bool, TreasuryOutput), ()>::insert(&storage, &(false, treasury_output(TREASURY_AMOUNT, 1)), &()).unwrap();
+    insert_receipt(&storage, &receipt(TREASURY_AMOUNT));
+    Insert::<MilestoneIndex, OutputDiff>::insert(
+        &storage,
+        &MilestoneIndex(1),
+        &OutputDiff::new(
+            Vec::new(),
+            Vec::new(),
+            Some(TreasuryDiff::new(milestone_id(1), milestone_id(0))),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    Insert::<(), LedgerIndex>::insert(&storage, &(), &LedgerIndex::new(MilestoneIndex(1))).unwrap();
+
+    synchronize_indexes(&storage, &IndexationConfig::build().tag(true).sender(true).finish()).unwrap();
+
+    storage
+}
+
+fn alias_id() -> AliasId {
+    AliasId::new([0x33; AliasId::LENGTH])
+}
+
+fn nft_id() -> NftId {
+    NftId::new([0x44; NftId::LENGTH])
+}
+
+/// Checks the storage, repairs it and checks that no inconsistency remains.
+fn check_and_repair(storage: &Storage) -> Vec<Inconsistency> {
+    let inconsistencies = check_storage(storage, false).unwrap().inconsistencies().to_vec();
+    let report = check_storage(storage, true).unwrap();
+
+    assert_eq!(report.inconsistencies(), inconsistencies);
+    assert_eq!(report.repaired(), inconsistencies.len());
+    assert!(check_storage(storage, false).unwrap().inconsistencies().is_empty());
+
+    inconsistencies
+}
+
+#[test]
+fn consistent() {
+    assert!(check_and_repair(&consistent_storage()).is_empty());
+}
+
+#[test]
+fn address_index_entry_of_spent_output() {
+    let storage = consistent_storage();
+    let entry = ((address(), AddressRole::Address), output_id(5));
+
+    insert_created_output(&storage, &output_id(5), basic_output());
+    Insert::<OutputId, ConsumedOutput>::insert(
+        &storage,
+        &output_id(5),
+        &ConsumedOutput::new(TransactionId::new([0xff; 32]), MilestoneIndex(1)),
+    )
+    .unwrap();
+    Insert::<((Address, AddressRole), OutputId), ()>::insert(&storage, &entry, &()).unwrap();
+
+    assert_eq!(
+        check_and_repair(&storage),
+        vec![Inconsistency::DanglingAddressIndexEntry(entry.0, entry.1)]
+    );
+}
+
+#[test]
+fn address_index_entry_of_pruned_output() {
+    let storage = consistent_storage();
+    let entry = ((address(), AddressRole::Address), output_id(5));
+
+    Insert::<((Address, AddressRole), OutputId), ()>::insert(&storage, &entry, &()).unwrap();
+
+    assert_eq!(
+        check_and_repair(&storage),
+        vec![Inconsistency::DanglingAddressIndexEntry(entry.0, entry.1)]
+    );
+}
+
+#[test]
+fn address_index_entry_with_wrong_role() {
+    let storage = consistent_storage();
+    let entry = ((address(), AddressRole::Governor), output_id(1));
+
+    Insert::<((Address, AddressRole), OutputId), ()>::insert(&storage, &entry, &()).unwrap();
+
+    assert_eq!(
+        check_and_repair(&storage),
+        vec![Inconsistency::DanglingAddressIndexEntry(entry.0, entry.1)]
+    );
+}
+
+#[test]
+fn missing_address_index_entry() {
+    let storage = consistent_storage();
+    let entry = ((address(), AddressRole::StateController), output_id(3));
+
+    Delete::<((Address, AddressRole), OutputId), ()>::delete(&storage, &entry).unwrap();
+
+    assert_eq!(
+        check_and_repair(&storage),
+        vec![Inconsistency::MissingAddressIndexEntry(entry.0, entry.1)]
+    );
+}
+
+#[test]
+fn chain_id_index_entry_of_unknown_output() {
+    let storage = consistent_storage();
+    let nft_id = NftId::new([0x55; NftId::LENGTH]);
+
+    Insert::<NftId, OutputId>::insert(&storage, &nft_id, &output_id(5)).unwrap();
+
+    assert_eq!(
+        check_and_repair(&storage),
+        vec![Inconsistency::DanglingChainIdIndexEntry(
+            IndexedChainId::Nft(nft_id),
+            output_id(5)
+        )]
+    );
+}
+
+#[test]
+fn missing_chain_id_index_entry() {
+    let storage = consistent_storage();
+
+    Delete::<NftId, OutputId>::delete(&storage, &nft_id()).unwrap();
+
+    assert_eq!(
+        check_and_repair(&storage),
+        vec![Inconsistency::MissingChainIdIndexEntry(
+            IndexedChainId::Nft(nft_id()),
+            output_id(4)
+        )]
+    );
+}
+
+#[test]
+fn chain_id_index_entry_of_previous_output() {
+    let storage = consistent_storage();
+
+    // The index still refers to the output of the previous state of the alias.
+    Insert::<AliasId, OutputId>::insert(&storage, &alias_id(), &output_id(1)).unwrap();
+
+    assert_eq!(
+        check_and_repair(&storage),
+        vec![
+            Inconsistency::DanglingChainIdIndexEntry(IndexedChainId::Alias(alias_id()), output_id(1)),
+            Inconsistency::MissingChainIdIndexEntry(IndexedChainId::Alias(alias_id()), output_id(3)),
+        ]
+    );
+    assert_eq!(
+        Fetch::<AliasId, OutputId>::fetch(&storage, &alias_id()).unwrap(),
+        Some(output_id(3))
+    );
+}
+
+#[test]
+fn tag_index_entry_with_wrong_tag() {
+    let storage = consistent_storage();
+    let entry = (TagFeatureBlock::new(b"other".to_vec()).unwrap(), output_id(1));
+
+    Insert::<(TagFeatureBlock, OutputId), ()>::insert(&storage, &entry, &()).unwrap();
+
+    assert_eq!(
+        check_and_repair(&storage),
+        vec![Inconsistency::DanglingTagIndexEntry(entry.0, entry.1)]
+    );
+}
+
+#[test]
+fn missing_tag_index_entry() {
+    let storage = consistent_storage();
+
+    Delete::<(TagFeatureBlock, OutputId), ()>::delete(&storage, &(tag(), output_id(2))).unwrap();
+
+    assert_eq!(
+        check_and_repair(&storage),
+        vec![Inconsistency::MissingTagIndexEntry(tag(), output_id(2))]
+    );
+}
+
+#[test]
+fn sender_index_entry_of_output_without_sender() {
+    let storage = consistent_storage();
+
+    Insert::<(SenderFeatureBlock, OutputId), ()>::insert(&storage, &(sender(), output_id(3)), &()).unwrap();
+
+    assert_eq!(
+        check_and_repair(&storage),
+        vec![Inconsistency::DanglingSenderIndexEntry(sender(), output_id(3))]
+    );
+}
+
+#[test]
+fn missing_sender_index_entry() {
+    let storage = consistent_storage();
+
+    Delete::<(SenderFeatureBlock, OutputId), ()>::delete(&storage, &(sender(), output_id(1))).unwrap();
+
+    assert_eq!(
+        check_and_repair(&storage),
+        vec![Inconsistency::MissingSenderIndexEntry(sender(), output_id(1))]
+    );
+}
+
+#[test]
+fn missing_receipt() {
+    let storage = consistent_storage();
+
+    Delete::<(MilestoneIndex, Receipt), ()>::delete(&storage, &(MilestoneIndex(0), receipt(TREASURY_AMOUNT))).unwrap();
+
+    let report = check_storage(&storage, true).unwrap();
+
+    assert_eq!(
+        report.inconsistencies(),
+        [Inconsistency::ReceiptTreasuryMismatch(MilestoneIndex(1))]
+    );
+    assert!(!report.inconsistencies()[0].is_repairable());
+    assert_eq!(report.repaired(), 0);
+}
+
+#[test]
+fn receipt_not_matching_created_treasury_output() {
+    let storage = consistent_storage();
+
+    Delete::<(MilestoneIndex, Receipt), ()>::delete(&storage, &(MilestoneIndex(0), receipt(TREASURY_AMOUNT))).unwrap();
+    insert_receipt(&storage, &receipt(TREASURY_AMOUNT - AMOUNT));
+
+    assert_eq!(
+        check_storage(&storage, false).unwrap().inconsistencies(),
+        [Inconsistency::ReceiptTreasuryMismatch(MilestoneIndex(1))]
+    );
+}
@@ -0,0 +1,113 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "workers")]
+
+use bee_ledger::{
+    types::{CreatedOutput, Unspent},
+    workers::indexation::{config::IndexationConfig, synchronize_indexes},
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::MilestoneIndex,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        unlock_condition::AddressUnlockCondition,
+        BasicOutputBuilder, Output, OutputId,
+    },
+    payload::transaction::TransactionId,
+    MessageId,
+};
+use bee_storage::{
+    access::{Fetch, Insert},
+    backend::StorageBackend,
+};
+use bee_storage_memory::storage::Storage;
+
+const AMOUNT: u64 = 1_000_000;
+
+fn tag() -> TagFeatureBlock {
+    TagFeatureBlock::new(b"tag".to_vec()).unwrap()
+}
+
+fn sender() -> SenderFeatureBlock {
+    SenderFeatureBlock::new(Address::from(Ed25519Address::new([0x22; 32])))
+}
+
+/// Stores an unspent output with a tag and a sender feature block.
+fn insert_unspent_output(storage: &Storage, transaction: u8) -> OutputId {
+    let output_id = OutputId::new(TransactionId::new([transaction; 32]), 0).unwrap();
+    let output = Output::from(
+        BasicOutputBuilder::new(AMOUNT)
+            .unwrap()
+            .add_unlock_condition(AddressUnlockCondition::new(Address::from(Ed25519Address::new([0x11; 32]))).into())
+            .add_feature_block(tag().into())
+            .add_feature_block(sender().into())
+            .finish()
+            .unwrap(),
+    );
+
+    Insert::<OutputId, CreatedOutput>::insert(
+        storage,
+        &output_id,
+        &CreatedOutput::new(MessageId::null(), MilestoneIndex(0), 0, output),
+    )
+    .unwrap();
+    Insert::<Unspent, ()>::insert(storage, &output_id.into(), &()).unwrap();
+
+    output_id
+}
+
+fn tag_index(storage: &Storage) -> Vec<OutputId> {
+    Fetch::<TagFeatureBlock, Vec<OutputId>>::fetch(storage, &tag())
+        .unwrap()
+        .unwrap_or_default()
+}
+
+fn sender_index(storage: &Storage) -> Vec<OutputId> {
+    Fetch::<SenderFeatureBlock, Vec<OutputId>>::fetch(storage, &sender())
+        .unwrap()
+        .unwrap_or_default()
+}
+
+#[test]
+fn enabled_empty_indexes_are_backfilled() {
+    let storage = Storage::start(()).unwrap();
+    let output_id = insert_unspent_output(&storage, 1);
+
+    synchronize_indexes(&storage, &IndexationConfig::build().tag(true).sender(true).finish()).unwrap();
+
+    assert_eq!(tag_index(&storage), vec![output_id]);
+    assert_eq!(sender_index(&storage), vec![output_id]);
+}
+
+#[test]
+fn disabled_indexes_are_truncated() {
+    let storage = Storage::start(()).unwrap();
+    insert_unspent_output(&storage, 1);
+
+    synchronize_indexes(&storage, &IndexationConfig::build().tag(true).sender(true).finish()).unwrap();
+    synchronize_indexes(&storage, &IndexationConfig::build().tag(false).sender(true).finish()).unwrap();
+
+    assert!(tag_index(&storage).is_empty());
+    assert_eq!(sender_index(&storage).len(), 1);
+}
+
+#[test]
+fn reenabled_index_is_complete() {
+    let storage = Storage::start(()).unwrap();
+    let first = insert_unspent_output(&storage, 1);
+
+    synchronize_indexes(&storage, &IndexationConfig::build().tag(true).finish()).unwrap();
+    synchronize_indexes(&storage, &IndexationConfig::build().tag(false).finish()).unwrap();
+
+    // Created while the index is disabled.
+    let second = insert_unspent_output(&storage, 2);
+
+    synchronize_indexes(&storage, &IndexationConfig::build().tag(true).finish()).unwrap();
+
+    let mut output_ids = tag_index(&storage);
+    output_ids.sort();
+
+    assert_eq!(output_ids, vec![first, second]);
+}
@@ -0,0 +1,391 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "workers")]
+
+use bee_ledger::{
+    types::{AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, TreasuryDiff, Unspent},
+    workers::{error::Error, indexation::config::IndexationConfig, rollback::rollback_ledger},
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::{
+        unlock_condition::{GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition},
+        AliasId, AliasOutputBuilder, Output, OutputId,
+    },
+    parent::Parents,
+    payload::{
+        milestone::{MilestoneEssence, MilestoneId, MilestonePayload},
+        transaction::TransactionId,
+    },
+    Message, MessageBuilder, MessageId,
+};
+use bee_storage::{
+    access::{Exist, Fetch, Insert},
+    backend::StorageBackend,
+};
+use bee_storage_memory::storage::Storage;
+use bee_tangle::{metadata::MessageMetadata, ConflictReason};
+
+const AMOUNT: u64 = 1_000_000;
+
+fn output_id(transaction: u8, index: u16) -> OutputId {
+    OutputId::new(TransactionId::new([transaction; 32]), index).unwrap()
+}
+
+fn address() -> Address {
+    Address::from(Ed25519Address::new([0x11; 32]))
+}
+
+fn alias_output(alias_id: AliasId, state_index: u32) -> Output {
+    Output::from(
+        AliasOutputBuilder::new(AMOUNT, alias_id)
+            .unwrap()
+            .with_state_index(state_index)
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(address()).into())
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(address()).into())
+            .finish()
+            .unwrap(),
+    )
+}
+
+fn milestone_message(index: MilestoneIndex, parents: Vec<MessageId>) -> Message {
+    let essence = MilestoneEssence::new(
+        index,
+        u64::from(*index),
+        Parents::new(parents.clone()).unwrap(),
+        [0; MilestoneEssence::MERKLE_PROOF_LENGTH],
+        0,
+        0,
+        vec![[0; 32]],
+        None,
+    )
+    .unwrap();
+
+    MessageBuilder::<u64>::new()
+        .with_network_id(0)
+        .with_parents(Parents::new(parents).unwrap())
+        .with_payload(MilestonePayload::new(essence, vec![[0; 64]]).unwrap().into())
+        .with_nonce_provider(0, 0f64)
+        .finish()
+        .unwrap()
+}
+
+/// Stores the milestone with the given index and its output diff, without touching the created and consumed outputs.
+fn insert_milestone(
+    storage: &Storage,
+    index: MilestoneIndex,
+    parents: Vec<MessageId>,
+    created: Vec<OutputId>,
+    consumed: Vec<OutputId>,
+) -> MessageId {
+    let message = milestone_message(index, parents);
+    let message_id = message.id();
+
+    Insert::<MessageId, Message>::insert(storage, &message_id, &message).unwrap();
+    Insert::<MilestoneIndex, Milestone>::insert(storage, &index, &Milestone::new(message_id, u64::from(*index)))
+        .unwrap();
+    Insert::<MilestoneIndex, OutputDiff>::insert(storage, &index, &OutputDiff::new(created, consumed, None).unwrap())
+        .unwrap();
+
+    message_id
+}
+
+/// Stores a message without payload and its metadata.
+fn insert_message(storage: &Storage, parents: Vec<MessageId>, metadata: MessageMetadata) -> MessageId {
+    let message = MessageBuilder::<u64>::new()
+        .with_network_id(0)
+        .with_parents(Parents::new(parents).unwrap())
+        .with_nonce_provider(0, 0f64)
+        .finish()
+        .unwrap();
+    let message_id = message.id();
+
+    Insert::<MessageId, Message>::insert(storage, &message_id, &message).unwrap();
+    Insert::<MessageId, MessageMetadata>::insert(storage, &message_id, &metadata).unwrap();
+
+    message_id
+}
+
+/// Confirms the message with the given ID the way the index updater and the consensus worker do.
+fn confirm_message(storage: &Storage, message_id: &MessageId, index: MilestoneIndex, conflict: ConflictReason) {
+    let mut metadata = Fetch::<MessageId, MessageMetadata>::fetch(storage, message_id)
+        .unwrap()
+        .unwrap_or_default();
+
+    // The index updater only sets the milestone index of messages that don't have one yet.
+    if metadata.milestone_index().is_none() {
+        metadata.set_milestone_index(index);
+    }
+    metadata.set_conflict(conflict);
+    metadata.reference(u64::from(*index));
+
+    Insert::<MessageId, MessageMetadata>::insert(storage, message_id, &metadata).unwrap();
+}
+
+fn fetch_metadata(storage: &Storage, message_id: &MessageId) -> MessageMetadata {
+    Fetch::<MessageId, MessageMetadata>::fetch(storage, message_id)
+        .unwrap()
+        .unwrap()
+}
+
+fn insert_created_output(storage: &Storage, output_id: &OutputId, index: MilestoneIndex, output: Output) {
+    Insert::<OutputId, CreatedOutput>::insert(
+        storage,
+        output_id,
+        &CreatedOutput::new(MessageId::null(), index, *index, output),
+    )
+    .unwrap();
+}
+
+fn insert_consumed_output(storage: &Storage, output_id: &OutputId, index: MilestoneIndex) {
+    Insert::<OutputId, ConsumedOutput>::insert(
+        storage,
+        output_id,
+        &ConsumedOutput::new(TransactionId::new([0xff; 32]), index),
+    )
+    .unwrap();
+}
+
+fn is_unspent(storage: &Storage, output_id: &OutputId) -> bool {
+    Exist::<Unspent, ()>::exist(storage, &(*output_id).into()).unwrap()
+}
+
+#[test]
+fn rollback_chain_created_and_consumed_within_milestone() {
+    let storage = Storage::start(()).unwrap();
+    let alias_id = AliasId::new([0x22; AliasId::LENGTH]);
+
+    // The alias is transitioned twice within milestone 1: `previous` -> `intermediate` -> `current`.
+    let previous = output_id(1, 0);
+    let intermediate = output_id(2, 0);
+    let current = output_id(3, 0);
+
+    insert_created_output(&storage, &previous, MilestoneIndex(0), alias_output(alias_id, 0));
+    insert_consumed_output(&storage, &previous, MilestoneIndex(1));
+    insert_created_output(&storage, &intermediate, MilestoneIndex(1), alias_output(alias_id, 1));
+    insert_consumed_output(&storage, &intermediate, MilestoneIndex(1));
+    insert_created_output(&storage, &current, MilestoneIndex(1), alias_output(alias_id, 2));
+    Insert::<Unspent, ()>::insert(&storage, &current.into(), &()).unwrap();
+    Insert::<AliasId, OutputId>::insert(&storage, &alias_id, &current).unwrap();
+    Insert::<((Address, AddressRole), OutputId), ()>::insert(
+        &storage,
+        &((address(), AddressRole::StateController), current),
+        &(),
+    )
+    .unwrap();
+    Insert::<(), LedgerIndex>::insert(&storage, &(), &LedgerIndex::new(MilestoneIndex(1))).unwrap();
+    insert_milestone(
+        &storage,
+        MilestoneIndex(1),
+        vec![MessageId::null()],
+        vec![intermediate, current],
+        vec![previous, intermediate],
+    );
+
+    assert_eq!(
+        rollback_ledger(&storage, MilestoneIndex(0), &IndexationConfig::build().finish()).unwrap(),
+        1
+    );
+
+    assert!(is_unspent(&storage, &previous));
+    assert!(!is_unspent(&storage, &intermediate));
+    assert!(!is_unspent(&storage, &current));
+
+    for output_id in [intermediate, current] {
+        assert!(!Exist::<OutputId, CreatedOutput>::exist(&storage, &output_id).unwrap());
+    }
+    for output_id in [previous, intermediate] {
+        assert!(!Exist::<OutputId, ConsumedOutput>::exist(&storage, &output_id).unwrap());
+    }
+
+    assert_eq!(
+        Fetch::<AliasId, OutputId>::fetch(&storage, &alias_id).unwrap(),
+        Some(previous)
+    );
+    assert!(Exist::<((Address, AddressRole), OutputId), ()>::exist(
+        &storage,
+        &((address(), AddressRole::StateController), previous)
+    )
+    .unwrap());
+    assert!(!Exist::<((Address, AddressRole), OutputId), ()>::exist(
+        &storage,
+        &((address(), AddressRole::StateController), current)
+    )
+    .unwrap());
+    assert_eq!(
+        Fetch::<(), LedgerIndex>::fetch(&storage, &()).unwrap(),
+        Some(LedgerIndex::new(MilestoneIndex(0)))
+    );
+    assert!(!Exist::<MilestoneIndex, OutputDiff>::exist(&storage, &MilestoneIndex(1)).unwrap());
+}
+
+#[test]
+fn rollback_without_ledger_index() {
+    let storage = Storage::start(()).unwrap();
+
+    assert!(matches!(
+        rollback_ledger(&storage, MilestoneIndex(0), &IndexationConfig::build().finish()),
+        Err(Error::MissingLedgerIndex)
+    ));
+}
+
+#[test]
+fn rollback_with_missing_output_diff() {
+    let storage = Storage::start(()).unwrap();
+    let alias_id = AliasId::new([0x22; AliasId::LENGTH]);
+    let previous = output_id(1, 0);
+    let current = output_id(2, 0);
+
+    // Milestone 1 has been pruned, only milestone 2 could be rolled back.
+    insert_created_output(&storage, &previous, MilestoneIndex(0), alias_output(alias_id, 0));
+    insert_consumed_output(&storage, &previous, MilestoneIndex(2));
+    insert_created_output(&storage, &current, MilestoneIndex(2), alias_output(alias_id, 1));
+    Insert::<Unspent, ()>::insert(&storage, &current.into(), &()).unwrap();
+    Insert::<AliasId, OutputId>::insert(&storage, &alias_id, &current).unwrap();
+    Insert::<(), LedgerIndex>::insert(&storage, &(), &LedgerIndex::new(MilestoneIndex(2))).unwrap();
+    insert_milestone(
+        &storage,
+        MilestoneIndex(2),
+        vec![MessageId::null()],
+        vec![current],
+        vec![previous],
+    );
+
+    assert_eq!(
+        rollback_ledger(&storage, MilestoneIndex(0), &IndexationConfig::build().finish())
+            .unwrap_err()
+            .to_string(),
+        "snapshot error: missing output diff of milestone 1"
+    );
+
+    // Nothing has been rolled back.
+    assert!(!is_unspent(&storage, &previous));
+    assert!(is_unspent(&storage, &current));
+    assert!(Exist::<OutputId, ConsumedOutput>::exist(&storage, &previous).unwrap());
+    assert_eq!(
+        Fetch::<AliasId, OutputId>::fetch(&storage, &alias_id).unwrap(),
+        Some(current)
+    );
+    assert_eq!(
+        Fetch::<(), LedgerIndex>::fetch(&storage, &()).unwrap(),
+        Some(LedgerIndex::new(MilestoneIndex(2)))
+    );
+    assert!(Exist::<MilestoneIndex, OutputDiff>::exist(&storage, &MilestoneIndex(2)).unwrap());
+}
+
+#[test]
+fn rollback_with_missing_receipt() {
+    let storage = Storage::start(()).unwrap();
+    let alias_id = AliasId::new([0x22; AliasId::LENGTH]);
+    let previous = output_id(1, 0);
+    let current = output_id(2, 0);
+
+    insert_created_output(&storage, &previous, MilestoneIndex(0), alias_output(alias_id, 0));
+    insert_consumed_output(&storage, &previous, MilestoneIndex(1));
+    insert_created_output(&storage, &current, MilestoneIndex(1), alias_output(alias_id, 1));
+    Insert::<Unspent, ()>::insert(&storage, &current.into(), &()).unwrap();
+    Insert::<(), LedgerIndex>::insert(&storage, &(), &LedgerIndex::new(MilestoneIndex(1))).unwrap();
+    insert_milestone(
+        &storage,
+        MilestoneIndex(1),
+        vec![MessageId::null()],
+        vec![current],
+        vec![previous],
+    );
+
+    // Milestone 1 transitioned the treasury, but its receipt is missing.
+    Insert::<MilestoneIndex, OutputDiff>::insert(
+        &storage,
+        &MilestoneIndex(1),
+        &OutputDiff::new(
+            vec![current],
+            vec![previous],
+            Some(TreasuryDiff::new(
+                MilestoneId::new([1; MilestoneId::LENGTH]),
+                MilestoneId::new([0; MilestoneId::LENGTH]),
+            )),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        rollback_ledger(&storage, MilestoneIndex(0), &IndexationConfig::build().finish())
+            .unwrap_err()
+            .to_string(),
+        "receipts included in milestone 1 don't match its treasury transition"
+    );
+
+    // Nothing has been rolled back.
+    assert!(!is_unspent(&storage, &previous));
+    assert!(is_unspent(&storage, &current));
+    assert_eq!(
+        Fetch::<(), LedgerIndex>::fetch(&storage, &()).unwrap(),
+        Some(LedgerIndex::new(MilestoneIndex(1)))
+    );
+}
+
+#[test]
+fn rollback_and_reconfirm_milestone_cone() {
+    let storage = Storage::start(()).unwrap();
+    let config = IndexationConfig::build().finish();
+
+    // Milestone 1 references `earlier`, milestone 2 references `message`, which was conflicting.
+    let earlier = insert_message(&storage, vec![MessageId::null()], MessageMetadata::default());
+    let milestone_1 = insert_milestone(&storage, MilestoneIndex(1), vec![earlier], Vec::new(), Vec::new());
+    let message = insert_message(&storage, vec![milestone_1], MessageMetadata::default());
+    let milestone_2 = insert_milestone(&storage, MilestoneIndex(2), vec![message], Vec::new(), Vec::new());
+
+    for message_id in [earlier, milestone_1] {
+        confirm_message(&storage, &message_id, MilestoneIndex(1), ConflictReason::None);
+    }
+    confirm_message(
+        &storage,
+        &message,
+        MilestoneIndex(2),
+        ConflictReason::InputUtxoAlreadySpent,
+    );
+    confirm_message(&storage, &milestone_2, MilestoneIndex(2), ConflictReason::None);
+    Insert::<(), LedgerIndex>::insert(&storage, &(), &LedgerIndex::new(MilestoneIndex(2))).unwrap();
+
+    let referenced_earlier = fetch_metadata(&storage, &earlier);
+
+    assert_eq!(rollback_ledger(&storage, MilestoneIndex(1), &config).unwrap(), 1);
+
+    // The confirmation state of the cone of milestone 2 has been reset, the one of milestone 1 is untouched.
+    for message_id in [message, milestone_2] {
+        let metadata = fetch_metadata(&storage, &message_id);
+
+        assert!(!metadata.flags().is_referenced());
+        assert_eq!(metadata.milestone_index(), None);
+        assert_eq!(metadata.reference_timestamp(), 0);
+        assert_eq!(metadata.conflict(), ConflictReason::None);
+    }
+    assert_eq!(fetch_metadata(&storage, &earlier), referenced_earlier);
+    assert!(fetch_metadata(&storage, &milestone_1).flags().is_referenced());
+
+    // Milestone 2 is confirmed again, this time without conflict.
+    let milestone_2 = insert_milestone(&storage, MilestoneIndex(2), vec![message], Vec::new(), Vec::new());
+
+    for message_id in [message, milestone_2] {
+        confirm_message(&storage, &message_id, MilestoneIndex(2), ConflictReason::None);
+
+        let metadata = fetch_metadata(&storage, &message_id);
+
+        assert!(metadata.flags().is_referenced());
+        assert_eq!(metadata.milestone_index(), Some(MilestoneIndex(2)));
+        assert_eq!(metadata.reference_timestamp(), 2);
+        assert_eq!(metadata.conflict(), ConflictReason::None);
+    }
+    Insert::<(), LedgerIndex>::insert(&storage, &(), &LedgerIndex::new(MilestoneIndex(2))).unwrap();
+
+    // The re-confirmed cone can be rolled back again.
+    assert_eq!(rollback_ledger(&storage, MilestoneIndex(1), &config).unwrap(), 1);
+
+    for message_id in [message, milestone_2] {
+        assert!(!fetch_metadata(&storage, &message_id).flags().is_referenced());
+    }
+    assert_eq!(fetch_metadata(&storage, &earlier), referenced_earlier);
+}
@@ -0,0 +1,1523 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "workers")]
+
+use bee_ledger::{
+    types::{CreatedOutput, Unspent},
+    workers::consensus::{white_flag, WhiteFlagMetadata},
+};
+use bee_message::{
+    address::{Address, AliasAddress, Ed25519Address, NftAddress},
+    input::{Input, UtxoInput},
+    milestone::MilestoneIndex,
+    output::{
+        feature_block::IssuerFeatureBlock,
+        unlock_condition::{
+            AddressUnlockCondition, DustDepositReturnUnlockCondition, ExpirationUnlockCondition,
+            GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition, TimelockUnlockCondition,
+            UnlockCondition,
+        },
+        AliasId, AliasOutput, AliasOutputBuilder, BasicOutputBuilder, ByteCostConfig, FoundryOutput,
+        FoundryOutputBuilder, NativeToken, NftId, NftOutputBuilder, Output, OutputId, TokenId, TokenScheme,
+    },
+    parent::Parents,
+    payload::{
+        transaction::{RegularTransactionEssence, TransactionEssence, TransactionId, TransactionPayload},
+        Payload,
+    },
+    signature::{Ed25519Signature, Signature},
+    unlock_block::{
+        AliasUnlockBlock, NftUnlockBlock, ReferenceUnlockBlock, SignatureUnlockBlock, UnlockBlock, UnlockBlocks,
+    },
+    MessageBuilder, MessageId,
+};
+use bee_runtime::resource::ResourceHandle;
+use bee_storage::{access::Insert, backend::StorageBackend};
+use bee_storage_memory::storage::Storage;
+use bee_tangle::{
+    config::TangleConfig, metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, ConflictReason, Tangle,
+};
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    signatures::ed25519::SecretKey,
+};
+use primitive_types::U256;
+
+const AMOUNT: u64 = 1_000_000;
+const MILESTONE_INDEX: u32 = 10;
+const MILESTONE_TIMESTAMP: u32 = 1_000;
+
+struct Wallet {
+    secret_key: SecretKey,
+    address: Address,
+}
+
+impl Wallet {
+    fn new(seed: u8) -> Self {
+        let secret_key = SecretKey::from_bytes([seed; 32]);
+        let address = Address::from(Ed25519Address::new(
+            Blake2b256::digest(&secret_key.public_key().to_bytes()).into(),
+        ));
+
+        Self { secret_key, address }
+    }
+
+    fn sign(&self, essence_hash: &[u8; 32]) -> UnlockBlock {
+        UnlockBlock::from(SignatureUnlockBlock::new(Signature::from(Ed25519Signature::new(
+            self.secret_key.public_key().to_bytes(),
+            self.secret_key.sign(essence_hash).to_bytes(),
+        ))))
+    }
+}
+
+fn output_id(index: u16) -> OutputId {
+    OutputId::new(TransactionId::new([0x42; 32]), index).unwrap()
+}
+
+fn basic_output(amount: u64, address: Address) -> Output {
+    Output::from(
+        BasicOutputBuilder::new(amount)
+            .unwrap()
+            .add_unlock_condition(AddressUnlockCondition::new(address).into())
+            .finish()
+            .unwrap(),
+    )
+}
+
+fn basic_output_with_native_token(amount: u64, address: Address, token_id: TokenId, tokens: u64) -> Output {
+    Output::from(
+        BasicOutputBuilder::new(amount)
+            .unwrap()
+            .add_native_token(NativeToken::new(token_id, U256::from(tokens)).unwrap())
+            .add_unlock_condition(AddressUnlockCondition::new(address).into())
+            .finish()
+            .unwrap(),
+    )
+}
+
+fn foundry_output(
+    alias_id: AliasId,
+    serial_number: u32,
+    circulating_supply: u64,
+    maximum_supply: u64,
+) -> FoundryOutput {
+    FoundryOutputBuilder::new(
+        AMOUNT,
+        serial_number,
+        [0; 12],
+        U256::from(circulating_supply),
+        U256::from(maximum_supply),
+        TokenScheme::Simple,
+    )
+    .unwrap()
+    .add_unlock_condition(AddressUnlockCondition::new(AliasAddress::new(alias_id).into()).into())
+    .finish()
+    .unwrap()
+}
+
+fn alias_output_builder(alias_id: AliasId, state_controller: Address, governor: Address) -> AliasOutputBuilder {
+    AliasOutputBuilder::new(AMOUNT, alias_id)
+        .unwrap()
+        .add_unlock_condition(StateControllerAddressUnlockCondition::new(state_controller).into())
+        .add_unlock_condition(GovernorAddressUnlockCondition::new(governor).into())
+}
+
+fn nft_output_builder(nft_id: NftId, address: Address) -> NftOutputBuilder {
+    NftOutputBuilder::new(AMOUNT, nft_id, vec![0xff; 16])
+        .unwrap()
+        .add_unlock_condition(AddressUnlockCondition::new(address).into())
+}
+
+/// Applies White Flag to a single transaction consuming the given outputs, stored as unspent beforehand, and returns
+/// the conflict it was excluded with, if any.
+async fn conflict(
+    inputs: Vec<Output>,
+    outputs: Vec<Output>,
+    unlock_blocks: impl FnOnce(&[u8; 32]) -> Vec<UnlockBlock>,
+) -> ConflictReason {
+    let storage = ResourceHandle::new(Storage::start(()).unwrap());
+
+    let inputs = inputs
+        .into_iter()
+        .enumerate()
+        .map(|(index, output)| {
+            let output_id = output_id(index as u16);
+
+            Insert::<OutputId, CreatedOutput>::insert(
+                &*storage,
+                &output_id,
+                &CreatedOutput::new(MessageId::null(), MilestoneIndex(1), 0, output),
+            )
+            .unwrap();
+            Insert::<Unspent, ()>::insert(&*storage, &output_id.into(), &()).unwrap();
+
+            Input::from(UtxoInput::from(output_id))
+        })
+        .collect();
+
+    let essence = RegularTransactionEssence::builder()
+        .with_inputs(inputs)
+        .with_outputs(outputs)
+        .finish()
+        .unwrap();
+    let unlock_blocks = UnlockBlocks::new(unlock_blocks(&TransactionEssence::from(essence.clone()).hash())).unwrap();
+    let transaction = TransactionPayload::builder()
+        .with_essence(essence.into())
+        .with_unlock_blocks(unlock_blocks)
+        .finish()
+        .unwrap();
+    let message = MessageBuilder::<u64>::new()
+        .with_network_id(0)
+        .with_parents(Parents::new(vec![MessageId::null()]).unwrap())
+        .with_payload(Payload::from(transaction))
+        .with_nonce_provider(0, 0f64)
+        .finish()
+        .unwrap();
+    let message_id = message.id();
+
+    let tangle = Tangle::new(TangleConfig::build().finish(), storage.clone());
+    tangle
+        .add_solid_entry_point(SolidEntryPoint::null(), MilestoneIndex(0))
+        .await;
+    tangle.insert(message, message_id, MessageMetadata::arrived()).await;
+
+    let mut metadata = WhiteFlagMetadata::new(
+        MilestoneIndex(MILESTONE_INDEX),
+        MILESTONE_TIMESTAMP as u64,
+        ByteCostConfig::default(),
+    );
+
+    white_flag(&tangle, &*storage, &[message_id], &mut metadata)
+        .await
+        .unwrap();
+
+    match metadata.excluded_conflicting_messages() {
+        [] => {
+            assert_eq!(metadata.included_messages(), &[message_id]);
+            ConflictReason::None
+        }
+        [(_, conflict)] => *conflict,
+        _ => unreachable!(),
+    }
+}
+
+#[tokio::test]
+async fn signature_unlock_valid() {
+    let wallet = Wallet::new(1);
+
+    assert_eq!(
+        conflict(
+            vec![basic_output(AMOUNT, wallet.address)],
+            vec![basic_output(AMOUNT, wallet.address)],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn signature_unlock_wrong_key() {
+    let wallet = Wallet::new(1);
+    let other = Wallet::new(2);
+
+    assert_eq!(
+        conflict(
+            vec![basic_output(AMOUNT, wallet.address)],
+            vec![basic_output(AMOUNT, wallet.address)],
+            |hash| vec![other.sign(hash)],
+        )
+        .await,
+        ConflictReason::InvalidSignature
+    );
+}
+
+#[tokio::test]
+async fn signature_unlock_wrong_essence() {
+    let wallet = Wallet::new(1);
+
+    assert_eq!(
+        conflict(
+            vec![basic_output(AMOUNT, wallet.address)],
+            vec![basic_output(AMOUNT, wallet.address)],
+            |_| vec![wallet.sign(&[0; 32])],
+        )
+        .await,
+        ConflictReason::InvalidSignature
+    );
+}
+
+#[tokio::test]
+async fn reference_unlock_valid() {
+    let wallet = Wallet::new(1);
+
+    assert_eq!(
+        conflict(
+            vec![
+                basic_output(AMOUNT, wallet.address),
+                basic_output(AMOUNT, wallet.address)
+            ],
+            vec![basic_output(2 * AMOUNT, wallet.address)],
+            |hash| vec![wallet.sign(hash), ReferenceUnlockBlock::new(0).unwrap().into()],
+        )
+        .await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn reference_unlock_other_address() {
+    let wallet = Wallet::new(1);
+    let other = Wallet::new(2);
+
+    assert_eq!(
+        conflict(
+            vec![
+                basic_output(AMOUNT, other.address),
+                basic_output(AMOUNT, wallet.address)
+            ],
+            vec![basic_output(2 * AMOUNT, wallet.address)],
+            |hash| vec![other.sign(hash), ReferenceUnlockBlock::new(0).unwrap().into()],
+        )
+        .await,
+        ConflictReason::InvalidSignature
+    );
+}
+
+#[tokio::test]
+async fn alias_unlock_valid() {
+    let wallet = Wallet::new(1);
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+    let alias = || alias_output_builder(alias_id, wallet.address, wallet.address);
+
+    assert_eq!(
+        conflict(
+            vec![
+                alias().finish().unwrap().into(),
+                basic_output(AMOUNT, AliasAddress::new(alias_id).into()),
+            ],
+            vec![
+                alias().with_state_index(1).finish().unwrap().into(),
+                basic_output(AMOUNT, wallet.address),
+            ],
+            |hash| vec![wallet.sign(hash), AliasUnlockBlock::new(0).unwrap().into()],
+        )
+        .await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn alias_unlock_other_alias() {
+    let wallet = Wallet::new(1);
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+    let alias = || alias_output_builder(alias_id, wallet.address, wallet.address);
+
+    assert_eq!(
+        conflict(
+            vec![
+                alias().finish().unwrap().into(),
+                basic_output(AMOUNT, AliasAddress::new(AliasId::new([2; AliasId::LENGTH])).into()),
+            ],
+            vec![
+                alias().with_state_index(1).finish().unwrap().into(),
+                basic_output(AMOUNT, wallet.address),
+            ],
+            |hash| vec![wallet.sign(hash), AliasUnlockBlock::new(0).unwrap().into()],
+        )
+        .await,
+        ConflictReason::InvalidSignature
+    );
+}
+
+#[tokio::test]
+async fn alias_unlock_not_an_alias() {
+    let wallet = Wallet::new(1);
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+
+    assert_eq!(
+        conflict(
+            vec![
+                basic_output(AMOUNT, wallet.address),
+                basic_output(AMOUNT, AliasAddress::new(alias_id).into()),
+            ],
+            vec![basic_output(2 * AMOUNT, wallet.address)],
+            |hash| vec![wallet.sign(hash), AliasUnlockBlock::new(0).unwrap().into()],
+        )
+        .await,
+        ConflictReason::InvalidSignature
+    );
+}
+
+#[tokio::test]
+async fn alias_address_signature_unlock() {
+    let wallet = Wallet::new(1);
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+    let alias = || alias_output_builder(alias_id, wallet.address, wallet.address);
+
+    assert_eq!(
+        conflict(
+            vec![
+                alias().finish().unwrap().into(),
+                basic_output(AMOUNT, AliasAddress::new(alias_id).into()),
+            ],
+            vec![
+                alias().with_state_index(1).finish().unwrap().into(),
+                basic_output(AMOUNT, wallet.address),
+            ],
+            |hash| vec![wallet.sign(hash), Wallet::new(2).sign(hash)],
+        )
+        .await,
+        ConflictReason::InvalidSignature
+    );
+}
+
+#[tokio::test]
+async fn alias_unlock_by_governance_transition() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+    let alias = || alias_output_builder(alias_id, state_controller.address, governor.address);
+
+    assert_eq!(
+        conflict(
+            vec![
+                alias().finish().unwrap().into(),
+                basic_output(AMOUNT, AliasAddress::new(alias_id).into()),
+            ],
+            vec![
+                alias_output_builder(alias_id, state_controller.address, Wallet::new(3).address)
+                    .finish()
+                    .unwrap()
+                    .into(),
+                basic_output(AMOUNT, governor.address),
+            ],
+            |hash| vec![governor.sign(hash), AliasUnlockBlock::new(0).unwrap().into()],
+        )
+        .await,
+        ConflictReason::InvalidAliasUnlock
+    );
+}
+
+#[tokio::test]
+async fn nft_unlock_valid() {
+    let wallet = Wallet::new(1);
+    let nft_id = NftId::new([1; NftId::LENGTH]);
+    let nft = nft_output_builder(nft_id, wallet.address).finish().unwrap();
+
+    assert_eq!(
+        conflict(
+            vec![nft.clone().into(), basic_output(AMOUNT, NftAddress::new(nft_id).into())],
+            vec![nft.into(), basic_output(AMOUNT, wallet.address)],
+            |hash| vec![wallet.sign(hash), NftUnlockBlock::new(0).unwrap().into()],
+        )
+        .await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn nft_unlock_other_nft() {
+    let wallet = Wallet::new(1);
+    let nft_id = NftId::new([1; NftId::LENGTH]);
+    let nft = nft_output_builder(nft_id, wallet.address).finish().unwrap();
+
+    assert_eq!(
+        conflict(
+            vec![
+                nft.clone().into(),
+                basic_output(AMOUNT, NftAddress::new(NftId::new([2; NftId::LENGTH])).into()),
+            ],
+            vec![nft.into(), basic_output(AMOUNT, wallet.address)],
+            |hash| vec![wallet.sign(hash), NftUnlockBlock::new(0).unwrap().into()],
+        )
+        .await,
+        ConflictReason::InvalidNftUnlockBlock
+    );
+}
+
+#[tokio::test]
+async fn nft_unlock_not_an_nft() {
+    let wallet = Wallet::new(1);
+    let nft_id = NftId::new([1; NftId::LENGTH]);
+
+    assert_eq!(
+        conflict(
+            vec![
+                basic_output(AMOUNT, wallet.address),
+                basic_output(AMOUNT, NftAddress::new(nft_id).into()),
+            ],
+            vec![basic_output(2 * AMOUNT, wallet.address)],
+            |hash| vec![wallet.sign(hash), NftUnlockBlock::new(0).unwrap().into()],
+        )
+        .await,
+        ConflictReason::InvalidNftUnlockBlock
+    );
+}
+
+#[tokio::test]
+async fn nft_address_signature_unlock() {
+    let wallet = Wallet::new(1);
+    let nft_id = NftId::new([1; NftId::LENGTH]);
+    let nft = nft_output_builder(nft_id, wallet.address).finish().unwrap();
+
+    assert_eq!(
+        conflict(
+            vec![nft.clone().into(), basic_output(AMOUNT, NftAddress::new(nft_id).into())],
+            vec![nft.into(), basic_output(AMOUNT, wallet.address)],
+            |hash| vec![wallet.sign(hash), Wallet::new(2).sign(hash)],
+        )
+        .await,
+        ConflictReason::InvalidNftUnlockBlock
+    );
+}
+
+#[tokio::test]
+async fn created_amount_above_consumed_amount() {
+    let wallet = Wallet::new(1);
+
+    assert_eq!(
+        conflict(
+            vec![basic_output(AMOUNT, wallet.address)],
+            vec![
+                basic_output(AMOUNT, wallet.address),
+                basic_output(AMOUNT, wallet.address)
+            ],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::InputOutputSumMismatch
+    );
+}
+
+#[tokio::test]
+async fn created_amount_below_consumed_amount() {
+    let wallet = Wallet::new(1);
+
+    assert_eq!(
+        conflict(
+            vec![
+                basic_output(AMOUNT, wallet.address),
+                basic_output(AMOUNT, wallet.address)
+            ],
+            vec![basic_output(AMOUNT, wallet.address)],
+            |hash| vec![wallet.sign(hash), ReferenceUnlockBlock::new(0).unwrap().into()],
+        )
+        .await,
+        ConflictReason::InputOutputSumMismatch
+    );
+}
+
+#[tokio::test]
+async fn native_tokens_balanced() {
+    let wallet = Wallet::new(1);
+    let token_id = TokenId::new([1; TokenId::LENGTH]);
+
+    assert_eq!(
+        conflict(
+            vec![basic_output_with_native_token(
+                2 * AMOUNT,
+                wallet.address,
+                token_id,
+                100
+            )],
+            vec![
+                basic_output_with_native_token(AMOUNT, wallet.address, token_id, 60),
+                basic_output_with_native_token(AMOUNT, wallet.address, token_id, 40),
+            ],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn native_tokens_created_above_consumed() {
+    let wallet = Wallet::new(1);
+    let token_id = TokenId::new([1; TokenId::LENGTH]);
+
+    assert_eq!(
+        conflict(
+            vec![basic_output_with_native_token(AMOUNT, wallet.address, token_id, 100)],
+            vec![basic_output_with_native_token(AMOUNT, wallet.address, token_id, 101)],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::NativeTokensSumMismatch
+    );
+}
+
+#[tokio::test]
+async fn native_tokens_created_below_consumed() {
+    let wallet = Wallet::new(1);
+    let token_id = TokenId::new([1; TokenId::LENGTH]);
+
+    assert_eq!(
+        conflict(
+            vec![basic_output_with_native_token(AMOUNT, wallet.address, token_id, 100)],
+            vec![basic_output_with_native_token(AMOUNT, wallet.address, token_id, 99)],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::NativeTokensSumMismatch
+    );
+}
+
+/// Applies a transaction consuming an alias, one of its foundries and a basic output, and creating their transitions.
+async fn foundry_transition(
+    consumed_supply: (u64, u64),
+    consumed_tokens: u64,
+    created_supply: (u64, u64),
+    created_tokens: u64,
+) -> ConflictReason {
+    let wallet = Wallet::new(1);
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+    let alias = || alias_output_builder(alias_id, wallet.address, wallet.address);
+    let token_id = foundry_output(alias_id, 1, 0, 1).token_id();
+
+    conflict(
+        vec![
+            alias().finish().unwrap().into(),
+            foundry_output(alias_id, 1, consumed_supply.0, consumed_supply.1).into(),
+            basic_output_with_native_token(AMOUNT, wallet.address, token_id, consumed_tokens),
+        ],
+        vec![
+            alias().with_state_index(1).finish().unwrap().into(),
+            foundry_output(alias_id, 1, created_supply.0, created_supply.1).into(),
+            basic_output_with_native_token(AMOUNT, wallet.address, token_id, created_tokens),
+        ],
+        |hash| {
+            vec![
+                wallet.sign(hash),
+                AliasUnlockBlock::new(0).unwrap().into(),
+                ReferenceUnlockBlock::new(0).unwrap().into(),
+            ]
+        },
+    )
+    .await
+}
+
+#[tokio::test]
+async fn foundry_mint() {
+    assert_eq!(
+        foundry_transition((100, 1000), 100, (300, 1000), 300).await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn foundry_mint_above_supply_change() {
+    assert_eq!(
+        foundry_transition((100, 1000), 100, (300, 1000), 301).await,
+        ConflictReason::InvalidFoundrySupplyChange
+    );
+}
+
+#[tokio::test]
+async fn foundry_mint_below_supply_change() {
+    assert_eq!(
+        foundry_transition((100, 1000), 100, (300, 1000), 299).await,
+        ConflictReason::InvalidFoundrySupplyChange
+    );
+}
+
+#[tokio::test]
+async fn foundry_melt() {
+    assert_eq!(
+        foundry_transition((100, 1000), 100, (40, 1000), 40).await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn foundry_melt_supply_mismatch() {
+    assert_eq!(
+        foundry_transition((100, 1000), 100, (40, 1000), 50).await,
+        ConflictReason::InvalidFoundrySupplyChange
+    );
+}
+
+#[tokio::test]
+async fn foundry_unchanged_supply_with_unbalanced_native_tokens() {
+    assert_eq!(
+        foundry_transition((100, 1000), 100, (100, 1000), 150).await,
+        ConflictReason::NativeTokensSumMismatch
+    );
+}
+
+#[tokio::test]
+async fn foundry_maximum_supply_change() {
+    assert_eq!(
+        foundry_transition((100, 1000), 100, (100, 2000), 100).await,
+        ConflictReason::InvalidFoundrySupplyChange
+    );
+}
+
+/// Applies a transaction state transitioning an alias between the given foundry counters and creating new foundries
+/// with the given serial numbers.
+async fn foundry_creation(foundry_counters: (u32, u32), serial_numbers: &[u32]) -> ConflictReason {
+    let wallet = Wallet::new(1);
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+    let alias = || alias_output_builder(alias_id, wallet.address, wallet.address);
+    let mut outputs = vec![alias()
+        .with_state_index(1)
+        .with_foundry_counter(foundry_counters.1)
+        .finish()
+        .unwrap()
+        .into()];
+    outputs.extend(
+        serial_numbers
+            .iter()
+            .map(|serial_number| Output::from(foundry_output(alias_id, *serial_number, 0, 1000))),
+    );
+
+    conflict(
+        vec![
+            alias()
+                .with_foundry_counter(foundry_counters.0)
+                .finish()
+                .unwrap()
+                .into(),
+            basic_output(serial_numbers.len() as u64 * AMOUNT, wallet.address),
+        ],
+        outputs,
+        |hash| vec![wallet.sign(hash), ReferenceUnlockBlock::new(0).unwrap().into()],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn foundry_creation_valid() {
+    assert_eq!(foundry_creation((1, 3), &[2, 3]).await, ConflictReason::None);
+}
+
+#[tokio::test]
+async fn foundry_creation_above_foundry_counter_increase() {
+    assert_eq!(
+        foundry_creation((1, 2), &[2, 3]).await,
+        ConflictReason::InvalidFoundryCreation
+    );
+}
+
+#[tokio::test]
+async fn foundry_creation_below_foundry_counter_increase() {
+    assert_eq!(
+        foundry_creation((1, 3), &[2]).await,
+        ConflictReason::InvalidFoundryCreation
+    );
+}
+
+#[tokio::test]
+async fn foundry_creation_with_used_serial_number() {
+    assert_eq!(
+        foundry_creation((1, 2), &[1]).await,
+        ConflictReason::InvalidFoundryCreation
+    );
+}
+
+#[tokio::test]
+async fn foundry_creation_without_alias_transition() {
+    let wallet = Wallet::new(1);
+
+    assert_eq!(
+        conflict(
+            vec![basic_output(AMOUNT, wallet.address)],
+            vec![foundry_output(AliasId::new([1; AliasId::LENGTH]), 1, 0, 1000).into()],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::InvalidFoundryCreation
+    );
+}
+
+/// Applies a transaction consuming the current alias output and creating the next one, or a basic output if there is
+/// none.
+async fn alias_transition(current: AliasOutput, next: Option<AliasOutput>, signer: &Wallet) -> ConflictReason {
+    let next = next.map_or_else(|| basic_output(AMOUNT, signer.address), Output::from);
+
+    conflict(vec![current.into()], vec![next], |hash| vec![signer.sign(hash)]).await
+}
+
+#[tokio::test]
+async fn alias_state_transition() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias = || {
+        alias_output_builder(
+            AliasId::new([1; AliasId::LENGTH]),
+            state_controller.address,
+            governor.address,
+        )
+    };
+
+    assert_eq!(
+        alias_transition(
+            alias().with_state_index(1).finish().unwrap(),
+            Some(
+                alias()
+                    .with_state_index(2)
+                    .with_state_metadata(vec![1, 2, 3])
+                    .finish()
+                    .unwrap()
+            ),
+            &state_controller,
+        )
+        .await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn alias_state_transition_unlocked_by_governor() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias = || {
+        alias_output_builder(
+            AliasId::new([1; AliasId::LENGTH]),
+            state_controller.address,
+            governor.address,
+        )
+    };
+
+    assert_eq!(
+        alias_transition(
+            alias().finish().unwrap(),
+            Some(alias().with_state_index(1).finish().unwrap()),
+            &governor,
+        )
+        .await,
+        ConflictReason::InvalidSignature
+    );
+}
+
+#[tokio::test]
+async fn alias_state_transition_changing_governor() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+
+    assert_eq!(
+        alias_transition(
+            alias_output_builder(alias_id, state_controller.address, governor.address)
+                .finish()
+                .unwrap(),
+            Some(
+                alias_output_builder(alias_id, state_controller.address, Wallet::new(3).address)
+                    .with_state_index(1)
+                    .finish()
+                    .unwrap()
+            ),
+            &state_controller,
+        )
+        .await,
+        ConflictReason::InvalidAliasStateTransition
+    );
+}
+
+#[tokio::test]
+async fn alias_state_transition_changing_issuer() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias = || {
+        alias_output_builder(
+            AliasId::new([1; AliasId::LENGTH]),
+            state_controller.address,
+            governor.address,
+        )
+    };
+
+    assert_eq!(
+        alias_transition(
+            alias()
+                .add_feature_block(IssuerFeatureBlock::new(governor.address).into())
+                .finish()
+                .unwrap(),
+            Some(
+                alias()
+                    .with_state_index(1)
+                    .add_feature_block(IssuerFeatureBlock::new(state_controller.address).into())
+                    .finish()
+                    .unwrap()
+            ),
+            &state_controller,
+        )
+        .await,
+        ConflictReason::InvalidAliasStateTransition
+    );
+}
+
+#[tokio::test]
+async fn alias_state_transition_decreasing_foundry_counter() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias = || {
+        alias_output_builder(
+            AliasId::new([1; AliasId::LENGTH]),
+            state_controller.address,
+            governor.address,
+        )
+    };
+
+    assert_eq!(
+        alias_transition(
+            alias().with_foundry_counter(2).finish().unwrap(),
+            Some(alias().with_state_index(1).with_foundry_counter(1).finish().unwrap()),
+            &state_controller,
+        )
+        .await,
+        ConflictReason::InvalidAliasStateTransition
+    );
+}
+
+#[tokio::test]
+async fn alias_state_transition_increasing_foundry_counter_without_foundry() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias = || {
+        alias_output_builder(
+            AliasId::new([1; AliasId::LENGTH]),
+            state_controller.address,
+            governor.address,
+        )
+    };
+
+    assert_eq!(
+        alias_transition(
+            alias().finish().unwrap(),
+            Some(alias().with_state_index(1).with_foundry_counter(1).finish().unwrap()),
+            &state_controller,
+        )
+        .await,
+        ConflictReason::InvalidFoundryCreation
+    );
+}
+
+#[tokio::test]
+async fn alias_governance_transition() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+
+    assert_eq!(
+        alias_transition(
+            alias_output_builder(alias_id, state_controller.address, governor.address)
+                .with_state_index(1)
+                .finish()
+                .unwrap(),
+            Some(
+                alias_output_builder(alias_id, Wallet::new(3).address, Wallet::new(4).address)
+                    .with_state_index(1)
+                    .finish()
+                    .unwrap()
+            ),
+            &governor,
+        )
+        .await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn alias_governance_transition_unlocked_by_state_controller() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+
+    assert_eq!(
+        alias_transition(
+            alias_output_builder(alias_id, state_controller.address, governor.address)
+                .finish()
+                .unwrap(),
+            Some(
+                alias_output_builder(alias_id, state_controller.address, Wallet::new(3).address)
+                    .finish()
+                    .unwrap()
+            ),
+            &state_controller,
+        )
+        .await,
+        ConflictReason::InvalidSignature
+    );
+}
+
+#[tokio::test]
+async fn alias_governance_transition_changing_state_metadata() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias = || {
+        alias_output_builder(
+            AliasId::new([1; AliasId::LENGTH]),
+            state_controller.address,
+            governor.address,
+        )
+    };
+
+    assert_eq!(
+        alias_transition(
+            alias().finish().unwrap(),
+            Some(alias().with_state_metadata(vec![1, 2, 3]).finish().unwrap()),
+            &governor,
+        )
+        .await,
+        ConflictReason::InvalidAliasGovernanceTransition
+    );
+}
+
+#[tokio::test]
+async fn alias_governance_transition_changing_foundry_counter() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias = || {
+        alias_output_builder(
+            AliasId::new([1; AliasId::LENGTH]),
+            state_controller.address,
+            governor.address,
+        )
+    };
+
+    assert_eq!(
+        alias_transition(
+            alias().finish().unwrap(),
+            Some(alias().with_foundry_counter(1).finish().unwrap()),
+            &governor,
+        )
+        .await,
+        ConflictReason::InvalidAliasGovernanceTransition
+    );
+}
+
+#[tokio::test]
+async fn alias_governance_transition_changing_issuer() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias = || {
+        alias_output_builder(
+            AliasId::new([1; AliasId::LENGTH]),
+            state_controller.address,
+            governor.address,
+        )
+    };
+
+    assert_eq!(
+        alias_transition(
+            alias()
+                .add_feature_block(IssuerFeatureBlock::new(governor.address).into())
+                .finish()
+                .unwrap(),
+            Some(
+                alias()
+                    .add_feature_block(IssuerFeatureBlock::new(state_controller.address).into())
+                    .finish()
+                    .unwrap()
+            ),
+            &governor,
+        )
+        .await,
+        ConflictReason::InvalidAliasGovernanceTransition
+    );
+}
+
+#[tokio::test]
+async fn alias_state_index_skipped() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias = || {
+        alias_output_builder(
+            AliasId::new([1; AliasId::LENGTH]),
+            state_controller.address,
+            governor.address,
+        )
+    };
+
+    assert_eq!(
+        alias_transition(
+            alias().with_state_index(1).finish().unwrap(),
+            Some(alias().with_state_index(3).finish().unwrap()),
+            &governor,
+        )
+        .await,
+        ConflictReason::InvalidAliasStateIndex
+    );
+}
+
+#[tokio::test]
+async fn alias_state_index_decreased() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias = || {
+        alias_output_builder(
+            AliasId::new([1; AliasId::LENGTH]),
+            state_controller.address,
+            governor.address,
+        )
+    };
+
+    assert_eq!(
+        alias_transition(
+            alias().with_state_index(1).finish().unwrap(),
+            Some(alias().finish().unwrap()),
+            &governor,
+        )
+        .await,
+        ConflictReason::InvalidAliasStateIndex
+    );
+}
+
+#[tokio::test]
+async fn alias_destruction() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias = alias_output_builder(
+        AliasId::new([1; AliasId::LENGTH]),
+        state_controller.address,
+        governor.address,
+    );
+
+    assert_eq!(
+        alias_transition(alias.finish().unwrap(), None, &governor).await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn alias_destruction_unlocked_by_state_controller() {
+    let (state_controller, governor) = (Wallet::new(1), Wallet::new(2));
+    let alias = alias_output_builder(
+        AliasId::new([1; AliasId::LENGTH]),
+        state_controller.address,
+        governor.address,
+    );
+
+    assert_eq!(
+        alias_transition(alias.finish().unwrap(), None, &state_controller).await,
+        ConflictReason::InvalidAliasDestruction
+    );
+}
+
+#[tokio::test]
+async fn alias_creation() {
+    let wallet = Wallet::new(1);
+
+    assert_eq!(
+        conflict(
+            vec![basic_output(AMOUNT, wallet.address)],
+            vec![alias_output_builder(AliasId::null(), wallet.address, wallet.address)
+                .finish()
+                .unwrap()
+                .into()],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn alias_creation_with_non_null_id() {
+    let wallet = Wallet::new(1);
+
+    assert_eq!(
+        conflict(
+            vec![basic_output(AMOUNT, wallet.address)],
+            vec![
+                alias_output_builder(AliasId::new([1; AliasId::LENGTH]), wallet.address, wallet.address)
+                    .finish()
+                    .unwrap()
+                    .into()
+            ],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::InvalidAliasCreation
+    );
+}
+
+#[tokio::test]
+async fn nft_creation() {
+    let wallet = Wallet::new(1);
+
+    assert_eq!(
+        conflict(
+            vec![basic_output(AMOUNT, wallet.address)],
+            vec![nft_output_builder(NftId::null(), wallet.address)
+                .finish()
+                .unwrap()
+                .into()],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn nft_creation_with_non_null_id() {
+    let wallet = Wallet::new(1);
+
+    assert_eq!(
+        conflict(
+            vec![basic_output(AMOUNT, wallet.address)],
+            vec![nft_output_builder(NftId::new([1; NftId::LENGTH]), wallet.address)
+                .finish()
+                .unwrap()
+                .into()],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::InvalidNftCreation
+    );
+}
+
+#[tokio::test]
+async fn nft_creation_with_unlocked_issuer() {
+    let wallet = Wallet::new(1);
+
+    assert_eq!(
+        conflict(
+            vec![basic_output(AMOUNT, wallet.address)],
+            vec![nft_output_builder(NftId::null(), Wallet::new(2).address)
+                .add_feature_block(IssuerFeatureBlock::new(wallet.address).into())
+                .finish()
+                .unwrap()
+                .into()],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn nft_creation_with_locked_issuer() {
+    let wallet = Wallet::new(1);
+
+    assert_eq!(
+        conflict(
+            vec![basic_output(AMOUNT, wallet.address)],
+            vec![nft_output_builder(NftId::null(), wallet.address)
+                .add_feature_block(IssuerFeatureBlock::new(Wallet::new(2).address).into())
+                .finish()
+                .unwrap()
+                .into()],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::InvalidNftCreation
+    );
+}
+
+#[tokio::test]
+async fn nft_transfer() {
+    let wallet = Wallet::new(1);
+    let nft_id = NftId::new([1; NftId::LENGTH]);
+
+    assert_eq!(
+        conflict(
+            vec![nft_output_builder(nft_id, wallet.address).finish().unwrap().into()],
+            vec![nft_output_builder(nft_id, Wallet::new(2).address)
+                .finish()
+                .unwrap()
+                .into()],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn nft_transition_changing_immutable_metadata() {
+    let wallet = Wallet::new(1);
+    let nft_id = NftId::new([1; NftId::LENGTH]);
+
+    assert_eq!(
+        conflict(
+            vec![nft_output_builder(nft_id, wallet.address).finish().unwrap().into()],
+            vec![NftOutputBuilder::new(AMOUNT, nft_id, vec![0xee; 16])
+                .unwrap()
+                .add_unlock_condition(AddressUnlockCondition::new(wallet.address).into())
+                .finish()
+                .unwrap()
+                .into()],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::InvalidNftTransition
+    );
+}
+
+#[tokio::test]
+async fn nft_transition_changing_issuer() {
+    let wallet = Wallet::new(1);
+    let nft = || nft_output_builder(NftId::new([1; NftId::LENGTH]), wallet.address);
+
+    assert_eq!(
+        conflict(
+            vec![nft()
+                .add_feature_block(IssuerFeatureBlock::new(wallet.address).into())
+                .finish()
+                .unwrap()
+                .into()],
+            vec![nft()
+                .add_feature_block(IssuerFeatureBlock::new(Wallet::new(2).address).into())
+                .finish()
+                .unwrap()
+                .into()],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::InvalidNftTransition
+    );
+}
+
+#[tokio::test]
+async fn nft_transition_removing_issuer() {
+    let wallet = Wallet::new(1);
+    let nft = || nft_output_builder(NftId::new([1; NftId::LENGTH]), wallet.address);
+
+    assert_eq!(
+        conflict(
+            vec![nft()
+                .add_feature_block(IssuerFeatureBlock::new(wallet.address).into())
+                .finish()
+                .unwrap()
+                .into()],
+            vec![nft().finish().unwrap().into()],
+            |hash| vec![wallet.sign(hash)],
+        )
+        .await,
+        ConflictReason::InvalidNftTransition
+    );
+}
+
+#[tokio::test]
+async fn nft_duplication() {
+    let wallet = Wallet::new(1);
+    let nft = || nft_output_builder(NftId::new([1; NftId::LENGTH]), wallet.address);
+
+    assert_eq!(
+        conflict(
+            vec![nft().finish().unwrap().into(), basic_output(AMOUNT, wallet.address)],
+            vec![nft().finish().unwrap().into(), nft().finish().unwrap().into()],
+            |hash| vec![wallet.sign(hash), ReferenceUnlockBlock::new(0).unwrap().into()],
+        )
+        .await,
+        ConflictReason::InvalidNftCreation
+    );
+}
+
+/// Applies a transaction consuming a basic output owned by the first wallet with an additional unlock condition.
+async fn unlock_condition_conflict(unlock_condition: UnlockCondition, signer: &Wallet) -> ConflictReason {
+    let input = BasicOutputBuilder::new(AMOUNT)
+        .unwrap()
+        .add_unlock_condition(AddressUnlockCondition::new(Wallet::new(1).address).into())
+        .add_unlock_condition(unlock_condition)
+        .finish()
+        .unwrap();
+
+    conflict(vec![input.into()], vec![basic_output(AMOUNT, signer.address)], |hash| {
+        vec![signer.sign(hash)]
+    })
+    .await
+}
+
+fn timelock(milestone_index: u32, timestamp: u32) -> UnlockCondition {
+    TimelockUnlockCondition::new(MilestoneIndex(milestone_index), timestamp)
+        .unwrap()
+        .into()
+}
+
+fn expiration(milestone_index: u32, timestamp: u32) -> UnlockCondition {
+    ExpirationUnlockCondition::new(Wallet::new(2).address, MilestoneIndex(milestone_index), timestamp)
+        .unwrap()
+        .into()
+}
+
+#[tokio::test]
+async fn timelock_milestone_index_before_milestone() {
+    assert_eq!(
+        unlock_condition_conflict(timelock(MILESTONE_INDEX - 1, 0), &Wallet::new(1)).await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn timelock_milestone_index_at_milestone() {
+    assert_eq!(
+        unlock_condition_conflict(timelock(MILESTONE_INDEX, 0), &Wallet::new(1)).await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn timelock_milestone_index_after_milestone() {
+    assert_eq!(
+        unlock_condition_conflict(timelock(MILESTONE_INDEX + 1, 0), &Wallet::new(1)).await,
+        ConflictReason::TimelockNotExpired
+    );
+}
+
+#[tokio::test]
+async fn timelock_timestamp_before_milestone() {
+    assert_eq!(
+        unlock_condition_conflict(timelock(0, MILESTONE_TIMESTAMP - 1), &Wallet::new(1)).await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn timelock_timestamp_at_milestone() {
+    assert_eq!(
+        unlock_condition_conflict(timelock(0, MILESTONE_TIMESTAMP), &Wallet::new(1)).await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn timelock_timestamp_after_milestone() {
+    assert_eq!(
+        unlock_condition_conflict(timelock(0, MILESTONE_TIMESTAMP + 1), &Wallet::new(1)).await,
+        ConflictReason::TimelockNotExpired
+    );
+}
+
+#[tokio::test]
+async fn timelock_milestone_index_reached_timestamp_not_reached() {
+    assert_eq!(
+        unlock_condition_conflict(timelock(MILESTONE_INDEX, MILESTONE_TIMESTAMP + 1), &Wallet::new(1)).await,
+        ConflictReason::TimelockNotExpired
+    );
+}
+
+#[tokio::test]
+async fn expiration_milestone_index_before_milestone() {
+    let condition = || expiration(MILESTONE_INDEX - 1, 0);
+
+    assert_eq!(
+        unlock_condition_conflict(condition(), &Wallet::new(1)).await,
+        ConflictReason::InvalidSignature
+    );
+    assert_eq!(
+        unlock_condition_conflict(condition(), &Wallet::new(2)).await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn expiration_milestone_index_at_milestone() {
+    let condition = || expiration(MILESTONE_INDEX, 0);
+
+    assert_eq!(
+        unlock_condition_conflict(condition(), &Wallet::new(1)).await,
+        ConflictReason::InvalidSignature
+    );
+    assert_eq!(
+        unlock_condition_conflict(condition(), &Wallet::new(2)).await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn expiration_milestone_index_after_milestone() {
+    let condition = || expiration(MILESTONE_INDEX + 1, 0);
+
+    assert_eq!(
+        unlock_condition_conflict(condition(), &Wallet::new(1)).await,
+        ConflictReason::None
+    );
+    assert_eq!(
+        unlock_condition_conflict(condition(), &Wallet::new(2)).await,
+        ConflictReason::InvalidSignature
+    );
+}
+
+#[tokio::test]
+async fn expiration_timestamp_before_milestone() {
+    let condition = || expiration(0, MILESTONE_TIMESTAMP - 1);
+
+    assert_eq!(
+        unlock_condition_conflict(condition(), &Wallet::new(1)).await,
+        ConflictReason::InvalidSignature
+    );
+    assert_eq!(
+        unlock_condition_conflict(condition(), &Wallet::new(2)).await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn expiration_timestamp_at_milestone() {
+    let condition = || expiration(0, MILESTONE_TIMESTAMP);
+
+    assert_eq!(
+        unlock_condition_conflict(condition(), &Wallet::new(1)).await,
+        ConflictReason::InvalidSignature
+    );
+    assert_eq!(
+        unlock_condition_conflict(condition(), &Wallet::new(2)).await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn expiration_timestamp_after_milestone() {
+    let condition = || expiration(0, MILESTONE_TIMESTAMP + 1);
+
+    assert_eq!(
+        unlock_condition_conflict(condition(), &Wallet::new(1)).await,
+        ConflictReason::None
+    );
+    assert_eq!(
+        unlock_condition_conflict(condition(), &Wallet::new(2)).await,
+        ConflictReason::InvalidSignature
+    );
+}
+
+/// Applies a transaction consuming a basic output owned by the first wallet that has to return half of its amount to
+/// the second wallet, and creating the given outputs.
+async fn dust_deposit_return_conflict(outputs: Vec<Output>) -> ConflictReason {
+    let owner = Wallet::new(1);
+    let input = BasicOutputBuilder::new(2 * AMOUNT)
+        .unwrap()
+        .add_unlock_condition(AddressUnlockCondition::new(owner.address).into())
+        .add_unlock_condition(
+            DustDepositReturnUnlockCondition::new(Wallet::new(2).address, AMOUNT)
+                .unwrap()
+                .into(),
+        )
+        .finish()
+        .unwrap();
+
+    conflict(vec![input.into()], outputs, |hash| vec![owner.sign(hash)]).await
+}
+
+#[tokio::test]
+async fn dust_deposit_returned() {
+    assert_eq!(
+        dust_deposit_return_conflict(vec![
+            basic_output(AMOUNT, Wallet::new(1).address),
+            basic_output(AMOUNT, Wallet::new(2).address),
+        ])
+        .await,
+        ConflictReason::None
+    );
+}
+
+#[tokio::test]
+async fn dust_deposit_return_insufficient() {
+    assert_eq!(
+        dust_deposit_return_conflict(vec![
+            basic_output(AMOUNT + 1, Wallet::new(1).address),
+            basic_output(AMOUNT - 1, Wallet::new(2).address),
+        ])
+        .await,
+        ConflictReason::DustDepositReturnMismatch
+    );
+}
+
+#[tokio::test]
+async fn dust_deposit_return_to_conditional_output() {
+    let conditional_output = BasicOutputBuilder::new(AMOUNT)
+        .unwrap()
+        .add_unlock_condition(AddressUnlockCondition::new(Wallet::new(2).address).into())
+        .add_unlock_condition(timelock(MILESTONE_INDEX + 1, 0))
+        .finish()
+        .unwrap();
+
+    assert_eq!(
+        dust_deposit_return_conflict(vec![
+            basic_output(AMOUNT, Wallet::new(1).address),
+            conditional_output.into(),
+        ])
+        .await,
+        ConflictReason::DustDepositReturnMismatch
+    );
+}
@@ -36,6 +36,7 @@ pub enum Error {
     InvalidAddress,
     InvalidAddressKind(u8),
     InvalidAliasIndex(<UnlockBlockIndex as TryFrom<u16>>::Error),
+    InvalidByteCostConfig,
     InvalidControllerKind(u8),
     InvalidDustDepositAmount(<DustDepositAmount as TryFrom<u64>>::Error),
     InvalidEssenceKind(u8),
@@ -137,6 +138,7 @@ impl fmt::Display for Error {
             Error::InvalidAddress => write!(f, "invalid address provided"),
             Error::InvalidAddressKind(k) => write!(f, "invalid address kind: {}", k),
             Error::InvalidAliasIndex(index) => write!(f, "invalid alias index: {}", index),
+            Error::InvalidByteCostConfig => write!(f, "invalid byte cost config: the byte cost of outputs overflows"),
             Error::InvalidControllerKind(k) => write!(f, "invalid controller kind: {}", k),
             Error::InvalidDustDepositAmount(amount) => {
                 write!(f, "invalid dust deposit amount: {}", amount)
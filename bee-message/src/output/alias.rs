@@ -338,7 +338,7 @@ impl Packable for AliasOutput {
 
 #[inline]
 fn verify_index_counter(alias_id: &AliasId, state_index: u32, foundry_counter: u32) -> Result<(), Error> {
-    if alias_id.as_ref().iter().all(|&b| b == 0) && (state_index != 0 || foundry_counter != 0) {
+    if alias_id.is_null() && (state_index != 0 || foundry_counter != 0) {
         return Err(Error::NonZeroStateIndexOrFoundryCounter);
     }
 
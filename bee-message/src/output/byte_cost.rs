@@ -0,0 +1,126 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{milestone::MilestoneIndex, output::OutputId, Error, Message, MessageId};
+
+const DEFAULT_BYTE_COST: u64 = 500;
+const DEFAULT_BYTE_COST_FACTOR_KEY: u64 = 10;
+const DEFAULT_BYTE_COST_FACTOR_DATA: u64 = 1;
+
+/// Builder for a [`ByteCostConfig`].
+#[derive(Default)]
+#[must_use]
+pub struct ByteCostConfigBuilder {
+    v_byte_cost: Option<u64>,
+    v_byte_factor_key: Option<u64>,
+    v_byte_factor_data: Option<u64>,
+}
+
+impl ByteCostConfigBuilder {
+    /// Creates a new [`ByteCostConfigBuilder`] with default parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the byte cost, i.e. the deposit required per virtual byte.
+    pub fn byte_cost(mut self, byte_cost: u64) -> Self {
+        self.v_byte_cost.replace(byte_cost);
+        self
+    }
+
+    /// Sets the virtual byte weight of key fields, i.e. fields that are used as database keys.
+    pub fn key_factor(mut self, weight: u64) -> Self {
+        self.v_byte_factor_key.replace(weight);
+        self
+    }
+
+    /// Sets the virtual byte weight of data fields.
+    pub fn data_factor(mut self, weight: u64) -> Self {
+        self.v_byte_factor_data.replace(weight);
+        self
+    }
+
+    /// Finishes the builder into a [`ByteCostConfig`].
+    ///
+    /// Fails if the byte cost of an output as large as a message can't be computed without overflowing.
+    pub fn finish(self) -> Result<ByteCostConfig, Error> {
+        let v_byte_cost = self.v_byte_cost.unwrap_or(DEFAULT_BYTE_COST);
+        let v_byte_factor_key = self.v_byte_factor_key.unwrap_or(DEFAULT_BYTE_COST_FACTOR_KEY);
+        let v_byte_factor_data = self.v_byte_factor_data.unwrap_or(DEFAULT_BYTE_COST_FACTOR_DATA);
+
+        // Every output is stored alongside its output ID as key and the message ID, milestone index and milestone
+        // timestamp of its creation as data.
+        let v_byte_offset = (OutputId::LENGTH as u64)
+            .checked_mul(v_byte_factor_key)
+            .and_then(|key| {
+                ((MessageId::LENGTH + core::mem::size_of::<MilestoneIndex>() + core::mem::size_of::<u32>()) as u64)
+                    .checked_mul(v_byte_factor_data)
+                    .and_then(|data| key.checked_add(data))
+            })
+            .ok_or(Error::InvalidByteCostConfig)?;
+
+        let config = ByteCostConfig {
+            v_byte_cost,
+            v_byte_factor_key,
+            v_byte_factor_data,
+            v_byte_offset,
+        };
+
+        // No output is larger than a message, so all byte costs can then be computed without overflowing.
+        config
+            .checked_byte_cost(Message::LENGTH_MAX as u64)
+            .ok_or(Error::InvalidByteCostConfig)?;
+
+        Ok(config)
+    }
+}
+
+/// Specifies the parameters of the byte cost computation, used to compute the minimum deposit of an output.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ByteCostConfig {
+    v_byte_cost: u64,
+    v_byte_factor_key: u64,
+    v_byte_factor_data: u64,
+    v_byte_offset: u64,
+}
+
+impl ByteCostConfig {
+    /// Returns a builder to create a [`ByteCostConfig`].
+    pub fn build() -> ByteCostConfigBuilder {
+        ByteCostConfigBuilder::new()
+    }
+
+    /// Returns the byte cost of a [`ByteCostConfig`].
+    pub fn byte_cost(&self) -> u64 {
+        self.v_byte_cost
+    }
+
+    /// Returns the virtual byte weight of key fields of a [`ByteCostConfig`].
+    pub fn key_factor(&self) -> u64 {
+        self.v_byte_factor_key
+    }
+
+    /// Returns the virtual byte weight of data fields of a [`ByteCostConfig`].
+    pub fn data_factor(&self) -> u64 {
+        self.v_byte_factor_data
+    }
+
+    /// Returns the virtual byte offset added to every output of a [`ByteCostConfig`].
+    pub fn offset(&self) -> u64 {
+        self.v_byte_offset
+    }
+
+    /// Computes the byte cost of data of the given length, if it doesn't overflow.
+    pub(crate) fn checked_byte_cost(&self, len: u64) -> Option<u64> {
+        len.checked_mul(self.v_byte_factor_data)
+            .and_then(|v_bytes| v_bytes.checked_add(self.v_byte_offset))
+            .and_then(|v_bytes| v_bytes.checked_mul(self.v_byte_cost))
+    }
+}
+
+impl Default for ByteCostConfig {
+    fn default() -> Self {
+        // Unwrapping is fine since the default parameters don't overflow.
+        ByteCostConfigBuilder::new().finish().unwrap()
+    }
+}
@@ -6,7 +6,7 @@ use crate::address::Address;
 use derive_more::From;
 
 /// Identifies the validated sender of an output.
-#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, From, packable::Packable)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, From, packable::Packable)]
 #[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
 pub struct SenderFeatureBlock(Address);
 
@@ -12,7 +12,7 @@ pub(crate) type TagFeatureBlockLength =
     BoundedU8<{ *TagFeatureBlock::LENGTH_RANGE.start() }, { *TagFeatureBlock::LENGTH_RANGE.end() }>;
 
 /// Makes it possible to tag outputs with an index, so they can be retrieved through an indexer API.
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, packable::Packable)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, packable::Packable)]
 #[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
 #[packable(unpack_error = Error, with = |e| Error::InvalidTagFeatureBlockLength(e.into_prefix().into()))]
 pub struct TagFeatureBlock(
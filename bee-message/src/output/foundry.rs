@@ -9,7 +9,7 @@ use crate::{
             verify_allowed_unlock_conditions, AddressUnlockCondition, UnlockCondition, UnlockConditionFlags,
             UnlockConditions,
         },
-        FoundryId, NativeToken, NativeTokens, OutputAmount,
+        FoundryId, NativeToken, NativeTokens, OutputAmount, TokenId,
     },
     Error,
 };
@@ -224,6 +224,16 @@ impl FoundryOutput {
         FoundryId::new(bytes)
     }
 
+    /// Returns the [`TokenId`] of the native tokens controlled by the [`FoundryOutput`].
+    pub fn token_id(&self) -> TokenId {
+        let mut bytes = [0u8; TokenId::LENGTH];
+
+        bytes[..FoundryId::LENGTH].copy_from_slice(self.id().as_ref());
+        bytes[FoundryId::LENGTH..].copy_from_slice(&self.token_tag);
+
+        TokenId::new(bytes)
+    }
+
     ///
     #[inline(always)]
     pub fn address(&self) -> &Address {
@@ -4,6 +4,7 @@
 mod alias;
 mod alias_id;
 mod basic;
+mod byte_cost;
 mod chain_id;
 mod foundry;
 mod foundry_id;
@@ -23,6 +24,7 @@ pub(crate) use alias::StateMetadataLength;
 pub use alias::{AliasOutput, AliasOutputBuilder};
 pub use alias_id::AliasId;
 pub use basic::{BasicOutput, BasicOutputBuilder};
+pub use byte_cost::{ByteCostConfig, ByteCostConfigBuilder};
 pub use chain_id::ChainId;
 pub use feature_block::{FeatureBlock, FeatureBlocks};
 pub(crate) use feature_block::{MetadataFeatureBlockLength, TagFeatureBlockLength};
@@ -44,7 +46,7 @@ pub use unlock_condition::{UnlockCondition, UnlockConditions};
 use crate::{constant::IOTA_SUPPLY, Error};
 
 use derive_more::From;
-use packable::bounded::BoundedU64;
+use packable::{bounded::BoundedU64, PackableExt};
 
 use core::ops::RangeInclusive;
 
@@ -122,4 +124,11 @@ impl Output {
             Self::Nft(output) => Some(output.native_tokens()),
         }
     }
+
+    /// Returns the byte cost of an `Output`, i.e. the minimum deposit it requires to be stored.
+    ///
+    /// The byte cost saturates at `u64::MAX`, which no output amount can cover.
+    pub fn byte_cost(&self, config: &ByteCostConfig) -> u64 {
+        config.checked_byte_cost(self.packed_len() as u64).unwrap_or(u64::MAX)
+    }
 }
@@ -1,7 +1,30 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::output::OutputId;
+
 impl_id!(NftId, 20, "TODO.");
 
 #[cfg(feature = "serde1")]
 string_serde_impl!(NftId);
+
+impl NftId {
+    /// Creates a null [`NftId`].
+    pub fn null() -> Self {
+        Self::from([0u8; NftId::LENGTH])
+    }
+
+    /// Checks if the [`NftId`] is null.
+    pub fn is_null(&self) -> bool {
+        self.0.iter().all(|&b| b == 0)
+    }
+
+    /// Returns the [`NftId`] itself, or the one derived from the given [`OutputId`] if it is null.
+    pub fn or_from_output_id(self, output_id: OutputId) -> Self {
+        if self.is_null() {
+            Self::from(output_id.hash())
+        } else {
+            self
+        }
+    }
+}
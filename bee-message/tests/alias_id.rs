@@ -0,0 +1,35 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::output::{AliasId, OutputId};
+
+use core::str::FromStr;
+
+const ALIAS_ID: &str = "52fdfc072182654f163f5f0f9a621d729566c74d";
+const OUTPUT_ID: &str = "52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6492a00";
+
+#[test]
+fn null() {
+    assert!(AliasId::null().is_null());
+    assert!(!AliasId::from_str(ALIAS_ID).unwrap().is_null());
+}
+
+#[test]
+fn or_from_output_id_null() {
+    let output_id = OutputId::from_str(OUTPUT_ID).unwrap();
+
+    assert_eq!(
+        AliasId::null().or_from_output_id(output_id),
+        AliasId::new(output_id.hash())
+    );
+}
+
+#[test]
+fn or_from_output_id_not_null() {
+    let alias_id = AliasId::from_str(ALIAS_ID).unwrap();
+
+    assert_eq!(
+        alias_id.or_from_output_id(OutputId::from_str(OUTPUT_ID).unwrap()),
+        alias_id
+    );
+}
@@ -0,0 +1,66 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::{
+    output::{ByteCostConfig, ByteCostConfigBuilder, Output, TreasuryOutput},
+    Error,
+};
+
+#[test]
+fn default() {
+    let config = ByteCostConfig::default();
+
+    assert_eq!(config.byte_cost(), 500);
+    assert_eq!(config.key_factor(), 10);
+    assert_eq!(config.data_factor(), 1);
+    assert_eq!(config.offset(), 380);
+}
+
+#[test]
+fn builder() {
+    let config = ByteCostConfigBuilder::new()
+        .byte_cost(1)
+        .key_factor(2)
+        .data_factor(3)
+        .finish()
+        .unwrap();
+
+    assert_eq!(config.byte_cost(), 1);
+    assert_eq!(config.key_factor(), 2);
+    assert_eq!(config.data_factor(), 3);
+    assert_eq!(config.offset(), 34 * 2 + 40 * 3);
+}
+
+#[test]
+fn builder_overflowing_offset() {
+    assert!(matches!(
+        ByteCostConfigBuilder::new().key_factor(u64::MAX).finish(),
+        Err(Error::InvalidByteCostConfig)
+    ));
+    assert!(matches!(
+        ByteCostConfigBuilder::new().data_factor(u64::MAX).finish(),
+        Err(Error::InvalidByteCostConfig)
+    ));
+}
+
+#[test]
+fn builder_overflowing_byte_cost() {
+    assert!(matches!(
+        ByteCostConfigBuilder::new().byte_cost(u64::MAX).finish(),
+        Err(Error::InvalidByteCostConfig)
+    ));
+    assert!(matches!(
+        ByteCostConfigBuilder::new()
+            .byte_cost(u64::MAX / 1_000_000)
+            .data_factor(1_000)
+            .finish(),
+        Err(Error::InvalidByteCostConfig)
+    ));
+}
+
+#[test]
+fn output_byte_cost() {
+    let output = Output::from(TreasuryOutput::new(1_000).unwrap());
+
+    assert_eq!(output.byte_cost(&ByteCostConfig::default()), 500 * (9 + 380));
+}
@@ -0,0 +1,29 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::output::{NftId, OutputId};
+
+use core::str::FromStr;
+
+const NFT_ID: &str = "52fdfc072182654f163f5f0f9a621d729566c74d";
+const OUTPUT_ID: &str = "52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6492a00";
+
+#[test]
+fn null() {
+    assert!(NftId::null().is_null());
+    assert!(!NftId::from_str(NFT_ID).unwrap().is_null());
+}
+
+#[test]
+fn or_from_output_id_null() {
+    let output_id = OutputId::from_str(OUTPUT_ID).unwrap();
+
+    assert_eq!(NftId::null().or_from_output_id(output_id), NftId::new(output_id.hash()));
+}
+
+#[test]
+fn or_from_output_id_not_null() {
+    let nft_id = NftId::from_str(NFT_ID).unwrap();
+
+    assert_eq!(nft_id.or_from_output_id(OutputId::from_str(OUTPUT_ID).unwrap()), nft_id);
+}
@@ -20,6 +20,8 @@ use crate::plugins::dashboard::config::{DashboardConfig, DashboardConfigBuilder}
 use bee_autopeering::config::{AutopeeringConfig, AutopeeringConfigTomlBuilder};
 use bee_gossip::{NetworkConfig, NetworkConfigBuilder};
 use bee_ledger::workers::{
+    consensus::config::{ConsensusConfig, ConsensusConfigBuilder},
+    indexation::config::{IndexationConfig, IndexationConfigBuilder},
     pruning::config::{PruningConfig, PruningConfigBuilder},
     snapshot::config::{SnapshotConfig, SnapshotConfigBuilder},
 };
@@ -54,6 +56,8 @@ pub struct NodeConfig<S: NodeStorageBackend> {
     pub(crate) rest_api_config: RestApiConfig,
     pub(crate) snapshot_config: SnapshotConfig,
     pub(crate) pruning_config: PruningConfig,
+    pub(crate) consensus_config: ConsensusConfig,
+    pub(crate) indexation_config: IndexationConfig,
     pub(crate) storage_config: S::Config,
     pub(crate) tangle_config: TangleConfig,
     pub(crate) mqtt_config: MqttConfig,
@@ -108,6 +112,10 @@ pub struct NodeConfigBuilder<S: NodeStorageBackend> {
     pub(crate) snapshot_builder: Option<SnapshotConfigBuilder>,
     #[serde(rename = "pruning")]
     pub(crate) pruning_builder: Option<PruningConfigBuilder>,
+    #[serde(rename = "consensus")]
+    pub(crate) consensus_builder: Option<ConsensusConfigBuilder>,
+    #[serde(rename = "indexation")]
+    pub(crate) indexation_builder: Option<IndexationConfigBuilder>,
     #[serde(rename = "storage")]
     pub(crate) storage_builder: Option<S::ConfigBuilder>,
     #[serde(rename = "tangle")]
@@ -182,6 +190,12 @@ impl<S: NodeStorageBackend> NodeConfigBuilder<S> {
                 rest_api_config: self.rest_api_builder.unwrap_or_default().finish(),
                 snapshot_config: self.snapshot_builder.unwrap_or_default().finish(),
                 pruning_config: self.pruning_builder.unwrap_or_default().finish(),
+                consensus_config: self
+                    .consensus_builder
+                    .unwrap_or_default()
+                    .finish()
+                    .expect("faulty consensus configuration"),
+                indexation_config: self.indexation_builder.unwrap_or_default().finish(),
                 storage_config: self.storage_builder.unwrap_or_default().into(),
                 tangle_config: self.tangle_builder.unwrap_or_default().finish(),
                 mqtt_config: self.mqtt_builder.unwrap_or_default().finish(),
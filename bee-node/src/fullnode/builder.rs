@@ -274,8 +274,17 @@ fn initialize_ledger<S: NodeStorageBackend>(builder: FullNodeBuilder<S>) -> Full
     let network_id = config.network_spec().id();
     let snapshot_cfg = config.snapshot_config.clone();
     let pruning_cfg = config.pruning_config.clone();
+    let consensus_cfg = config.consensus_config.clone();
+    let indexation_cfg = config.indexation_config.clone();
 
-    bee_ledger::workers::init::<FullNode<S>>(builder, network_id, snapshot_cfg, pruning_cfg)
+    bee_ledger::workers::init::<FullNode<S>>(
+        builder,
+        network_id,
+        snapshot_cfg,
+        pruning_cfg,
+        consensus_cfg,
+        indexation_cfg,
+    )
 }
 
 /// Initializes the protocol.
@@ -391,9 +400,17 @@ async fn initialize_api<S: NodeStorageBackend>(builder: FullNodeBuilder<S>) -> F
     let network_id = (network_name, network_id);
     let rest_api_cfg = config.rest_api_config.clone();
     let protocol_cfg = config.protocol_config.clone();
+    let byte_cost_cfg = *config.consensus_config.byte_cost();
 
-    let builder =
-        bee_rest_api::endpoints::init::<FullNode<S>>(rest_api_cfg, protocol_cfg, network_id, hrp, builder).await;
+    let builder = bee_rest_api::endpoints::init::<FullNode<S>>(
+        rest_api_cfg,
+        protocol_cfg,
+        byte_cost_cfg,
+        network_id,
+        hrp,
+        builder,
+    )
+    .await;
 
     builder
 }
@@ -10,7 +10,10 @@ use crate::plugins::dashboard::config::DashboardConfig;
 
 use bee_autopeering::config::AutopeeringConfig;
 use bee_gossip::NetworkConfig;
-use bee_ledger::workers::{pruning::config::PruningConfig, snapshot::config::SnapshotConfig};
+use bee_ledger::workers::{
+    consensus::config::ConsensusConfig, indexation::config::IndexationConfig, pruning::config::PruningConfig,
+    snapshot::config::SnapshotConfig,
+};
 use bee_protocol::workers::config::ProtocolConfig;
 use bee_rest_api::endpoints::config::RestApiConfig;
 use bee_tangle::config::TangleConfig;
@@ -39,6 +42,10 @@ pub struct FullNodeConfig<S: NodeStorageBackend> {
     pub snapshot_config: SnapshotConfig,
     /// Pruning.
     pub pruning_config: PruningConfig,
+    /// Consensus.
+    pub consensus_config: ConsensusConfig,
+    /// Indexation.
+    pub indexation_config: IndexationConfig,
     /// Storage layer.
     pub storage_config: S::Config,
     /// Tangle.
@@ -78,6 +85,8 @@ impl<S: NodeStorageBackend> FullNodeConfig<S> {
             rest_api_config: node_cfg.rest_api_config,
             snapshot_config: node_cfg.snapshot_config,
             pruning_config: node_cfg.pruning_config,
+            consensus_config: node_cfg.consensus_config,
+            indexation_config: node_cfg.indexation_config,
             storage_config: node_cfg.storage_config,
             tangle_config: node_cfg.tangle_config,
             mqtt_config: node_cfg.mqtt_config,
@@ -100,6 +109,8 @@ impl<S: NodeStorageBackend> Clone for FullNodeConfig<S> {
             rest_api_config: self.rest_api_config.clone(),
             snapshot_config: self.snapshot_config.clone(),
             pruning_config: self.pruning_config.clone(),
+            consensus_config: self.consensus_config.clone(),
+            indexation_config: self.indexation_config.clone(),
             storage_config: self.storage_config.clone(),
             tangle_config: self.tangle_config.clone(),
             mqtt_config: self.mqtt_config.clone(),
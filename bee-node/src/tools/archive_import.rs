@@ -0,0 +1,102 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::workers::{error::Error as LedgerError, pruning::archive::import_archive};
+use bee_message::milestone::MilestoneIndex;
+use bee_storage::backend::StorageBackend;
+#[cfg(feature = "rocksdb")]
+use bee_storage_rocksdb::{config::RocksDbConfigBuilder, error::Error as RocksdbBackendError};
+#[cfg(feature = "sled")]
+use bee_storage_sled::{config::SledConfigBuilder, storage::Error as SledBackendError};
+
+use structopt::StructOpt;
+use thiserror::Error;
+
+use std::path::Path;
+
+#[derive(Debug, Error)]
+pub enum ArchiveImportError {
+    #[cfg(feature = "rocksdb")]
+    #[error("Rocksdb storage backend error: {0}")]
+    RocksdbBackend(#[from] RocksdbBackendError),
+    #[cfg(feature = "sled")]
+    #[error("Sled storage backend error: {0}")]
+    SledBackend(#[from] SledBackendError),
+    #[error("Ledger error: {0}")]
+    Ledger(#[from] LedgerError),
+    #[error("Invalid milestone range: {0} > {1}")]
+    InvalidRange(u32, u32),
+    #[error("Unsupported storage backend: {0}")]
+    UnsupportedBackend(String),
+}
+
+/// The node using the database must be stopped.
+#[derive(Clone, Debug, StructOpt)]
+pub struct ArchiveImportTool {
+    /// Path of the database.
+    database_path: String,
+    /// Path of the archive directory.
+    archive_path: String,
+    /// Index of the first milestone to import.
+    start_index: u32,
+    /// Index of the last milestone to import.
+    end_index: u32,
+    /// Storage backend of the database, either "rocksdb" or "sled".
+    #[structopt(long, default_value = "rocksdb")]
+    backend: String,
+}
+
+fn exec_inner<B: bee_ledger::workers::StorageBackend>(
+    tool: &ArchiveImportTool,
+    storage: &B,
+) -> Result<(), ArchiveImportError> {
+    let imported = import_archive(
+        storage,
+        Path::new(&tool.archive_path),
+        MilestoneIndex(tool.start_index),
+        MilestoneIndex(tool.end_index),
+    )?;
+
+    println!(
+        "Imported {} archived milestones from {} to {} into {}.",
+        imported, tool.start_index, tool.end_index, tool.database_path
+    );
+
+    Ok(())
+}
+
+pub fn exec(tool: &ArchiveImportTool) -> Result<(), ArchiveImportError> {
+    if tool.start_index > tool.end_index {
+        return Err(ArchiveImportError::InvalidRange(tool.start_index, tool.end_index));
+    }
+
+    match tool.backend.as_str() {
+        #[cfg(feature = "rocksdb")]
+        "rocksdb" => {
+            let storage = bee_storage_rocksdb::storage::Storage::start(
+                RocksDbConfigBuilder::default()
+                    .with_path(tool.database_path.clone())
+                    .finish(),
+            )?;
+            let res = exec_inner(tool, &storage);
+
+            storage.shutdown()?;
+
+            res
+        }
+        #[cfg(feature = "sled")]
+        "sled" => {
+            let storage = bee_storage_sled::storage::Storage::start(
+                SledConfigBuilder::default()
+                    .with_path(tool.database_path.clone())
+                    .finish(),
+            )?;
+            let res = exec_inner(tool, &storage);
+
+            storage.shutdown()?;
+
+            res
+        }
+        backend => Err(ArchiveImportError::UnsupportedBackend(backend.to_owned())),
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::{
+    types::{output_addresses, AddressRole, CreatedOutput},
+    workers::{error::Error as LedgerError, snapshot::unspent_outputs_at},
+};
+use bee_message::{milestone::MilestoneIndex, output::OutputId};
+use bee_rest_api::types::dtos::{AddressDto, NativeTokenDto, OutputDto};
+use bee_storage::{access::Fetch, backend::StorageBackend};
+#[cfg(feature = "rocksdb")]
+use bee_storage_rocksdb::{config::RocksDbConfigBuilder, error::Error as RocksdbBackendError};
+#[cfg(feature = "sled")]
+use bee_storage_sled::{config::SledConfigBuilder, storage::Error as SledBackendError};
+
+use serde::Serialize;
+use structopt::StructOpt;
+use thiserror::Error;
+
+use std::{
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Error)]
+pub enum LedgerExportError {
+    #[cfg(feature = "rocksdb")]
+    #[error("Rocksdb storage backend error: {0}")]
+    RocksdbBackend(#[from] RocksdbBackendError),
+    #[cfg(feature = "sled")]
+    #[error("Sled storage backend error: {0}")]
+    SledBackend(#[from] SledBackendError),
+    #[error("Ledger error: {0}")]
+    Ledger(#[from] LedgerError),
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("Missing output {0}")]
+    MissingOutput(OutputId),
+    #[error("Unsupported storage backend: {0}")]
+    UnsupportedBackend(String),
+    #[error("Unsupported format: {0}")]
+    UnsupportedFormat(String),
+}
+
+/// The database is only read from, the node using it must be stopped.
+#[derive(Clone, Debug, StructOpt)]
+pub struct LedgerExportTool {
+    /// Path of the database.
+    database_path: String,
+    /// Index of the confirmed milestone at which the unspent outputs are exported.
+    index: u32,
+    /// Path of the export file.
+    export_path: String,
+    /// Storage backend of the database, either "rocksdb" or "sled".
+    #[structopt(long, default_value = "rocksdb")]
+    backend: String,
+    /// Format of the export file, either "jsonl" or "csv".
+    #[structopt(long, default_value = "jsonl")]
+    format: String,
+}
+
+#[derive(Serialize)]
+struct ExportedOutput {
+    #[serde(rename = "outputId")]
+    output_id: String,
+    #[serde(rename = "messageId")]
+    message_id: String,
+    #[serde(rename = "milestoneIndexBooked")]
+    milestone_index_booked: u32,
+    #[serde(rename = "milestoneTimestampBooked")]
+    milestone_timestamp_booked: u32,
+    owner: Option<AddressDto>,
+    amount: u64,
+    #[serde(rename = "nativeTokens")]
+    native_tokens: Vec<NativeTokenDto>,
+    output: OutputDto,
+}
+
+impl ExportedOutput {
+    fn new(output_id: &OutputId, created_output: &CreatedOutput) -> Self {
+        let output = created_output.inner();
+
+        Self {
+            output_id: output_id.to_string(),
+            message_id: created_output.message_id().to_string(),
+            milestone_index_booked: *created_output.milestone_index(),
+            milestone_timestamp_booked: created_output.milestone_timestamp(),
+            owner: output_addresses(output)
+                .iter()
+                .find(|(_, role)| matches!(role, AddressRole::Address | AddressRole::StateController))
+                .map(|(address, _)| address.into()),
+            amount: output.amount(),
+            native_tokens: output
+                .native_tokens()
+                .unwrap_or_default()
+                .iter()
+                .map(Into::into)
+                .collect(),
+            output: output.into(),
+        }
+    }
+}
+
+/// Flat version of an `ExportedOutput` with its nested DTOs encoded as JSON, as CSV doesn't support nesting.
+#[derive(Serialize)]
+struct CsvExportedOutput {
+    #[serde(rename = "outputId")]
+    output_id: String,
+    #[serde(rename = "messageId")]
+    message_id: String,
+    #[serde(rename = "milestoneIndexBooked")]
+    milestone_index_booked: u32,
+    #[serde(rename = "milestoneTimestampBooked")]
+    milestone_timestamp_booked: u32,
+    owner: String,
+    amount: u64,
+    #[serde(rename = "nativeTokens")]
+    native_tokens: String,
+    output: String,
+}
+
+impl TryFrom<ExportedOutput> for CsvExportedOutput {
+    type Error = serde_json::Error;
+
+    fn try_from(value: ExportedOutput) -> Result<Self, Self::Error> {
+        Ok(Self {
+            output_id: value.output_id,
+            message_id: value.message_id,
+            milestone_index_booked: value.milestone_index_booked,
+            milestone_timestamp_booked: value.milestone_timestamp_booked,
+            owner: match value.owner {
+                Some(owner) => serde_json::to_string(&owner)?,
+                None => String::new(),
+            },
+            amount: value.amount,
+            native_tokens: serde_json::to_string(&value.native_tokens)?,
+            output: serde_json::to_string(&value.output)?,
+        })
+    }
+}
+
+enum ExportWriter {
+    Jsonl(BufWriter<File>),
+    Csv(Box<csv::Writer<File>>),
+}
+
+impl ExportWriter {
+    fn new(path: &Path, format: &str) -> Result<Self, LedgerExportError> {
+        match format {
+            "jsonl" => Ok(Self::Jsonl(BufWriter::new(File::create(path)?))),
+            "csv" => Ok(Self::Csv(Box::new(csv::Writer::from_path(path)?))),
+            format => Err(LedgerExportError::UnsupportedFormat(format.to_owned())),
+        }
+    }
+
+    fn write(&mut self, output: ExportedOutput) -> Result<(), LedgerExportError> {
+        match self {
+            Self::Jsonl(writer) => {
+                serde_json::to_writer(&mut *writer, &output)?;
+                writer.write_all(b"\n")?;
+            }
+            Self::Csv(writer) => writer.serialize(CsvExportedOutput::try_from(output)?)?,
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), LedgerExportError> {
+        match self {
+            Self::Jsonl(mut writer) => writer.flush()?,
+            Self::Csv(mut writer) => writer.flush()?,
+        }
+
+        Ok(())
+    }
+}
+
+fn exec_inner<B: bee_ledger::workers::StorageBackend>(
+    tool: &LedgerExportTool,
+    storage: &B,
+) -> Result<(), LedgerExportError> {
+    let export_path = Path::new(&tool.export_path);
+    let mut file_name = export_path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".tmp");
+    let temporary_path: PathBuf = export_path.with_file_name(file_name);
+
+    let output_ids = unspent_outputs_at(storage, MilestoneIndex(tool.index))?;
+    let mut writer = ExportWriter::new(&temporary_path, &tool.format)?;
+
+    for output_id in &output_ids {
+        let created_output = Fetch::<OutputId, CreatedOutput>::fetch(storage, output_id)
+            .map_err(|e| LedgerError::Storage(Box::new(e)))?
+            .ok_or(LedgerExportError::MissingOutput(*output_id))?;
+
+        writer.write(ExportedOutput::new(output_id, &created_output))?;
+    }
+
+    writer.finish()?;
+    fs::rename(&temporary_path, export_path)?;
+
+    println!(
+        "Exported {} unspent outputs at index {} to {}.",
+        output_ids.len(),
+        tool.index,
+        tool.export_path
+    );
+
+    Ok(())
+}
+
+pub fn exec(tool: &LedgerExportTool) -> Result<(), LedgerExportError> {
+    match tool.backend.as_str() {
+        #[cfg(feature = "rocksdb")]
+        "rocksdb" => {
+            let storage = bee_storage_rocksdb::storage::Storage::start(
+                RocksDbConfigBuilder::default()
+                    .with_path(tool.database_path.clone())
+                    .finish(),
+            )?;
+            let res = exec_inner(tool, &storage);
+
+            storage.shutdown()?;
+
+            res
+        }
+        #[cfg(feature = "sled")]
+        "sled" => {
+            let storage = bee_storage_sled::storage::Storage::start(
+                SledConfigBuilder::default()
+                    .with_path(tool.database_path.clone())
+                    .finish(),
+            )?;
+            let res = exec_inner(tool, &storage);
+
+            storage.shutdown()?;
+
+            res
+        }
+        backend => Err(LedgerExportError::UnsupportedBackend(backend.to_owned())),
+    }
+}
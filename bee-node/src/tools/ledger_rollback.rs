@@ -0,0 +1,97 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::workers::{
+    error::Error as LedgerError, indexation::config::IndexationConfig, rollback::rollback_ledger,
+};
+use bee_message::milestone::MilestoneIndex;
+use bee_storage::backend::StorageBackend;
+#[cfg(feature = "rocksdb")]
+use bee_storage_rocksdb::{config::RocksDbConfigBuilder, error::Error as RocksdbBackendError};
+#[cfg(feature = "sled")]
+use bee_storage_sled::{config::SledConfigBuilder, storage::Error as SledBackendError};
+
+use structopt::StructOpt;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum LedgerRollbackError {
+    #[cfg(feature = "rocksdb")]
+    #[error("Rocksdb storage backend error: {0}")]
+    RocksdbBackend(#[from] RocksdbBackendError),
+    #[cfg(feature = "sled")]
+    #[error("Sled storage backend error: {0}")]
+    SledBackend(#[from] SledBackendError),
+    #[error("Ledger error: {0}")]
+    Ledger(#[from] LedgerError),
+    #[error("Unsupported storage backend: {0}")]
+    UnsupportedBackend(String),
+}
+
+/// The node using the database must be stopped.
+#[derive(Clone, Debug, StructOpt)]
+pub struct LedgerRollbackTool {
+    /// Path of the database.
+    database_path: String,
+    /// Index of the confirmed milestone to roll the ledger back to.
+    index: u32,
+    /// Storage backend of the database, either "rocksdb" or "sled".
+    #[structopt(long, default_value = "rocksdb")]
+    backend: String,
+    /// Whether outputs are indexed by tag, must match the indexation configuration of the node.
+    #[structopt(long)]
+    tag: bool,
+    /// Whether outputs are indexed by sender, must match the indexation configuration of the node.
+    #[structopt(long)]
+    sender: bool,
+}
+
+fn exec_inner<B: bee_ledger::workers::StorageBackend>(
+    tool: &LedgerRollbackTool,
+    storage: &B,
+) -> Result<(), LedgerRollbackError> {
+    let rolled_back = rollback_ledger(
+        storage,
+        MilestoneIndex(tool.index),
+        &IndexationConfig::build().tag(tool.tag).sender(tool.sender).finish(),
+    )?;
+
+    println!(
+        "Rolled back {} milestones, the ledger is now at index {}.",
+        rolled_back, tool.index
+    );
+
+    Ok(())
+}
+
+pub fn exec(tool: &LedgerRollbackTool) -> Result<(), LedgerRollbackError> {
+    match tool.backend.as_str() {
+        #[cfg(feature = "rocksdb")]
+        "rocksdb" => {
+            let storage = bee_storage_rocksdb::storage::Storage::start(
+                RocksDbConfigBuilder::default()
+                    .with_path(tool.database_path.clone())
+                    .finish(),
+            )?;
+            let res = exec_inner(tool, &storage);
+
+            storage.shutdown()?;
+
+            res
+        }
+        #[cfg(feature = "sled")]
+        "sled" => {
+            let storage = bee_storage_sled::storage::Storage::start(
+                SledConfigBuilder::default()
+                    .with_path(tool.database_path.clone())
+                    .finish(),
+            )?;
+            let res = exec_inner(tool, &storage);
+
+            storage.shutdown()?;
+
+            res
+        }
+        backend => Err(LedgerRollbackError::UnsupportedBackend(backend.to_owned())),
+    }
+}
@@ -1,13 +1,21 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod archive_import;
 mod ed25519;
+mod ledger_export;
+mod ledger_rollback;
 mod password;
 #[cfg(feature = "rocksdb")]
 mod rocksdb;
 #[cfg(feature = "sled")]
 mod sled;
+mod snapshot_generate;
 mod snapshot_info;
+mod snapshot_merge;
+mod snapshot_verify;
+mod storage_check;
+mod storage_restore;
 
 use structopt::StructOpt;
 use thiserror::Error;
@@ -15,24 +23,46 @@ use thiserror::Error;
 #[non_exhaustive]
 #[derive(Clone, Debug, StructOpt)]
 pub enum Tool {
+    /// Imports a range of archived pruned milestones into a stopped node database.
+    ArchiveImport(archive_import::ArchiveImportTool),
     /// Generates Ed25519 public/private keys and addresses.
     Ed25519(ed25519::Ed25519Tool),
+    /// Exports the unspent outputs of a stopped node database at a given milestone index.
+    LedgerExport(ledger_export::LedgerExportTool),
+    /// Rolls the ledger of a stopped node database back to a given milestone index.
+    LedgerRollback(ledger_rollback::LedgerRollbackTool),
     /// Rocksdb database analyser.
     #[cfg(feature = "rocksdb")]
     Rocksdb(rocksdb::RocksdbTool),
     /// Sled database analyser.
     #[cfg(feature = "sled")]
     Sled(sled::SledTool),
+    /// Generates a full snapshot file at a given milestone index from a stopped node database.
+    SnapshotGenerate(snapshot_generate::SnapshotGenerateTool),
     /// Outputs information about a snapshot file.
     SnapshotInfo(snapshot_info::SnapshotInfoTool),
+    /// Merges a delta snapshot file into its full snapshot file.
+    SnapshotMerge(snapshot_merge::SnapshotMergeTool),
+    /// Verifies the integrity of a snapshot file.
+    SnapshotVerify(snapshot_verify::SnapshotVerifyTool),
+    /// Checks the integrity of a stopped node database and optionally repairs its secondary indexes.
+    StorageCheck(storage_check::StorageCheckTool),
+    /// Restores a database from a backup after validating its version and health.
+    StorageRestore(storage_restore::StorageRestoreTool),
     /// Generates password salt and hash.
     Password(password::PasswordTool),
 }
 
 #[derive(Debug, Error)]
 pub enum ToolError {
+    #[error("{0}")]
+    ArchiveImport(#[from] archive_import::ArchiveImportError),
     #[error("{0}")]
     Ed25519(#[from] ed25519::Ed25519Error),
+    #[error("{0}")]
+    LedgerExport(#[from] ledger_export::LedgerExportError),
+    #[error("{0}")]
+    LedgerRollback(#[from] ledger_rollback::LedgerRollbackError),
     #[cfg(feature = "rocksdb")]
     #[error("{0}")]
     Rocksdb(#[from] rocksdb::RocksdbError),
@@ -40,19 +70,37 @@ pub enum ToolError {
     #[error("{0}")]
     Sled(#[from] sled::SledError),
     #[error("{0}")]
+    SnapshotGenerate(#[from] snapshot_generate::SnapshotGenerateError),
+    #[error("{0}")]
     SnapshotInfo(#[from] snapshot_info::SnapshotInfoError),
     #[error("{0}")]
+    SnapshotMerge(#[from] snapshot_merge::SnapshotMergeError),
+    #[error("{0}")]
+    SnapshotVerify(#[from] snapshot_verify::SnapshotVerifyError),
+    #[error("{0}")]
+    StorageCheck(#[from] storage_check::StorageCheckError),
+    #[error("{0}")]
+    StorageRestore(#[from] storage_restore::StorageRestoreError),
+    #[error("{0}")]
     Password(#[from] password::PasswordError),
 }
 
 pub fn exec(tool: &Tool) -> Result<(), ToolError> {
     match tool {
+        Tool::ArchiveImport(tool) => archive_import::exec(tool)?,
         Tool::Ed25519(tool) => ed25519::exec(tool)?,
+        Tool::LedgerExport(tool) => ledger_export::exec(tool)?,
+        Tool::LedgerRollback(tool) => ledger_rollback::exec(tool)?,
         #[cfg(feature = "rocksdb")]
         Tool::Rocksdb(tool) => rocksdb::exec(tool)?,
         #[cfg(feature = "sled")]
         Tool::Sled(tool) => sled::exec(tool)?,
+        Tool::SnapshotGenerate(tool) => snapshot_generate::exec(tool)?,
         Tool::SnapshotInfo(tool) => snapshot_info::exec(tool)?,
+        Tool::SnapshotMerge(tool) => snapshot_merge::exec(tool)?,
+        Tool::SnapshotVerify(tool) => snapshot_verify::exec(tool)?,
+        Tool::StorageCheck(tool) => storage_check::exec(tool)?,
+        Tool::StorageRestore(tool) => storage_restore::exec(tool)?,
         Tool::Password(tool) => password::exec(tool)?,
     }
 
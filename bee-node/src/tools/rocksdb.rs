@@ -2,10 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     milestone::{Milestone, MilestoneIndex},
     output::OutputId,
     Message, MessageId,
@@ -171,15 +172,20 @@ fn exec_inner(tool: &RocksdbTool, storage: &Storage) -> Result<(), RocksdbError>
                 }
             }
         },
-        CF_ED25519_ADDRESS_TO_OUTPUT_ID => match &tool.command {
+        CF_ADDRESS_TO_OUTPUT_ID => match &tool.command {
             RocksdbCommand::Fetch { key } => {
-                let key = Ed25519Address::from_str(key).map_err(|_| RocksdbError::InvalidKey(key.clone()))?;
-                let value = Fetch::<Ed25519Address, Vec<OutputId>>::fetch(storage, &key)?;
+                let address =
+                    Address::from(Ed25519Address::from_str(key).map_err(|_| RocksdbError::InvalidKey(key.clone()))?);
 
-                println!("Key: {:?}\nValue: {:?}\n", key, value);
+                for role in AddressRole::ALL {
+                    let key = (address, role);
+                    let value = Fetch::<(Address, AddressRole), Vec<OutputId>>::fetch(storage, &key)?;
+
+                    println!("Key: {:?}\nValue: {:?}\n", key, value);
+                }
             }
             RocksdbCommand::Iterator => {
-                let iterator = AsIterator::<(Ed25519Address, OutputId), ()>::iter(storage)?;
+                let iterator = AsIterator::<((Address, AddressRole), OutputId), ()>::iter(storage)?;
 
                 for result in iterator {
                     let (key, value) = result?;
@@ -0,0 +1,105 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::workers::{error::Error as LedgerError, snapshot::generate_full_snapshot};
+use bee_message::milestone::MilestoneIndex;
+use bee_storage::{backend::StorageBackend, system::StorageHealth};
+#[cfg(feature = "rocksdb")]
+use bee_storage_rocksdb::{config::RocksDbConfigBuilder, error::Error as RocksdbBackendError};
+#[cfg(feature = "sled")]
+use bee_storage_sled::{config::SledConfigBuilder, storage::Error as SledBackendError};
+
+use structopt::StructOpt;
+use thiserror::Error;
+
+use std::path::Path;
+
+#[derive(Debug, Error)]
+pub enum SnapshotGenerateError {
+    #[cfg(feature = "rocksdb")]
+    #[error("Rocksdb storage backend error: {0}")]
+    RocksdbBackend(#[from] RocksdbBackendError),
+    #[cfg(feature = "sled")]
+    #[error("Sled storage backend error: {0}")]
+    SledBackend(#[from] SledBackendError),
+    #[error("Ledger error: {0}")]
+    Ledger(#[from] LedgerError),
+    #[error("Unsupported storage backend: {0}")]
+    UnsupportedBackend(String),
+}
+
+/// The database is only read from and may be unhealthy, the node using it must be stopped.
+#[derive(Clone, Debug, StructOpt)]
+pub struct SnapshotGenerateTool {
+    /// Path of the database.
+    database_path: String,
+    /// Milestone index of the generated snapshot.
+    index: u32,
+    /// Path of the generated full snapshot file.
+    snapshot_path: String,
+    /// Storage backend of the database, either "rocksdb" or "sled".
+    #[structopt(long, default_value = "rocksdb")]
+    backend: String,
+    /// Below max depth used to collect the solid entry points.
+    #[structopt(long, default_value = "15")]
+    below_max_depth: u32,
+}
+
+fn warn_unhealthy(health: Option<StorageHealth>) {
+    if health != Some(StorageHealth::Healthy) {
+        println!(
+            "Warning: the storage health is {:?}, the generated snapshot may be inconsistent.",
+            health
+        );
+    }
+}
+
+fn exec_inner<B: bee_ledger::workers::StorageBackend>(
+    tool: &SnapshotGenerateTool,
+    storage: &B,
+) -> Result<(), SnapshotGenerateError> {
+    generate_full_snapshot(
+        storage,
+        Path::new(&tool.snapshot_path),
+        tool.below_max_depth,
+        MilestoneIndex(tool.index),
+    )?;
+
+    println!(
+        "Generated full snapshot file {} at index {}.",
+        tool.snapshot_path, tool.index
+    );
+
+    Ok(())
+}
+
+pub fn exec(tool: &SnapshotGenerateTool) -> Result<(), SnapshotGenerateError> {
+    // The database is opened without checking nor updating its version and health, so that snapshots can also be
+    // generated from databases that were not closed properly.
+    match tool.backend.as_str() {
+        #[cfg(feature = "rocksdb")]
+        "rocksdb" => {
+            let storage = bee_storage_rocksdb::storage::Storage::open_read_only(
+                RocksDbConfigBuilder::default()
+                    .with_path(tool.database_path.clone())
+                    .finish(),
+            )?;
+
+            warn_unhealthy(storage.get_health()?);
+            exec_inner(tool, &storage)
+        }
+        #[cfg(feature = "sled")]
+        "sled" => {
+            // Sled has no read-only mode but nothing is written by the generation.
+            let storage = bee_storage_sled::storage::Storage::new(
+                SledConfigBuilder::default()
+                    .with_path(tool.database_path.clone())
+                    .finish(),
+            )?;
+
+            warn_unhealthy(storage.get_health()?);
+            exec_inner(tool, &storage)
+        }
+        backend => Err(SnapshotGenerateError::UnsupportedBackend(backend.to_owned())),
+    }
+}
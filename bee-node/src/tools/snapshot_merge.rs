@@ -0,0 +1,455 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::types::{
+    snapshot::{DeltaSnapshotHeader, FullSnapshotHeader, MilestoneDiff, SnapshotHeader, SnapshotKind},
+    CreatedOutput,
+};
+use bee_message::{
+    milestone::MilestoneIndex,
+    output::{Output, OutputId},
+    payload::{milestone::MilestoneId, Payload},
+    MessageId,
+};
+use bee_tangle::solid_entry_point::SolidEntryPoint;
+
+use packable::{
+    packer::{IoPacker, Packer},
+    unpacker::{IoUnpacker, Unpacker},
+    Packable, PackableExt,
+};
+use structopt::StructOpt;
+use thiserror::Error;
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File, OpenOptions},
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Error)]
+pub enum SnapshotMergeError {
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    #[error("unpacking snapshot failed")]
+    SnapshotUnpack,
+    #[error("unexpected snapshot kind: expected {0:?}, read {1:?}")]
+    UnexpectedSnapshotKind(SnapshotKind, SnapshotKind),
+    #[error("network id mismatch between full and delta snapshots: {0} != {1}")]
+    NetworkIdMismatch(u64, u64),
+    #[error("delta snapshot ledger index {0} doesn't match full snapshot sep index {1}")]
+    DeltaSnapshotMismatch(MilestoneIndex, MilestoneIndex),
+    #[error("unexpected milestone diff index: expected {0}, read {1}")]
+    UnexpectedMilestoneDiffIndex(MilestoneIndex, MilestoneIndex),
+    #[error("invalid receipt in milestone diff {0}")]
+    InvalidReceipt(MilestoneIndex),
+    #[error("{0} outputs consumed by the delta snapshot are missing from the full snapshot")]
+    MissingConsumedOutputs(usize),
+}
+
+#[derive(Clone, Debug, StructOpt)]
+pub struct SnapshotMergeTool {
+    /// Path of the full snapshot file.
+    full_path: String,
+    /// Path of the delta snapshot file based on the full snapshot file.
+    delta_path: String,
+    /// Path of the merged full snapshot file.
+    output_path: String,
+}
+
+/// Ledger changes of the delta snapshot milestone diffs that are not yet part of the full snapshot ledger state.
+#[derive(Default)]
+struct DeltaChanges {
+    created_outputs: HashMap<OutputId, CreatedOutput>,
+    consumed_outputs: HashSet<OutputId>,
+    treasury_output: Option<(MilestoneId, u64)>,
+}
+
+impl DeltaChanges {
+    fn apply(&mut self, diff: &MilestoneDiff) -> Result<(), SnapshotMergeError> {
+        // Outputs created and consumed within the milestone are neither part of the full snapshot nor unspent.
+        for output_id in diff.consumed().keys() {
+            if diff.created().contains_key(output_id) {
+                continue;
+            }
+            if self.created_outputs.remove(output_id).is_none() {
+                self.consumed_outputs.insert(*output_id);
+            }
+        }
+        for (output_id, created_output) in diff.created() {
+            if diff.consumed().contains_key(output_id) {
+                continue;
+            }
+            self.created_outputs.insert(*output_id, created_output.clone());
+        }
+        if let Some(receipt) = diff.milestone().essence().receipt() {
+            let index = diff.milestone().essence().index();
+            let amount = match receipt {
+                Payload::Receipt(receipt) => match receipt.transaction() {
+                    Payload::TreasuryTransaction(transaction) => match transaction.output() {
+                        Output::Treasury(output) => output.amount(),
+                        _ => return Err(SnapshotMergeError::InvalidReceipt(index)),
+                    },
+                    _ => return Err(SnapshotMergeError::InvalidReceipt(index)),
+                },
+                _ => return Err(SnapshotMergeError::InvalidReceipt(index)),
+            };
+
+            self.treasury_output = Some((diff.milestone().id(), amount));
+        }
+
+        Ok(())
+    }
+}
+
+fn snapshot_unpacker(path: &Path) -> Result<IoUnpacker<BufReader<File>>, SnapshotMergeError> {
+    Ok(IoUnpacker::new(BufReader::new(
+        OpenOptions::new().read(true).open(path)?,
+    )))
+}
+
+fn snapshot_packer(path: &Path) -> Result<IoPacker<BufWriter<File>>, SnapshotMergeError> {
+    Ok(IoPacker::new(BufWriter::new(
+        OpenOptions::new().write(true).create(true).truncate(true).open(path)?,
+    )))
+}
+
+fn read_header<U: Unpacker<Error = std::io::Error>>(
+    unpacker: &mut U,
+    kind: SnapshotKind,
+) -> Result<SnapshotHeader, SnapshotMergeError> {
+    let header = SnapshotHeader::unpack::<_, true>(unpacker).map_err(|_| SnapshotMergeError::SnapshotUnpack)?;
+
+    if header.kind() != kind {
+        return Err(SnapshotMergeError::UnexpectedSnapshotKind(kind, header.kind()));
+    }
+
+    Ok(header)
+}
+
+fn read_milestone_diff<U: Unpacker<Error = std::io::Error>>(
+    unpacker: &mut U,
+    expected_index: u32,
+) -> Result<MilestoneDiff, SnapshotMergeError> {
+    let diff = MilestoneDiff::unpack::<_, true>(unpacker).map_err(|_| SnapshotMergeError::SnapshotUnpack)?;
+    let index = diff.milestone().essence().index();
+
+    if index != MilestoneIndex(expected_index) {
+        return Err(SnapshotMergeError::UnexpectedMilestoneDiffIndex(
+            MilestoneIndex(expected_index),
+            index,
+        ));
+    }
+
+    Ok(diff)
+}
+
+fn write_output<P: Packer<Error = std::io::Error>>(
+    packer: &mut P,
+    output_id: &OutputId,
+    created_output: &CreatedOutput,
+) -> Result<(), SnapshotMergeError> {
+    created_output.message_id().pack(packer)?;
+    output_id.pack(packer)?;
+    created_output.milestone_index().pack(packer)?;
+    created_output.milestone_timestamp().pack(packer)?;
+    // This length prefix is currently needed for hornet/iota.go and not for bee but still needs to be written.
+    (created_output.inner().packed_len() as u32).pack(packer)?;
+    created_output.inner().pack(packer)?;
+
+    Ok(())
+}
+
+/// Writes the merged full snapshot, streaming the outputs and milestone diffs of the full snapshot file through.
+fn merge(full_path: &Path, delta_path: &Path, path: &Path) -> Result<(), SnapshotMergeError> {
+    let mut full_unpacker = snapshot_unpacker(full_path)?;
+    let full_header = read_header(&mut full_unpacker, SnapshotKind::Full)?;
+    let full_full_header =
+        FullSnapshotHeader::unpack::<_, true>(&mut full_unpacker).map_err(|_| SnapshotMergeError::SnapshotUnpack)?;
+
+    let mut delta_unpacker = snapshot_unpacker(delta_path)?;
+    let delta_header = read_header(&mut delta_unpacker, SnapshotKind::Delta)?;
+    let delta_delta_header =
+        DeltaSnapshotHeader::unpack::<_, true>(&mut delta_unpacker).map_err(|_| SnapshotMergeError::SnapshotUnpack)?;
+
+    if full_header.network_id() != delta_header.network_id() {
+        return Err(SnapshotMergeError::NetworkIdMismatch(
+            full_header.network_id(),
+            delta_header.network_id(),
+        ));
+    }
+    if full_header.sep_index() != delta_header.ledger_index() {
+        return Err(SnapshotMergeError::DeltaSnapshotMismatch(
+            delta_header.ledger_index(),
+            full_header.sep_index(),
+        ));
+    }
+
+    let seps = (0..delta_delta_header.sep_count())
+        .map(|_| {
+            SolidEntryPoint::unpack::<_, true>(&mut delta_unpacker).map_err(|_| SnapshotMergeError::SnapshotUnpack)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Milestone diffs of the delta snapshot up to the full snapshot ledger index are already part of its ledger state.
+    let mut changes = DeltaChanges::default();
+
+    for index in *delta_header.ledger_index() + 1..=*delta_header.sep_index() {
+        let diff = read_milestone_diff(&mut delta_unpacker, index)?;
+
+        if index > *full_header.ledger_index() {
+            changes.apply(&diff)?;
+        }
+    }
+
+    // The ledger index is kept if the delta snapshot ends before it, the milestone diffs of the full snapshot then roll
+    // the ledger back to the new sep index.
+    let sep_index = delta_header.sep_index();
+    let ledger_index = full_header.ledger_index().max(sep_index);
+    let (treasury_output_milestone_id, treasury_output_amount) = changes.treasury_output.unwrap_or((
+        *full_full_header.treasury_output_milestone_id(),
+        full_full_header.treasury_output_amount(),
+    ));
+
+    let header = SnapshotHeader::new(
+        SnapshotKind::Full,
+        delta_header.timestamp(),
+        delta_header.network_id(),
+        sep_index,
+        ledger_index,
+    );
+    let merged_full_header = FullSnapshotHeader::new(
+        seps.len() as u64,
+        full_full_header
+            .output_count()
+            .saturating_sub(changes.consumed_outputs.len() as u64)
+            + changes.created_outputs.len() as u64,
+        *(ledger_index - sep_index) as u64,
+        treasury_output_milestone_id,
+        treasury_output_amount,
+    );
+
+    let mut packer = snapshot_packer(path)?;
+
+    header.pack(&mut packer)?;
+    merged_full_header.pack(&mut packer)?;
+
+    for sep in &seps {
+        sep.pack(&mut packer)?;
+    }
+
+    // The solid entry points of the full snapshot are superseded by the ones of the delta snapshot.
+    for _ in 0..full_full_header.sep_count() {
+        SolidEntryPoint::unpack::<_, true>(&mut full_unpacker).map_err(|_| SnapshotMergeError::SnapshotUnpack)?;
+    }
+
+    let mut consumed_outputs = changes.consumed_outputs.len();
+
+    for _ in 0..full_full_header.output_count() {
+        let message_id =
+            MessageId::unpack::<_, true>(&mut full_unpacker).map_err(|_| SnapshotMergeError::SnapshotUnpack)?;
+        let output_id =
+            OutputId::unpack::<_, true>(&mut full_unpacker).map_err(|_| SnapshotMergeError::SnapshotUnpack)?;
+        let milestone_index =
+            MilestoneIndex::unpack::<_, true>(&mut full_unpacker).map_err(|_| SnapshotMergeError::SnapshotUnpack)?;
+        let milestone_timestamp =
+            u32::unpack::<_, true>(&mut full_unpacker).map_err(|_| SnapshotMergeError::SnapshotUnpack)?;
+        // This length prefix is currently needed for hornet/iota.go and not for bee but still needs to be parsed.
+        let _ = u32::unpack::<_, true>(&mut full_unpacker).map_err(|_| SnapshotMergeError::SnapshotUnpack)?;
+        let output = Output::unpack::<_, true>(&mut full_unpacker).map_err(|_| SnapshotMergeError::SnapshotUnpack)?;
+
+        if changes.consumed_outputs.contains(&output_id) {
+            consumed_outputs -= 1;
+        } else {
+            write_output(
+                &mut packer,
+                &output_id,
+                &CreatedOutput::new(message_id, milestone_index, milestone_timestamp, output),
+            )?;
+        }
+    }
+
+    if consumed_outputs != 0 {
+        return Err(SnapshotMergeError::MissingConsumedOutputs(consumed_outputs));
+    }
+
+    for (output_id, created_output) in &changes.created_outputs {
+        write_output(&mut packer, output_id, created_output)?;
+    }
+
+    // Milestone diffs of the full snapshot are in descending order, only the ones above the new sep index are kept.
+    for index in (*full_header.sep_index() + 1..=*full_header.ledger_index()).rev() {
+        let diff = read_milestone_diff(&mut full_unpacker, index)?;
+
+        if MilestoneIndex(index) > sep_index {
+            diff.pack(&mut packer)?;
+        }
+    }
+
+    packer
+        .into_inner()
+        .into_inner()
+        .map_err(|e| e.into_error())?
+        .sync_all()?;
+
+    println!(
+        "Merged full snapshot file {} with sep index {}, ledger index {}, {} solid entry points, {} outputs and {} milestone diffs.",
+        path.display(),
+        *sep_index,
+        *ledger_index,
+        merged_full_header.sep_count(),
+        merged_full_header.output_count(),
+        merged_full_header.milestone_diff_count()
+    );
+
+    Ok(())
+}
+
+pub fn exec(tool: &SnapshotMergeTool) -> Result<(), SnapshotMergeError> {
+    let path = Path::new(&tool.output_path);
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".tmp");
+    let temporary_path: PathBuf = path.with_file_name(file_name);
+
+    if let Err(e) = merge(Path::new(&tool.full_path), Path::new(&tool.delta_path), &temporary_path) {
+        let _ = fs::remove_file(&temporary_path);
+        return Err(e);
+    }
+
+    fs::rename(&temporary_path, path)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use bee_ledger::types::ConsumedOutput;
+    use bee_message::{
+        address::{Address, Ed25519Address},
+        constant::IOTA_SUPPLY,
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+        parent::Parents,
+        payload::{
+            milestone::{MilestoneEssence, MilestonePayload},
+            transaction::TransactionId,
+        },
+    };
+
+    const NETWORK_ID: u64 = 42;
+
+    fn output_id(transaction: u8) -> OutputId {
+        OutputId::new(TransactionId::new([transaction; 32]), 0).unwrap()
+    }
+
+    fn created_output(index: MilestoneIndex) -> CreatedOutput {
+        CreatedOutput::new(
+            MessageId::null(),
+            index,
+            0,
+            Output::from(
+                BasicOutputBuilder::new(IOTA_SUPPLY)
+                    .unwrap()
+                    .add_unlock_condition(
+                        AddressUnlockCondition::new(Address::from(Ed25519Address::new([0; 32]))).into(),
+                    )
+                    .finish()
+                    .unwrap(),
+            ),
+        )
+    }
+
+    fn milestone_diff(index: MilestoneIndex, created: &[OutputId], consumed: &[OutputId]) -> MilestoneDiff {
+        let milestone = MilestonePayload::new(
+            MilestoneEssence::new(
+                index,
+                0,
+                Parents::new(vec![MessageId::null()]).unwrap(),
+                [0; MilestoneEssence::MERKLE_PROOF_LENGTH],
+                0,
+                0,
+                vec![[0; 32]],
+                None,
+            )
+            .unwrap(),
+            vec![[0; 64]],
+        )
+        .unwrap();
+
+        MilestoneDiff::new(
+            milestone,
+            None,
+            created
+                .iter()
+                .map(|output_id| (*output_id, created_output(index)))
+                .collect(),
+            consumed
+                .iter()
+                .map(|output_id| {
+                    (
+                        *output_id,
+                        (
+                            created_output(index),
+                            ConsumedOutput::new(TransactionId::new([0xff; 32]), index),
+                        ),
+                    )
+                })
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn merge_output_created_and_consumed_within_milestone() {
+        let directory = std::env::temp_dir().join(format!("bee-snapshot-merge-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let (full_path, delta_path, path) = (
+            directory.join("full.bin"),
+            directory.join("delta.bin"),
+            directory.join("merged.bin"),
+        );
+        let (previous, intermediate, current) = (output_id(1), output_id(2), output_id(3));
+
+        let mut packer = snapshot_packer(&full_path).unwrap();
+        SnapshotHeader::new(SnapshotKind::Full, 0, NETWORK_ID, MilestoneIndex(0), MilestoneIndex(0))
+            .pack(&mut packer)
+            .unwrap();
+        FullSnapshotHeader::new(1, 1, 0, MilestoneId::new([0; MilestoneId::LENGTH]), 0)
+            .pack(&mut packer)
+            .unwrap();
+        SolidEntryPoint::null().pack(&mut packer).unwrap();
+        write_output(&mut packer, &previous, &created_output(MilestoneIndex(0))).unwrap();
+        packer.into_inner().into_inner().unwrap();
+
+        // The intermediate output is created and consumed within milestone 1.
+        let mut packer = snapshot_packer(&delta_path).unwrap();
+        SnapshotHeader::new(SnapshotKind::Delta, 0, NETWORK_ID, MilestoneIndex(1), MilestoneIndex(0))
+            .pack(&mut packer)
+            .unwrap();
+        DeltaSnapshotHeader::new(1, 1).pack(&mut packer).unwrap();
+        SolidEntryPoint::null().pack(&mut packer).unwrap();
+        milestone_diff(MilestoneIndex(1), &[intermediate, current], &[previous, intermediate])
+            .pack(&mut packer)
+            .unwrap();
+        packer.into_inner().into_inner().unwrap();
+
+        merge(&full_path, &delta_path, &path).unwrap();
+
+        let mut unpacker = snapshot_unpacker(&path).unwrap();
+        let header = read_header(&mut unpacker, SnapshotKind::Full).unwrap();
+        let full_header = FullSnapshotHeader::unpack::<_, true>(&mut unpacker).unwrap();
+
+        assert_eq!(header.sep_index(), MilestoneIndex(1));
+        assert_eq!(header.ledger_index(), MilestoneIndex(1));
+        assert_eq!(full_header.output_count(), 1);
+        assert_eq!(full_header.milestone_diff_count(), 0);
+
+        SolidEntryPoint::unpack::<_, true>(&mut unpacker).unwrap();
+        MessageId::unpack::<_, true>(&mut unpacker).unwrap();
+        assert_eq!(OutputId::unpack::<_, true>(&mut unpacker).unwrap(), current);
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
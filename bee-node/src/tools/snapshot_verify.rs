@@ -0,0 +1,530 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::types::snapshot::{
+    DeltaSnapshotHeader, FullSnapshotHeader, MilestoneDiff, SnapshotHeader, SnapshotKind,
+};
+use bee_message::{
+    constant::IOTA_SUPPLY,
+    milestone::MilestoneIndex,
+    output::{Output, OutputId},
+    payload::Payload,
+    MessageId,
+};
+use bee_tangle::solid_entry_point::SolidEntryPoint;
+
+use packable::{
+    unpacker::{IoUnpacker, Unpacker},
+    Packable,
+};
+use structopt::StructOpt;
+use thiserror::Error;
+
+use std::{
+    collections::HashMap,
+    fs::{File, OpenOptions},
+    io::{BufReader, Read},
+    path::Path,
+};
+
+#[derive(Debug, Error)]
+pub enum SnapshotVerifyError {
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    #[error("unpacking snapshot failed")]
+    SnapshotUnpack,
+    #[error("unexpected snapshot kind: expected {0:?}, read {1:?}")]
+    UnexpectedSnapshotKind(SnapshotKind, SnapshotKind),
+    #[error("network id mismatch between full and delta snapshots: {0} != {1}")]
+    NetworkIdMismatch(u64, u64),
+    #[error("inconsistency between ledger index {0} and sep index {1}")]
+    LedgerSepIndexesInconsistency(MilestoneIndex, MilestoneIndex),
+    #[error("invalid milestone diffs count: expected {0}, read {1}")]
+    InvalidMilestoneDiffsCount(u64, u64),
+    #[error("delta snapshot ledger index {0} doesn't match full snapshot sep index {1}")]
+    DeltaSnapshotMismatch(MilestoneIndex, MilestoneIndex),
+    #[error("unexpected milestone diff index: expected {0}, read {1}")]
+    UnexpectedMilestoneDiffIndex(MilestoneIndex, MilestoneIndex),
+    #[error("duplicate output {0}")]
+    DuplicateOutput(OutputId),
+    #[error("output {1} of milestone diff {0} is unexpectedly unspent")]
+    UnexpectedUnspentOutput(MilestoneIndex, OutputId),
+    #[error("output {1} of milestone diff {0} is unexpectedly not unspent")]
+    MissingUnspentOutput(MilestoneIndex, OutputId),
+    #[error("invalid receipt in milestone diff {0}")]
+    InvalidReceipt(MilestoneIndex),
+    #[error("treasury mismatch at milestone diff {0}: expected {1}, found {2}")]
+    TreasuryMismatch(MilestoneIndex, u64, u64),
+    #[error("invalid supply at index {0}: {1}")]
+    InvalidSupply(MilestoneIndex, u128),
+    #[error("remaining bytes in file")]
+    RemainingBytes,
+}
+
+#[derive(Clone, Debug, StructOpt)]
+pub struct SnapshotVerifyTool {
+    /// Path of the full or delta snapshot file to verify.
+    path: String,
+    /// Path of the full snapshot file a delta snapshot is verified on top of.
+    #[structopt(long)]
+    full_path: Option<String>,
+}
+
+/// Amounts of the unspent outputs and of the treasury output at a given index.
+struct LedgerState {
+    index: MilestoneIndex,
+    outputs: HashMap<OutputId, u64>,
+    treasury: u64,
+}
+
+impl LedgerState {
+    fn verify_supply(&self) -> Result<(), SnapshotVerifyError> {
+        let supply = self.outputs.values().map(|amount| *amount as u128).sum::<u128>() + self.treasury as u128;
+
+        if supply != IOTA_SUPPLY as u128 {
+            return Err(SnapshotVerifyError::InvalidSupply(self.index, supply));
+        }
+
+        Ok(())
+    }
+
+    fn apply_milestone_diff(&mut self, diff: &MilestoneDiff) -> Result<(), SnapshotVerifyError> {
+        let index = diff.milestone().essence().index();
+
+        // Outputs created and consumed within the milestone are never unspent.
+        for output_id in diff.consumed().keys() {
+            if diff.created().contains_key(output_id) {
+                continue;
+            }
+            if self.outputs.remove(output_id).is_none() {
+                return Err(SnapshotVerifyError::MissingUnspentOutput(index, *output_id));
+            }
+        }
+        for (output_id, created_output) in diff.created() {
+            if diff.consumed().contains_key(output_id) {
+                continue;
+            }
+            if self
+                .outputs
+                .insert(*output_id, created_output.inner().amount())
+                .is_some()
+            {
+                return Err(SnapshotVerifyError::UnexpectedUnspentOutput(index, *output_id));
+            }
+        }
+        if let Some((consumed_treasury, created_treasury)) = treasury_transition(diff)? {
+            if self.treasury != consumed_treasury {
+                return Err(SnapshotVerifyError::TreasuryMismatch(
+                    index,
+                    consumed_treasury,
+                    self.treasury,
+                ));
+            }
+            self.treasury = created_treasury;
+        }
+        self.index = index;
+
+        Ok(())
+    }
+
+    fn rollback_milestone_diff(&mut self, diff: &MilestoneDiff) -> Result<(), SnapshotVerifyError> {
+        let index = diff.milestone().essence().index();
+
+        // Outputs created and consumed within the milestone are never unspent.
+        for output_id in diff.created().keys() {
+            if diff.consumed().contains_key(output_id) {
+                continue;
+            }
+            if self.outputs.remove(output_id).is_none() {
+                return Err(SnapshotVerifyError::MissingUnspentOutput(index, *output_id));
+            }
+        }
+        for (output_id, (created_output, _)) in diff.consumed() {
+            if diff.created().contains_key(output_id) {
+                continue;
+            }
+            if self
+                .outputs
+                .insert(*output_id, created_output.inner().amount())
+                .is_some()
+            {
+                return Err(SnapshotVerifyError::UnexpectedUnspentOutput(index, *output_id));
+            }
+        }
+        if let Some((consumed_treasury, created_treasury)) = treasury_transition(diff)? {
+            if self.treasury != created_treasury {
+                return Err(SnapshotVerifyError::TreasuryMismatch(
+                    index,
+                    created_treasury,
+                    self.treasury,
+                ));
+            }
+            self.treasury = consumed_treasury;
+        }
+        self.index = MilestoneIndex(*index - 1);
+
+        Ok(())
+    }
+}
+
+/// Returns the consumed and created treasury amounts of a milestone diff holding a receipt.
+fn treasury_transition(diff: &MilestoneDiff) -> Result<Option<(u64, u64)>, SnapshotVerifyError> {
+    let index = diff.milestone().essence().index();
+
+    match diff.milestone().essence().receipt() {
+        Some(Payload::Receipt(receipt)) => {
+            let created_treasury = match receipt.transaction() {
+                Payload::TreasuryTransaction(transaction) => match transaction.output() {
+                    Output::Treasury(output) => output.amount(),
+                    _ => return Err(SnapshotVerifyError::InvalidReceipt(index)),
+                },
+                _ => return Err(SnapshotVerifyError::InvalidReceipt(index)),
+            };
+            let consumed_treasury = diff
+                .consumed_treasury()
+                .ok_or(SnapshotVerifyError::InvalidReceipt(index))?
+                .0
+                .amount();
+
+            Ok(Some((consumed_treasury, created_treasury)))
+        }
+        Some(_) => Err(SnapshotVerifyError::InvalidReceipt(index)),
+        None => Ok(None),
+    }
+}
+
+fn snapshot_unpacker(path: &Path) -> Result<IoUnpacker<BufReader<File>>, SnapshotVerifyError> {
+    Ok(IoUnpacker::new(BufReader::new(
+        OpenOptions::new().read(true).open(path)?,
+    )))
+}
+
+fn read_header<U: Unpacker<Error = std::io::Error>>(
+    unpacker: &mut U,
+    kind: SnapshotKind,
+) -> Result<SnapshotHeader, SnapshotVerifyError> {
+    let header = SnapshotHeader::unpack::<_, true>(unpacker).map_err(|_| SnapshotVerifyError::SnapshotUnpack)?;
+
+    if header.kind() != kind {
+        return Err(SnapshotVerifyError::UnexpectedSnapshotKind(kind, header.kind()));
+    }
+
+    Ok(header)
+}
+
+fn read_solid_entry_points<U: Unpacker<Error = std::io::Error>>(
+    unpacker: &mut U,
+    sep_count: u64,
+) -> Result<(), SnapshotVerifyError> {
+    for _ in 0..sep_count {
+        SolidEntryPoint::unpack::<_, true>(unpacker).map_err(|_| SnapshotVerifyError::SnapshotUnpack)?;
+    }
+
+    Ok(())
+}
+
+fn read_outputs<U: Unpacker<Error = std::io::Error>>(
+    unpacker: &mut U,
+    output_count: u64,
+) -> Result<HashMap<OutputId, u64>, SnapshotVerifyError> {
+    let mut outputs = HashMap::new();
+
+    for _ in 0..output_count {
+        MessageId::unpack::<_, true>(unpacker).map_err(|_| SnapshotVerifyError::SnapshotUnpack)?;
+        let output_id = OutputId::unpack::<_, true>(unpacker).map_err(|_| SnapshotVerifyError::SnapshotUnpack)?;
+        MilestoneIndex::unpack::<_, true>(unpacker).map_err(|_| SnapshotVerifyError::SnapshotUnpack)?;
+        u32::unpack::<_, true>(unpacker).map_err(|_| SnapshotVerifyError::SnapshotUnpack)?;
+        // This length prefix is currently needed for hornet/iota.go and not for bee but still needs to be parsed.
+        u32::unpack::<_, true>(unpacker).map_err(|_| SnapshotVerifyError::SnapshotUnpack)?;
+        let output = Output::unpack::<_, true>(unpacker).map_err(|_| SnapshotVerifyError::SnapshotUnpack)?;
+
+        if outputs.insert(output_id, output.amount()).is_some() {
+            return Err(SnapshotVerifyError::DuplicateOutput(output_id));
+        }
+    }
+
+    Ok(outputs)
+}
+
+fn read_milestone_diff<U: Unpacker<Error = std::io::Error>>(
+    unpacker: &mut U,
+    expected_index: MilestoneIndex,
+) -> Result<MilestoneDiff, SnapshotVerifyError> {
+    let diff = MilestoneDiff::unpack::<_, true>(unpacker).map_err(|_| SnapshotVerifyError::SnapshotUnpack)?;
+    let index = diff.milestone().essence().index();
+
+    if index != expected_index {
+        return Err(SnapshotVerifyError::UnexpectedMilestoneDiffIndex(expected_index, index));
+    }
+
+    Ok(diff)
+}
+
+fn check_remaining_bytes(unpacker: IoUnpacker<BufReader<File>>) -> Result<(), SnapshotVerifyError> {
+    if unpacker.into_inner().bytes().next().is_some() {
+        return Err(SnapshotVerifyError::RemainingBytes);
+    }
+
+    Ok(())
+}
+
+/// Verifies a full snapshot file and returns its header along with the ledger state at its sep index.
+fn verify_full_snapshot(path: &Path) -> Result<(SnapshotHeader, LedgerState), SnapshotVerifyError> {
+    let mut unpacker = snapshot_unpacker(path)?;
+    let header = read_header(&mut unpacker, SnapshotKind::Full)?;
+    let full_header =
+        FullSnapshotHeader::unpack::<_, true>(&mut unpacker).map_err(|_| SnapshotVerifyError::SnapshotUnpack)?;
+
+    if header.ledger_index() < header.sep_index() {
+        return Err(SnapshotVerifyError::LedgerSepIndexesInconsistency(
+            header.ledger_index(),
+            header.sep_index(),
+        ));
+    }
+    if *(header.ledger_index() - header.sep_index()) as u64 != full_header.milestone_diff_count() {
+        return Err(SnapshotVerifyError::InvalidMilestoneDiffsCount(
+            *(header.ledger_index() - header.sep_index()) as u64,
+            full_header.milestone_diff_count(),
+        ));
+    }
+
+    read_solid_entry_points(&mut unpacker, full_header.sep_count())?;
+
+    let mut state = LedgerState {
+        index: header.ledger_index(),
+        outputs: read_outputs(&mut unpacker, full_header.output_count())?,
+        treasury: full_header.treasury_output_amount(),
+    };
+
+    state.verify_supply()?;
+
+    // Full snapshot milestone diffs roll the ledger back from the ledger index to the sep index.
+    for index in (*header.sep_index() + 1..=*header.ledger_index()).rev() {
+        let diff = read_milestone_diff(&mut unpacker, MilestoneIndex(index))?;
+
+        state.rollback_milestone_diff(&diff)?;
+        state.verify_supply()?;
+    }
+
+    check_remaining_bytes(unpacker)?;
+
+    println!(
+        "Full snapshot file {} is valid: sep index {}, ledger index {}, {} solid entry points, {} outputs and {} milestone diffs.",
+        path.display(),
+        *header.sep_index(),
+        *header.ledger_index(),
+        full_header.sep_count(),
+        full_header.output_count(),
+        full_header.milestone_diff_count()
+    );
+
+    Ok((header, state))
+}
+
+/// Verifies a delta snapshot file, on top of the ledger state of a full snapshot if given.
+fn verify_delta_snapshot(path: &Path, full: Option<(SnapshotHeader, LedgerState)>) -> Result<(), SnapshotVerifyError> {
+    let mut unpacker = snapshot_unpacker(path)?;
+    let header = read_header(&mut unpacker, SnapshotKind::Delta)?;
+    let delta_header =
+        DeltaSnapshotHeader::unpack::<_, true>(&mut unpacker).map_err(|_| SnapshotVerifyError::SnapshotUnpack)?;
+
+    if header.sep_index() < header.ledger_index() {
+        return Err(SnapshotVerifyError::LedgerSepIndexesInconsistency(
+            header.ledger_index(),
+            header.sep_index(),
+        ));
+    }
+    if *(header.sep_index() - header.ledger_index()) as u64 != delta_header.milestone_diff_count() {
+        return Err(SnapshotVerifyError::InvalidMilestoneDiffsCount(
+            *(header.sep_index() - header.ledger_index()) as u64,
+            delta_header.milestone_diff_count(),
+        ));
+    }
+
+    let mut state = match full {
+        Some((full_header, state)) => {
+            if full_header.network_id() != header.network_id() {
+                return Err(SnapshotVerifyError::NetworkIdMismatch(
+                    full_header.network_id(),
+                    header.network_id(),
+                ));
+            }
+            if full_header.sep_index() != header.ledger_index() {
+                return Err(SnapshotVerifyError::DeltaSnapshotMismatch(
+                    header.ledger_index(),
+                    full_header.sep_index(),
+                ));
+            }
+            Some(state)
+        }
+        None => None,
+    };
+
+    read_solid_entry_points(&mut unpacker, delta_header.sep_count())?;
+
+    // Delta snapshot milestone diffs move the ledger forward from the ledger index to the sep index.
+    for index in *header.ledger_index() + 1..=*header.sep_index() {
+        let diff = read_milestone_diff(&mut unpacker, MilestoneIndex(index))?;
+
+        if let Some(state) = state.as_mut() {
+            state.apply_milestone_diff(&diff)?;
+            state.verify_supply()?;
+        }
+    }
+
+    check_remaining_bytes(unpacker)?;
+
+    println!(
+        "Delta snapshot file {} is valid{}: sep index {}, ledger index {}, {} solid entry points and {} milestone diffs.",
+        path.display(),
+        if state.is_some() {
+            " on top of the full snapshot file"
+        } else {
+            ""
+        },
+        *header.sep_index(),
+        *header.ledger_index(),
+        delta_header.sep_count(),
+        delta_header.milestone_diff_count()
+    );
+
+    Ok(())
+}
+
+pub fn exec(tool: &SnapshotVerifyTool) -> Result<(), SnapshotVerifyError> {
+    let path = Path::new(&tool.path);
+    let mut unpacker = snapshot_unpacker(path)?;
+    let kind = SnapshotHeader::unpack::<_, true>(&mut unpacker)
+        .map_err(|_| SnapshotVerifyError::SnapshotUnpack)?
+        .kind();
+
+    match kind {
+        SnapshotKind::Full => {
+            verify_full_snapshot(path)?;
+        }
+        SnapshotKind::Delta => {
+            let full = match &tool.full_path {
+                Some(full_path) => Some(verify_full_snapshot(Path::new(full_path))?),
+                None => None,
+            };
+
+            verify_delta_snapshot(path, full)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use bee_ledger::types::{ConsumedOutput, CreatedOutput};
+    use bee_message::{
+        address::{Address, Ed25519Address},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+        parent::Parents,
+        payload::{
+            milestone::{MilestoneEssence, MilestonePayload},
+            transaction::TransactionId,
+        },
+    };
+
+    const INDEX: MilestoneIndex = MilestoneIndex(1);
+
+    fn output_id(transaction: u8) -> OutputId {
+        OutputId::new(TransactionId::new([transaction; 32]), 0).unwrap()
+    }
+
+    fn created_output(amount: u64) -> CreatedOutput {
+        CreatedOutput::new(
+            MessageId::null(),
+            INDEX,
+            0,
+            Output::from(
+                BasicOutputBuilder::new(amount)
+                    .unwrap()
+                    .add_unlock_condition(
+                        AddressUnlockCondition::new(Address::from(Ed25519Address::new([0; 32]))).into(),
+                    )
+                    .finish()
+                    .unwrap(),
+            ),
+        )
+    }
+
+    /// Returns a milestone diff moving the whole supply from `previous` to `current` through `intermediate`, which is
+    /// created and consumed within the milestone.
+    fn milestone_diff(previous: OutputId, intermediate: OutputId, current: OutputId) -> MilestoneDiff {
+        let milestone = MilestonePayload::new(
+            MilestoneEssence::new(
+                INDEX,
+                0,
+                Parents::new(vec![MessageId::null()]).unwrap(),
+                [0; MilestoneEssence::MERKLE_PROOF_LENGTH],
+                0,
+                0,
+                vec![[0; 32]],
+                None,
+            )
+            .unwrap(),
+            vec![[0; 64]],
+        )
+        .unwrap();
+        let consumed_output = ConsumedOutput::new(TransactionId::new([0xff; 32]), INDEX);
+
+        MilestoneDiff::new(
+            milestone,
+            None,
+            [
+                (intermediate, created_output(IOTA_SUPPLY)),
+                (current, created_output(IOTA_SUPPLY)),
+            ]
+            .into_iter()
+            .collect(),
+            [
+                (previous, (created_output(IOTA_SUPPLY), consumed_output.clone())),
+                (intermediate, (created_output(IOTA_SUPPLY), consumed_output)),
+            ]
+            .into_iter()
+            .collect(),
+        )
+        .unwrap()
+    }
+
+    fn ledger_state(index: MilestoneIndex, output_id: OutputId) -> LedgerState {
+        LedgerState {
+            index,
+            outputs: [(output_id, IOTA_SUPPLY)].into_iter().collect(),
+            treasury: 0,
+        }
+    }
+
+    #[test]
+    fn apply_output_created_and_consumed_within_milestone() {
+        let (previous, intermediate, current) = (output_id(1), output_id(2), output_id(3));
+        let mut state = ledger_state(MilestoneIndex(0), previous);
+
+        state
+            .apply_milestone_diff(&milestone_diff(previous, intermediate, current))
+            .unwrap();
+        state.verify_supply().unwrap();
+
+        assert_eq!(state.index, INDEX);
+        assert_eq!(state.outputs, [(current, IOTA_SUPPLY)].into_iter().collect());
+    }
+
+    #[test]
+    fn rollback_output_created_and_consumed_within_milestone() {
+        let (previous, intermediate, current) = (output_id(1), output_id(2), output_id(3));
+        let mut state = ledger_state(INDEX, current);
+
+        state
+            .rollback_milestone_diff(&milestone_diff(previous, intermediate, current))
+            .unwrap();
+        state.verify_supply().unwrap();
+
+        assert_eq!(state.index, MilestoneIndex(0));
+        assert_eq!(state.outputs, [(previous, IOTA_SUPPLY)].into_iter().collect());
+    }
+}
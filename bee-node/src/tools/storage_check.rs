@@ -0,0 +1,229 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::workers::{check::check_storage, error::Error as LedgerError};
+use bee_storage::system::StorageHealth;
+#[cfg(feature = "rocksdb")]
+use bee_storage_rocksdb::{config::RocksDbConfigBuilder, error::Error as RocksdbBackendError};
+#[cfg(feature = "sled")]
+use bee_storage_sled::{config::SledConfigBuilder, storage::Error as SledBackendError};
+
+use structopt::StructOpt;
+use thiserror::Error;
+
+use std::path::Path;
+
+#[derive(Debug, Error)]
+pub enum StorageCheckError {
+    #[cfg(feature = "rocksdb")]
+    #[error("Rocksdb storage backend error: {0}")]
+    RocksdbBackend(#[from] RocksdbBackendError),
+    #[cfg(feature = "sled")]
+    #[error("Sled storage backend error: {0}")]
+    SledBackend(#[from] SledBackendError),
+    #[error("Ledger error: {0}")]
+    Ledger(#[from] LedgerError),
+    #[error("No database found at {0}")]
+    MissingDatabase(String),
+    #[error("Found {0} inconsistencies")]
+    Inconsistencies(usize),
+    #[error("Unsupported storage backend: {0}")]
+    UnsupportedBackend(String),
+}
+
+/// The node using the database must be stopped.
+#[derive(Clone, Debug, StructOpt)]
+pub struct StorageCheckTool {
+    /// Path of the database.
+    database_path: String,
+    /// Storage backend of the database, either "rocksdb" or "sled".
+    #[structopt(long, default_value = "rocksdb")]
+    backend: String,
+    /// Whether the inconsistencies of the secondary indexes should be repaired, the database is then marked as healthy
+    /// if no inconsistency remains. Without it, the database is opened read-only if the backend supports it and left
+    /// untouched.
+    #[structopt(long)]
+    repair: bool,
+}
+
+fn exec_inner<B: bee_ledger::workers::StorageBackend>(
+    tool: &StorageCheckTool,
+    storage: &B,
+) -> Result<(), StorageCheckError>
+where
+    StorageCheckError: From<B::Error>,
+{
+    match storage.get_health()? {
+        Some(health) => println!("Storage health: {:?}.", health),
+        None => println!("Storage health: unknown."),
+    }
+
+    let report = check_storage(storage, tool.repair)?;
+
+    for inconsistency in report.inconsistencies() {
+        println!(
+            "{}{}",
+            inconsistency,
+            if tool.repair && inconsistency.is_repairable() {
+                " (repaired)"
+            } else {
+                ""
+            }
+        );
+    }
+
+    let remaining = report.inconsistencies().len() - report.repaired();
+
+    println!(
+        "Found {} inconsistencies, repaired {}.",
+        report.inconsistencies().len(),
+        report.repaired()
+    );
+
+    if tool.repair {
+        if remaining > 0 {
+            storage.set_health(StorageHealth::Corrupted)?;
+        } else {
+            storage.set_health(StorageHealth::Healthy)?;
+            println!("Marked the storage as healthy.");
+        }
+    }
+
+    if remaining > 0 {
+        return Err(StorageCheckError::Inconsistencies(remaining));
+    }
+
+    Ok(())
+}
+
+/// The storage is opened without checking its health nor migrating it, so that a storage left unhealthy by a crashed
+/// node can be checked as is.
+pub fn exec(tool: &StorageCheckTool) -> Result<(), StorageCheckError> {
+    // Opening a missing database would create an empty one.
+    if !Path::new(&tool.database_path).exists() {
+        return Err(StorageCheckError::MissingDatabase(tool.database_path.clone()));
+    }
+
+    match tool.backend.as_str() {
+        #[cfg(feature = "rocksdb")]
+        "rocksdb" => {
+            let config = RocksDbConfigBuilder::default()
+                .with_path(tool.database_path.clone())
+                .finish();
+            let storage = if tool.repair {
+                bee_storage_rocksdb::storage::Storage::new(config)?
+            } else {
+                bee_storage_rocksdb::storage::Storage::open_read_only(config)?
+            };
+
+            exec_inner(tool, &storage)
+        }
+        #[cfg(feature = "sled")]
+        "sled" => {
+            let config = SledConfigBuilder::default()
+                .with_path(tool.database_path.clone())
+                .finish();
+            // Sled has no read-only mode, nothing is written unless repairing.
+            let storage = bee_storage_sled::storage::Storage::new(config)?;
+
+            exec_inner(tool, &storage)
+        }
+        backend => Err(StorageCheckError::UnsupportedBackend(backend.to_owned())),
+    }
+}
+
+#[cfg(all(test, feature = "sled"))]
+mod test {
+    use super::*;
+
+    use bee_ledger::types::TreasuryOutput;
+    use bee_message::{constant::IOTA_SUPPLY, output, payload::milestone::MilestoneId};
+    use bee_storage::{access::Insert, backend::StorageBackend};
+
+    use fs2::FileExt;
+
+    use std::fs;
+
+    /// Sled only releases the lock of a dropped database once its background threads are done with it, this blocks
+    /// until then so that the database can be opened again.
+    fn wait_for_release(database_path: &str) {
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(Path::new(database_path).join("db"))
+            .unwrap();
+
+        file.lock_exclusive().unwrap();
+        file.unlock().unwrap();
+    }
+
+    fn tool(database_path: &str, repair: bool) -> StorageCheckTool {
+        StorageCheckTool {
+            database_path: database_path.to_owned(),
+            backend: "sled".to_owned(),
+            repair,
+        }
+    }
+
+    fn health(database_path: &str) -> Option<StorageHealth> {
+        wait_for_release(database_path);
+
+        let storage = bee_storage_sled::storage::Storage::new(
+            SledConfigBuilder::default()
+                .with_path(database_path.to_owned())
+                .finish(),
+        )
+        .unwrap();
+        let health = storage.get_health().unwrap();
+        drop(storage);
+        wait_for_release(database_path);
+
+        health
+    }
+
+    #[test]
+    fn check_missing_storage() {
+        let directory = std::env::temp_dir().join(format!("bee-check-missing-storage-{}", std::process::id()));
+        let database_path = directory.to_string_lossy().into_owned();
+
+        assert!(matches!(
+            exec(&tool(&database_path, true)),
+            Err(StorageCheckError::MissingDatabase(_))
+        ));
+        assert!(!directory.exists());
+    }
+
+    #[test]
+    fn check_crashed_storage() {
+        let directory = std::env::temp_dir().join(format!("bee-check-crashed-storage-{}", std::process::id()));
+        let database_path = directory.to_string_lossy().into_owned();
+
+        // The storage is left idle, as if the node crashed.
+        let storage = bee_storage_sled::storage::Storage::start(
+            SledConfigBuilder::default().with_path(database_path.clone()).finish(),
+        )
+        .unwrap();
+        Insert::<(bool, TreasuryOutput), ()>::insert(
+            &storage,
+            &(
+                false,
+                TreasuryOutput::new(
+                    output::TreasuryOutput::new(IOTA_SUPPLY).unwrap(),
+                    MilestoneId::new([0; MilestoneId::LENGTH]),
+                ),
+            ),
+            &(),
+        )
+        .unwrap();
+        drop(storage);
+        wait_for_release(&database_path);
+
+        exec(&tool(&database_path, false)).unwrap();
+        assert_eq!(health(&database_path), Some(StorageHealth::Idle));
+
+        exec(&tool(&database_path, true)).unwrap();
+        assert_eq!(health(&database_path), Some(StorageHealth::Healthy));
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
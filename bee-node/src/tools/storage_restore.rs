@@ -0,0 +1,217 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_storage::{
+    access::Fetch,
+    backend::StorageBackend,
+    system::{StorageHealth, System, SYSTEM_VERSION_KEY},
+};
+#[cfg(feature = "rocksdb")]
+use bee_storage_rocksdb::{config::RocksDbConfigBuilder, error::Error as RocksdbBackendError};
+#[cfg(feature = "sled")]
+use bee_storage_sled::{config::SledConfigBuilder, storage::Error as SledBackendError};
+
+use structopt::StructOpt;
+use thiserror::Error;
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Error)]
+pub enum StorageRestoreError {
+    #[cfg(feature = "rocksdb")]
+    #[error("Rocksdb storage backend error: {0}")]
+    RocksdbBackend(#[from] RocksdbBackendError),
+    #[cfg(feature = "sled")]
+    #[error("Sled storage backend error: {0}")]
+    SledBackend(#[from] SledBackendError),
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Backup not found: {0}")]
+    BackupNotFound(String),
+    #[error("Not a storage backup: {0}")]
+    NotABackup(String),
+    #[error("Unhealthy backup: {0:?}")]
+    UnhealthyBackup(Option<StorageHealth>),
+    #[error("Path already exists: {0}")]
+    PathAlreadyExists(String),
+    #[error("Unsupported storage backend: {0}")]
+    UnsupportedBackend(String),
+}
+
+/// The node using the database must be stopped.
+#[derive(Clone, Debug, StructOpt)]
+pub struct StorageRestoreTool {
+    /// Path of the backup to restore.
+    backup_path: String,
+    /// Path of the database to replace, the current database is kept next to it with an ".old" extension.
+    database_path: String,
+    /// Storage backend of the database, either "rocksdb" or "sled".
+    #[structopt(long, default_value = "rocksdb")]
+    backend: String,
+}
+
+fn copy_dir(from: &Path, to: &Path) -> Result<(), io::Error> {
+    fs::create_dir_all(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the storage has a version, since opening an empty directory creates a fresh storage, and that it has
+/// been marked as healthy.
+fn check_backup<B>(storage: &B, path: &str) -> Result<(), StorageRestoreError>
+where
+    B: StorageBackend + Fetch<u8, System>,
+    StorageRestoreError: From<B::Error>,
+{
+    if Fetch::<u8, System>::fetch(storage, &SYSTEM_VERSION_KEY)?.is_none() {
+        return Err(StorageRestoreError::NotABackup(path.to_owned()));
+    }
+
+    match storage.get_health()? {
+        Some(StorageHealth::Healthy) => Ok(()),
+        health => Err(StorageRestoreError::UnhealthyBackup(health)),
+    }
+}
+
+/// Checks the backup before starting it, starting the storage checks that its version is supported and migrates it if
+/// needed.
+fn validate(tool: &StorageRestoreTool, path: &Path) -> Result<(), StorageRestoreError> {
+    let path = path.to_string_lossy().into_owned();
+
+    match tool.backend.as_str() {
+        #[cfg(feature = "rocksdb")]
+        "rocksdb" => {
+            let config = RocksDbConfigBuilder::default().with_path(path.clone()).finish();
+
+            check_backup(&bee_storage_rocksdb::storage::Storage::new(config.clone())?, &path)?;
+            bee_storage_rocksdb::storage::Storage::start(config)?.shutdown()?;
+        }
+        #[cfg(feature = "sled")]
+        "sled" => {
+            let config = SledConfigBuilder::default().with_path(path.clone()).finish();
+
+            check_backup(&bee_storage_sled::storage::Storage::new(config.clone())?, &path)?;
+            bee_storage_sled::storage::Storage::start(config)?.shutdown()?;
+        }
+        backend => return Err(StorageRestoreError::UnsupportedBackend(backend.to_owned())),
+    }
+
+    Ok(())
+}
+
+pub fn exec(tool: &StorageRestoreTool) -> Result<(), StorageRestoreError> {
+    let backup_path = Path::new(&tool.backup_path);
+    let database_path = Path::new(&tool.database_path);
+    let staging_path = PathBuf::from(format!("{}.restore", tool.database_path));
+    let old_path = PathBuf::from(format!("{}.old", tool.database_path));
+
+    if !backup_path.is_dir() {
+        return Err(StorageRestoreError::BackupNotFound(tool.backup_path.clone()));
+    }
+
+    for path in [&staging_path, &old_path] {
+        if path.exists() {
+            return Err(StorageRestoreError::PathAlreadyExists(path.display().to_string()));
+        }
+    }
+
+    // The backup itself is left untouched, a copy of it is validated and swapped in.
+    copy_dir(backup_path, &staging_path)?;
+
+    if let Err(e) = validate(tool, &staging_path) {
+        let _ = fs::remove_dir_all(&staging_path);
+        return Err(e);
+    }
+
+    if database_path.exists() {
+        fs::rename(database_path, &old_path)?;
+        println!("Moved the current database to {}.", old_path.display());
+    }
+
+    fs::rename(&staging_path, database_path)?;
+
+    println!(
+        "Restored the backup {} to {}.",
+        backup_path.display(),
+        database_path.display()
+    );
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "sled"))]
+mod test {
+    use super::*;
+
+    use fs2::FileExt;
+
+    /// Blocks until sled has released the lock of the database at the given path, which it does from its background
+    /// threads after the database is dropped.
+    fn wait_for_release(path: &str) {
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(Path::new(path).join("db"))
+            .unwrap();
+
+        file.lock_exclusive().unwrap();
+        file.unlock().unwrap();
+    }
+
+    fn tool(directory: &Path) -> StorageRestoreTool {
+        StorageRestoreTool {
+            backup_path: directory.join("backup").to_string_lossy().into_owned(),
+            database_path: directory.join("database").to_string_lossy().into_owned(),
+            backend: "sled".to_owned(),
+        }
+    }
+
+    #[test]
+    fn restore_empty_directory() {
+        let directory = std::env::temp_dir().join(format!("bee-restore-empty-directory-{}", std::process::id()));
+        let tool = tool(&directory);
+        fs::create_dir_all(&tool.backup_path).unwrap();
+
+        assert!(matches!(exec(&tool), Err(StorageRestoreError::NotABackup(_))));
+        assert!(!Path::new(&tool.database_path).exists());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn restore_unhealthy_backup() {
+        let directory = std::env::temp_dir().join(format!("bee-restore-unhealthy-backup-{}", std::process::id()));
+        let tool = tool(&directory);
+        let storage = bee_storage_sled::storage::Storage::start(
+            SledConfigBuilder::default()
+                .with_path(tool.backup_path.clone())
+                .finish(),
+        )
+        .unwrap();
+        storage.set_health(StorageHealth::Corrupted).unwrap();
+        drop(storage);
+        wait_for_release(&tool.backup_path);
+
+        assert!(matches!(
+            exec(&tool),
+            Err(StorageRestoreError::UnhealthyBackup(Some(StorageHealth::Corrupted)))
+        ));
+        assert!(!Path::new(&tool.database_path).exists());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
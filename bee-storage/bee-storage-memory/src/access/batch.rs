@@ -6,13 +6,16 @@
 use crate::{storage::Storage, table::TableBatch};
 
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::{
@@ -32,7 +35,12 @@ pub struct StorageBatch {
     output_id_to_created_output: TableBatch<OutputId, CreatedOutput>,
     output_id_to_consumed_output: TableBatch<OutputId, ConsumedOutput>,
     output_id_unspent: TableBatch<Unspent, ()>,
-    ed25519_address_to_output_id: TableBatch<(Ed25519Address, OutputId), ()>,
+    address_to_output_id: TableBatch<((Address, AddressRole), OutputId), ()>,
+    alias_id_to_output_id: TableBatch<AliasId, OutputId>,
+    nft_id_to_output_id: TableBatch<NftId, OutputId>,
+    foundry_id_to_output_id: TableBatch<FoundryId, OutputId>,
+    tag_to_output_id: TableBatch<(TagFeatureBlock, OutputId), ()>,
+    sender_to_output_id: TableBatch<(SenderFeatureBlock, OutputId), ()>,
     ledger_index: TableBatch<(), LedgerIndex>,
     milestone_index_to_milestone: TableBatch<MilestoneIndex, Milestone>,
     snapshot_info: TableBatch<(), SnapshotInfo>,
@@ -65,7 +73,12 @@ impl BatchBuilder for Storage {
         apply_batch!(output_id_to_created_output);
         apply_batch!(output_id_to_consumed_output);
         apply_batch!(output_id_unspent);
-        apply_batch!(ed25519_address_to_output_id);
+        apply_batch!(address_to_output_id);
+        apply_batch!(alias_id_to_output_id);
+        apply_batch!(nft_id_to_output_id);
+        apply_batch!(foundry_id_to_output_id);
+        apply_batch!(tag_to_output_id);
+        apply_batch!(sender_to_output_id);
         apply_batch!(ledger_index);
         apply_batch!(milestone_index_to_milestone);
         apply_batch!(snapshot_info);
@@ -108,7 +121,12 @@ impl_batch!((MessageId, MessageId), (), message_id_to_message_id);
 impl_batch!(OutputId, CreatedOutput, output_id_to_created_output);
 impl_batch!(OutputId, ConsumedOutput, output_id_to_consumed_output);
 impl_batch!(Unspent, (), output_id_unspent);
-impl_batch!((Ed25519Address, OutputId), (), ed25519_address_to_output_id);
+impl_batch!(((Address, AddressRole), OutputId), (), address_to_output_id);
+impl_batch!(AliasId, OutputId, alias_id_to_output_id);
+impl_batch!(NftId, OutputId, nft_id_to_output_id);
+impl_batch!(FoundryId, OutputId, foundry_id_to_output_id);
+impl_batch!((TagFeatureBlock, OutputId), (), tag_to_output_id);
+impl_batch!((SenderFeatureBlock, OutputId), (), sender_to_output_id);
 impl_batch!((), LedgerIndex, ledger_index);
 impl_batch!(MilestoneIndex, Milestone, milestone_index_to_milestone);
 impl_batch!((), SnapshotInfo, snapshot_info);
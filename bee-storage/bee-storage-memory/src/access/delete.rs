@@ -6,13 +6,16 @@
 use crate::storage::Storage;
 
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::{access::Delete, backend::StorageBackend};
@@ -38,7 +41,12 @@ impl_delete!((MessageId, MessageId), (), message_id_to_message_id);
 impl_delete!(OutputId, CreatedOutput, output_id_to_created_output);
 impl_delete!(OutputId, ConsumedOutput, output_id_to_consumed_output);
 impl_delete!(Unspent, (), output_id_unspent);
-impl_delete!((Ed25519Address, OutputId), (), ed25519_address_to_output_id);
+impl_delete!(((Address, AddressRole), OutputId), (), address_to_output_id);
+impl_delete!(AliasId, OutputId, alias_id_to_output_id);
+impl_delete!(NftId, OutputId, nft_id_to_output_id);
+impl_delete!(FoundryId, OutputId, foundry_id_to_output_id);
+impl_delete!((TagFeatureBlock, OutputId), (), tag_to_output_id);
+impl_delete!((SenderFeatureBlock, OutputId), (), sender_to_output_id);
 impl_delete!((), LedgerIndex, ledger_index);
 impl_delete!(MilestoneIndex, Milestone, milestone_index_to_milestone);
 impl_delete!((), SnapshotInfo, snapshot_info);
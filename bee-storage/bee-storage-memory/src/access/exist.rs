@@ -6,13 +6,16 @@
 use crate::storage::Storage;
 
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::{access::Exist, backend::StorageBackend};
@@ -36,7 +39,12 @@ impl_exist!((MessageId, MessageId), (), message_id_to_message_id);
 impl_exist!(OutputId, CreatedOutput, output_id_to_created_output);
 impl_exist!(OutputId, ConsumedOutput, output_id_to_consumed_output);
 impl_exist!(Unspent, (), output_id_unspent);
-impl_exist!((Ed25519Address, OutputId), (), ed25519_address_to_output_id);
+impl_exist!(((Address, AddressRole), OutputId), (), address_to_output_id);
+impl_exist!(AliasId, OutputId, alias_id_to_output_id);
+impl_exist!(NftId, OutputId, nft_id_to_output_id);
+impl_exist!(FoundryId, OutputId, foundry_id_to_output_id);
+impl_exist!((TagFeatureBlock, OutputId), (), tag_to_output_id);
+impl_exist!((SenderFeatureBlock, OutputId), (), sender_to_output_id);
 impl_exist!((), LedgerIndex, ledger_index);
 impl_exist!(MilestoneIndex, Milestone, milestone_index_to_milestone);
 impl_exist!((), SnapshotInfo, snapshot_info);
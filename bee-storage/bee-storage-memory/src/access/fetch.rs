@@ -3,15 +3,19 @@
 
 //! Fetch access operations.
 
-use crate::storage::Storage;
+use crate::storage::{Storage, FETCH_OUTPUT_ID_LIMIT};
 
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
+    snapshot::info::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::{access::Fetch, backend::StorageBackend, system::System};
@@ -29,13 +33,29 @@ macro_rules! impl_fetch {
     };
 }
 
+macro_rules! impl_fetch_output_ids {
+    ($key:ty, $field:ident) => {
+        impl Fetch<$key, Vec<OutputId>> for Storage {
+            fn fetch(&self, k: &$key) -> Result<Option<Vec<OutputId>>, <Self as StorageBackend>::Error> {
+                Ok(self.inner.read()?.$field.fetch(k).map(|mut output_ids| {
+                    output_ids.truncate(FETCH_OUTPUT_ID_LIMIT);
+                    output_ids
+                }))
+            }
+        }
+    };
+}
+
 impl_fetch!(u8, System, system);
 impl_fetch!(MessageId, Message, message_id_to_message);
 impl_fetch!(MessageId, MessageMetadata, message_id_to_metadata);
 impl_fetch!(MessageId, Vec<MessageId>, message_id_to_message_id);
 impl_fetch!(OutputId, CreatedOutput, output_id_to_created_output);
 impl_fetch!(OutputId, ConsumedOutput, output_id_to_consumed_output);
-impl_fetch!(Ed25519Address, Vec<OutputId>, ed25519_address_to_output_id);
+impl_fetch_output_ids!((Address, AddressRole), address_to_output_id);
+impl_fetch!(AliasId, OutputId, alias_id_to_output_id);
+impl_fetch!(NftId, OutputId, nft_id_to_output_id);
+impl_fetch!(FoundryId, OutputId, foundry_id_to_output_id);
 impl_fetch!((), LedgerIndex, ledger_index);
 impl_fetch!(MilestoneIndex, Milestone, milestone_index_to_milestone);
 impl_fetch!((), SnapshotInfo, snapshot_info);
@@ -48,3 +68,5 @@ impl_fetch!(
 );
 impl_fetch!(MilestoneIndex, Vec<Receipt>, milestone_index_to_receipt);
 impl_fetch!(bool, Vec<TreasuryOutput>, spent_to_treasury_output);
+impl_fetch_output_ids!(TagFeatureBlock, tag_to_output_id);
+impl_fetch_output_ids!(SenderFeatureBlock, sender_to_output_id);
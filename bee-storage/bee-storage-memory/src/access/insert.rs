@@ -6,13 +6,16 @@
 use crate::storage::Storage;
 
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::{access::Insert, backend::StorageBackend, system::System};
@@ -39,7 +42,12 @@ impl_insert!((MessageId, MessageId), (), message_id_to_message_id);
 impl_insert!(OutputId, CreatedOutput, output_id_to_created_output);
 impl_insert!(OutputId, ConsumedOutput, output_id_to_consumed_output);
 impl_insert!(Unspent, (), output_id_unspent);
-impl_insert!((Ed25519Address, OutputId), (), ed25519_address_to_output_id);
+impl_insert!(((Address, AddressRole), OutputId), (), address_to_output_id);
+impl_insert!(AliasId, OutputId, alias_id_to_output_id);
+impl_insert!(NftId, OutputId, nft_id_to_output_id);
+impl_insert!(FoundryId, OutputId, foundry_id_to_output_id);
+impl_insert!((TagFeatureBlock, OutputId), (), tag_to_output_id);
+impl_insert!((SenderFeatureBlock, OutputId), (), sender_to_output_id);
 impl_insert!((), LedgerIndex, ledger_index);
 impl_insert!(MilestoneIndex, Milestone, milestone_index_to_milestone);
 impl_insert!((), SnapshotInfo, snapshot_info);
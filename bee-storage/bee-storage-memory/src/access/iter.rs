@@ -5,23 +5,33 @@
 
 use crate::{
     storage::Storage,
-    table::{SingletonTableIter, TableIter, VecTableIter},
+    table::{SingletonTableIter, TableIter, TableRangeIter, VecTableIter},
 };
 
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
-use bee_storage::{access::AsIterator, backend::StorageBackend, system::System};
+use bee_storage::{
+    access::{AsIterator, AsRangeIterator, IterDirection},
+    backend::StorageBackend,
+    system::System,
+};
 use bee_tangle::{
     metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
 };
 
+use packable::PackableExt;
+
 macro_rules! impl_iter {
     (($key:ty, $value:ty), (), $field:ident) => {
         impl<'a> AsIterator<'a, ($key, $value), ()> for Storage {
@@ -60,7 +70,12 @@ impl_iter!((MessageId, MessageId), (), message_id_to_message_id);
 impl_iter!(OutputId, CreatedOutput, output_id_to_created_output);
 impl_iter!(OutputId, ConsumedOutput, output_id_to_consumed_output);
 impl_iter!(Unspent, (), output_id_unspent);
-impl_iter!((Ed25519Address, OutputId), (), ed25519_address_to_output_id);
+impl_iter!(((Address, AddressRole), OutputId), (), address_to_output_id);
+impl_iter!(AliasId, OutputId, alias_id_to_output_id);
+impl_iter!(NftId, OutputId, nft_id_to_output_id);
+impl_iter!(FoundryId, OutputId, foundry_id_to_output_id);
+impl_iter!((TagFeatureBlock, OutputId), (), tag_to_output_id);
+impl_iter!((SenderFeatureBlock, OutputId), (), sender_to_output_id);
 impl_iter!((), LedgerIndex, ledger_index);
 impl_iter!(MilestoneIndex, Milestone, milestone_index_to_milestone);
 impl_iter!((), SnapshotInfo, snapshot_info);
@@ -73,3 +88,114 @@ impl_iter!(
 );
 impl_iter!((MilestoneIndex, Receipt), (), milestone_index_to_receipt);
 impl_iter!((bool, TreasuryOutput), (), spent_to_treasury_output);
+
+// Keys are compared by the same byte representation as the persistent backends use, for all backends to agree on the
+// iteration order.
+macro_rules! impl_range_iter {
+    ($prefix:ty, ($key:ty, $value:ty), $field:ident, $pack_prefix:expr, $pack_key:expr) => {
+        impl<'a> AsRangeIterator<'a, $prefix, ($key, $value), ()> for Storage {
+            type AsRangeIter = TableRangeIter<($key, $value), ()>;
+
+            fn iter_from(
+                &'a self,
+                start: &($key, $value),
+                direction: IterDirection,
+            ) -> Result<Self::AsRangeIter, <Self as StorageBackend>::Error> {
+                let start: Vec<u8> = $pack_key(start);
+
+                Ok(self.inner.read()?.$field.range_iter(
+                    $pack_key,
+                    |key| match direction {
+                        IterDirection::Forward => key >= start.as_slice(),
+                        IterDirection::Reverse => key <= start.as_slice(),
+                    },
+                    direction,
+                ))
+            }
+
+            fn iter_range(
+                &'a self,
+                start: &($key, $value),
+                end: &($key, $value),
+                direction: IterDirection,
+            ) -> Result<Self::AsRangeIter, <Self as StorageBackend>::Error> {
+                let start: Vec<u8> = $pack_key(start);
+                let end: Vec<u8> = $pack_key(end);
+
+                Ok(self.inner.read()?.$field.range_iter(
+                    $pack_key,
+                    |key| key >= start.as_slice() && key < end.as_slice(),
+                    direction,
+                ))
+            }
+
+            fn iter_prefix(
+                &'a self,
+                prefix: &$prefix,
+                direction: IterDirection,
+            ) -> Result<Self::AsRangeIter, <Self as StorageBackend>::Error> {
+                let prefix: Vec<u8> = $pack_prefix(prefix);
+
+                Ok(self
+                    .inner
+                    .read()?
+                    .$field
+                    .range_iter($pack_key, |key| key.starts_with(&prefix), direction))
+            }
+        }
+    };
+}
+
+impl_range_iter!(
+    MessageId,
+    (MessageId, MessageId),
+    message_id_to_message_id,
+    |parent: &MessageId| parent.as_ref().to_vec(),
+    |(parent, child): &(MessageId, MessageId)| [parent.as_ref(), child.as_ref()].concat()
+);
+impl_range_iter!(
+    (Address, AddressRole),
+    ((Address, AddressRole), OutputId),
+    address_to_output_id,
+    |(address, role): &(Address, AddressRole)| [address.pack_to_vec(), role.pack_to_vec()].concat(),
+    |((address, role), output_id): &((Address, AddressRole), OutputId)| {
+        [address.pack_to_vec(), role.pack_to_vec(), output_id.pack_to_vec()].concat()
+    }
+);
+impl_range_iter!(
+    TagFeatureBlock,
+    (TagFeatureBlock, OutputId),
+    tag_to_output_id,
+    |tag: &TagFeatureBlock| tag.tag().to_vec(),
+    |(tag, output_id): &(TagFeatureBlock, OutputId)| [tag.tag().to_vec(), output_id.pack_to_vec()].concat()
+);
+impl_range_iter!(
+    SenderFeatureBlock,
+    (SenderFeatureBlock, OutputId),
+    sender_to_output_id,
+    |sender: &SenderFeatureBlock| sender.pack_to_vec(),
+    |(sender, output_id): &(SenderFeatureBlock, OutputId)| [sender.pack_to_vec(), output_id.pack_to_vec()].concat()
+);
+impl_range_iter!(
+    MilestoneIndex,
+    (MilestoneIndex, UnreferencedMessage),
+    milestone_index_to_unreferenced_message,
+    |index: &MilestoneIndex| index.pack_to_vec(),
+    |(index, unreferenced_message): &(MilestoneIndex, UnreferencedMessage)| {
+        [index.pack_to_vec().as_slice(), unreferenced_message.as_ref()].concat()
+    }
+);
+impl_range_iter!(
+    MilestoneIndex,
+    (MilestoneIndex, Receipt),
+    milestone_index_to_receipt,
+    |index: &MilestoneIndex| index.pack_to_vec(),
+    |(index, receipt): &(MilestoneIndex, Receipt)| [index.pack_to_vec(), receipt.pack_to_vec()].concat()
+);
+impl_range_iter!(
+    bool,
+    (bool, TreasuryOutput),
+    spent_to_treasury_output,
+    |spent: &bool| spent.pack_to_vec(),
+    |(spent, output): &(bool, TreasuryOutput)| [spent.pack_to_vec(), output.pack_to_vec()].concat()
+);
@@ -8,7 +8,7 @@ use crate::storage::Storage;
 use bee_ledger::types::{ConsumedOutput, CreatedOutput, OutputDiff};
 use bee_message::{
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{AliasId, FoundryId, NftId, OutputId},
     Message, MessageId,
 };
 use bee_storage::{access::MultiFetch, backend::StorageBackend, system::System};
@@ -36,6 +36,9 @@ impl_multi_fetch!(MessageId, Message, message_id_to_message);
 impl_multi_fetch!(MessageId, MessageMetadata, message_id_to_metadata);
 impl_multi_fetch!(OutputId, CreatedOutput, output_id_to_created_output);
 impl_multi_fetch!(OutputId, ConsumedOutput, output_id_to_consumed_output);
+impl_multi_fetch!(AliasId, OutputId, alias_id_to_output_id);
+impl_multi_fetch!(NftId, OutputId, nft_id_to_output_id);
+impl_multi_fetch!(FoundryId, OutputId, foundry_id_to_output_id);
 impl_multi_fetch!(MilestoneIndex, Milestone, milestone_index_to_milestone);
 impl_multi_fetch!(SolidEntryPoint, MilestoneIndex, solid_entry_point_to_milestone_index);
 impl_multi_fetch!(MilestoneIndex, OutputDiff, milestone_index_to_output_diff);
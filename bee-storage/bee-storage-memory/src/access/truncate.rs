@@ -6,12 +6,16 @@
 use crate::storage::Storage;
 
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::{access::Truncate, backend::StorageBackend};
@@ -37,7 +41,12 @@ impl_truncate!((MessageId, MessageId), (), message_id_to_message_id);
 impl_truncate!(OutputId, CreatedOutput, output_id_to_created_output);
 impl_truncate!(OutputId, ConsumedOutput, output_id_to_consumed_output);
 impl_truncate!(Unspent, (), output_id_unspent);
-impl_truncate!((Ed25519Address, OutputId), (), ed25519_address_to_output_id);
+impl_truncate!(((Address, AddressRole), OutputId), (), address_to_output_id);
+impl_truncate!(AliasId, OutputId, alias_id_to_output_id);
+impl_truncate!(NftId, OutputId, nft_id_to_output_id);
+impl_truncate!(FoundryId, OutputId, foundry_id_to_output_id);
+impl_truncate!((TagFeatureBlock, OutputId), (), tag_to_output_id);
+impl_truncate!((SenderFeatureBlock, OutputId), (), sender_to_output_id);
 impl_truncate!((), LedgerIndex, ledger_index);
 impl_truncate!(MilestoneIndex, Milestone, milestone_index_to_milestone);
 impl_truncate!((), SnapshotInfo, snapshot_info);
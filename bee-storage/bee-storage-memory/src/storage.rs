@@ -6,12 +6,16 @@
 use crate::table::{SingletonTable, Table, VecBinTable, VecTable};
 
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::{
@@ -48,6 +52,8 @@ impl<T> From<PoisonError<T>> for Error {
 }
 
 pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion(0);
+/// Maximum number of output ids returned by a fetch, as the default `fetch_output_id_limit` of the other backends.
+pub(crate) const FETCH_OUTPUT_ID_LIMIT: usize = 1_000;
 
 /// An in-memory database.
 #[derive(Default)]
@@ -64,7 +70,12 @@ pub(crate) struct InnerStorage {
     pub(crate) output_id_to_created_output: Table<OutputId, CreatedOutput>,
     pub(crate) output_id_to_consumed_output: Table<OutputId, ConsumedOutput>,
     pub(crate) output_id_unspent: Table<Unspent, ()>,
-    pub(crate) ed25519_address_to_output_id: VecBinTable<Ed25519Address, OutputId>,
+    pub(crate) address_to_output_id: VecBinTable<(Address, AddressRole), OutputId>,
+    pub(crate) alias_id_to_output_id: Table<AliasId, OutputId>,
+    pub(crate) nft_id_to_output_id: Table<NftId, OutputId>,
+    pub(crate) foundry_id_to_output_id: Table<FoundryId, OutputId>,
+    pub(crate) tag_to_output_id: VecBinTable<TagFeatureBlock, OutputId>,
+    pub(crate) sender_to_output_id: VecBinTable<SenderFeatureBlock, OutputId>,
     pub(crate) ledger_index: SingletonTable<LedgerIndex>,
     pub(crate) milestone_index_to_milestone: Table<MilestoneIndex, Milestone>,
     pub(crate) snapshot_info: SingletonTable<SnapshotInfo>,
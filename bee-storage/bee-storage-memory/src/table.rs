@@ -3,6 +3,8 @@
 
 use crate::storage::Error;
 
+use bee_storage::access::IterDirection;
+
 use std::{
     collections::{hash_map::IntoIter as HashMapIter, HashMap},
     hash::Hash,
@@ -12,6 +14,7 @@ use std::{
 };
 
 pub(crate) type TableIter<K, V> = Map<HashMapIter<K, V>, fn((K, V)) -> Result<(K, V), Error>>;
+pub(crate) type TableRangeIter<K, V> = Map<VecIter<(K, V)>, fn((K, V)) -> Result<(K, V), Error>>;
 pub(crate) type TableMultiFetchIter<V> = Map<VecIter<Option<V>>, fn(Option<V>) -> Result<Option<V>, Error>>;
 
 pub(crate) struct Table<K, V> {
@@ -103,6 +106,35 @@ impl<K: Clone, V> Iterator for VecTableIter<K, V> {
     }
 }
 
+/// Returns the elements of a `VecTable` or `VecBinTable` whose keys, in their storage representation, satisfy the
+/// predicate, ordered by these keys in the given direction.
+fn range_iter<K: Clone, V: Clone>(
+    inner: &HashMap<K, Vec<V>>,
+    key_to_vec: impl Fn(&(K, V)) -> Vec<u8>,
+    predicate: impl Fn(&[u8]) -> bool,
+    direction: IterDirection,
+) -> TableRangeIter<(K, V), ()> {
+    let mut elements = inner
+        .iter()
+        .flat_map(|(k, vs)| vs.iter().map(move |v| (k.clone(), v.clone())))
+        .map(|element| (key_to_vec(&element), element))
+        .filter(|(bytes, _)| predicate(bytes))
+        .collect::<Vec<_>>();
+
+    elements.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+    if direction == IterDirection::Reverse {
+        elements.reverse();
+    }
+
+    let elements = elements
+        .into_iter()
+        .map(|(_, element)| (element, ()))
+        .collect::<Vec<_>>();
+
+    elements.into_iter().map(Ok)
+}
+
 pub(crate) struct VecTable<K, V> {
     inner: HashMap<K, Vec<V>>,
 }
@@ -151,6 +183,15 @@ impl<K: Hash + Eq + Clone, V: Clone + Eq> VecTable<K, V> {
         VecTableIter::new(self.inner.clone().into_iter())
     }
 
+    pub(crate) fn range_iter(
+        &self,
+        key_to_vec: impl Fn(&(K, V)) -> Vec<u8>,
+        predicate: impl Fn(&[u8]) -> bool,
+        direction: IterDirection,
+    ) -> TableRangeIter<(K, V), ()> {
+        range_iter(&self.inner, key_to_vec, predicate, direction)
+    }
+
     pub(crate) fn batch_commit(&mut self, batch: TableBatch<(K, V), ()>) {
         for op in batch.0 {
             match op {
@@ -221,6 +262,15 @@ impl<K: Hash + Eq + Clone, V: Clone + Eq + Ord> VecBinTable<K, V> {
         VecTableIter::new(self.inner.clone().into_iter())
     }
 
+    pub(crate) fn range_iter(
+        &self,
+        key_to_vec: impl Fn(&(K, V)) -> Vec<u8>,
+        predicate: impl Fn(&[u8]) -> bool,
+        direction: IterDirection,
+    ) -> TableRangeIter<(K, V), ()> {
+        range_iter(&self.inner, key_to_vec, predicate, direction)
+    }
+
     pub(crate) fn batch_commit(&mut self, batch: TableBatch<(K, V), ()>) {
         for op in batch.0 {
             match op {
@@ -0,0 +1,7 @@
+// Copyright 2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+mod access;
+
+impl_access_test!(address_to_output_id_access_memory, address_to_output_id_access);
@@ -3,7 +3,10 @@
 
 use crate::Storage;
 
-use bee_storage::{access::AsIterator, backend::StorageBackend};
+use bee_storage::{
+    access::{AsIterator, AsRangeIterator, IterDirection},
+    backend::StorageBackend,
+};
 
 use std::marker::PhantomData;
 
@@ -32,3 +35,19 @@ impl<'a, K, V> AsIterator<'a, K, V> for Storage {
         Ok(StorageIterator::new())
     }
 }
+
+impl<'a, P, K, V> AsRangeIterator<'a, P, K, V> for Storage {
+    type AsRangeIter = StorageIterator<K, V>;
+
+    fn iter_from(&'a self, _start: &K, _direction: IterDirection) -> Result<Self::AsRangeIter, Self::Error> {
+        Ok(StorageIterator::new())
+    }
+
+    fn iter_range(&'a self, _start: &K, _end: &K, _direction: IterDirection) -> Result<Self::AsRangeIter, Self::Error> {
+        Ok(StorageIterator::new())
+    }
+
+    fn iter_prefix(&'a self, _prefix: &P, _direction: IterDirection) -> Result<Self::AsRangeIter, Self::Error> {
+        Ok(StorageIterator::new())
+    }
+}
@@ -7,13 +7,16 @@ use crate::{
 };
 
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::access::{Batch, BatchBuilder};
@@ -247,20 +250,22 @@ impl Batch<Unspent, ()> for Storage {
     }
 }
 
-impl Batch<(Ed25519Address, OutputId), ()> for Storage {
+impl Batch<((Address, AddressRole), OutputId), ()> for Storage {
     fn batch_insert(
         &self,
         batch: &mut Self::Batch,
-        (address, output_id): &(Ed25519Address, OutputId),
+        ((address, role), output_id): &((Address, AddressRole), OutputId),
         (): &(),
     ) -> Result<(), <Self as StorageBackend>::Error> {
         batch.key_buf.clear();
-        batch.key_buf.extend_from_slice(address.as_ref());
-        batch.key_buf.extend_from_slice(&output_id.pack_to_vec());
+        // Packing to bytes can't fail.
+        address.pack(&mut batch.key_buf).unwrap();
+        role.pack(&mut batch.key_buf).unwrap();
+        output_id.pack(&mut batch.key_buf).unwrap();
 
         batch
             .inner
-            .put_cf(self.cf_handle(CF_ED25519_ADDRESS_TO_OUTPUT_ID)?, &batch.key_buf, []);
+            .put_cf(self.cf_handle(CF_ADDRESS_TO_OUTPUT_ID)?, &batch.key_buf, []);
 
         Ok(())
     }
@@ -268,15 +273,176 @@ impl Batch<(Ed25519Address, OutputId), ()> for Storage {
     fn batch_delete(
         &self,
         batch: &mut Self::Batch,
-        (address, output_id): &(Ed25519Address, OutputId),
+        ((address, role), output_id): &((Address, AddressRole), OutputId),
     ) -> Result<(), <Self as StorageBackend>::Error> {
         batch.key_buf.clear();
-        batch.key_buf.extend_from_slice(address.as_ref());
-        batch.key_buf.extend_from_slice(&output_id.pack_to_vec());
+        // Packing to bytes can't fail.
+        address.pack(&mut batch.key_buf).unwrap();
+        role.pack(&mut batch.key_buf).unwrap();
+        output_id.pack(&mut batch.key_buf).unwrap();
+
+        batch
+            .inner
+            .delete_cf(self.cf_handle(CF_ADDRESS_TO_OUTPUT_ID)?, &batch.key_buf);
+
+        Ok(())
+    }
+}
+
+impl Batch<(TagFeatureBlock, OutputId), ()> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        (tag, output_id): &(TagFeatureBlock, OutputId),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(tag.tag());
+        // Packing to bytes can't fail.
+        output_id.pack(&mut batch.key_buf).unwrap();
+
+        batch
+            .inner
+            .put_cf(self.cf_handle(CF_TAG_TO_OUTPUT_ID)?, &batch.key_buf, []);
+
+        Ok(())
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        (tag, output_id): &(TagFeatureBlock, OutputId),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(tag.tag());
+        // Packing to bytes can't fail.
+        output_id.pack(&mut batch.key_buf).unwrap();
+
+        batch
+            .inner
+            .delete_cf(self.cf_handle(CF_TAG_TO_OUTPUT_ID)?, &batch.key_buf);
+
+        Ok(())
+    }
+}
+
+impl Batch<(SenderFeatureBlock, OutputId), ()> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        (sender, output_id): &(SenderFeatureBlock, OutputId),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        // Packing to bytes can't fail.
+        sender.pack(&mut batch.key_buf).unwrap();
+        output_id.pack(&mut batch.key_buf).unwrap();
+
+        batch
+            .inner
+            .put_cf(self.cf_handle(CF_SENDER_TO_OUTPUT_ID)?, &batch.key_buf, []);
+
+        Ok(())
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        (sender, output_id): &(SenderFeatureBlock, OutputId),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        // Packing to bytes can't fail.
+        sender.pack(&mut batch.key_buf).unwrap();
+        output_id.pack(&mut batch.key_buf).unwrap();
+
+        batch
+            .inner
+            .delete_cf(self.cf_handle(CF_SENDER_TO_OUTPUT_ID)?, &batch.key_buf);
+
+        Ok(())
+    }
+}
+
+impl Batch<AliasId, OutputId> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        alias_id: &AliasId,
+        output_id: &OutputId,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.value_buf.clear();
+        // Packing to bytes can't fail.
+        output_id.pack(&mut batch.value_buf).unwrap();
+
+        batch
+            .inner
+            .put_cf(self.cf_handle(CF_ALIAS_ID_TO_OUTPUT_ID)?, alias_id, &batch.value_buf);
 
+        Ok(())
+    }
+
+    fn batch_delete(&self, batch: &mut Self::Batch, alias_id: &AliasId) -> Result<(), <Self as StorageBackend>::Error> {
+        batch
+            .inner
+            .delete_cf(self.cf_handle(CF_ALIAS_ID_TO_OUTPUT_ID)?, alias_id);
+
+        Ok(())
+    }
+}
+
+impl Batch<NftId, OutputId> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        nft_id: &NftId,
+        output_id: &OutputId,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.value_buf.clear();
+        // Packing to bytes can't fail.
+        output_id.pack(&mut batch.value_buf).unwrap();
+
+        batch
+            .inner
+            .put_cf(self.cf_handle(CF_NFT_ID_TO_OUTPUT_ID)?, nft_id, &batch.value_buf);
+
+        Ok(())
+    }
+
+    fn batch_delete(&self, batch: &mut Self::Batch, nft_id: &NftId) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.inner.delete_cf(self.cf_handle(CF_NFT_ID_TO_OUTPUT_ID)?, nft_id);
+
+        Ok(())
+    }
+}
+
+impl Batch<FoundryId, OutputId> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        foundry_id: &FoundryId,
+        output_id: &OutputId,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.value_buf.clear();
+        // Packing to bytes can't fail.
+        output_id.pack(&mut batch.value_buf).unwrap();
+
+        batch.inner.put_cf(
+            self.cf_handle(CF_FOUNDRY_ID_TO_OUTPUT_ID)?,
+            foundry_id,
+            &batch.value_buf,
+        );
+
+        Ok(())
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        foundry_id: &FoundryId,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
         batch
             .inner
-            .delete_cf(self.cf_handle(CF_ED25519_ADDRESS_TO_OUTPUT_ID)?, &batch.key_buf);
+            .delete_cf(self.cf_handle(CF_FOUNDRY_ID_TO_OUTPUT_ID)?, foundry_id);
 
         Ok(())
     }
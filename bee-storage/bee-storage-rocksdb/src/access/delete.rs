@@ -7,13 +7,16 @@ use crate::{
 };
 
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::access::Delete;
@@ -82,13 +85,67 @@ impl Delete<Unspent, ()> for Storage {
     }
 }
 
-impl Delete<(Ed25519Address, OutputId), ()> for Storage {
-    fn delete(&self, (address, output_id): &(Ed25519Address, OutputId)) -> Result<(), <Self as StorageBackend>::Error> {
-        let mut key = address.as_ref().to_vec();
+impl Delete<((Address, AddressRole), OutputId), ()> for Storage {
+    fn delete(
+        &self,
+        ((address, role), output_id): &((Address, AddressRole), OutputId),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = address.pack_to_vec();
+        key.extend_from_slice(&role.pack_to_vec());
+        key.extend_from_slice(&output_id.pack_to_vec());
+
+        self.inner.delete_cf(self.cf_handle(CF_ADDRESS_TO_OUTPUT_ID)?, key)?;
+
+        Ok(())
+    }
+}
+
+impl Delete<(TagFeatureBlock, OutputId), ()> for Storage {
+    fn delete(&self, (tag, output_id): &(TagFeatureBlock, OutputId)) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = tag.tag().to_vec();
+        key.extend_from_slice(&output_id.pack_to_vec());
+
+        self.inner.delete_cf(self.cf_handle(CF_TAG_TO_OUTPUT_ID)?, key)?;
+
+        Ok(())
+    }
+}
+
+impl Delete<(SenderFeatureBlock, OutputId), ()> for Storage {
+    fn delete(
+        &self,
+        (sender, output_id): &(SenderFeatureBlock, OutputId),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = sender.pack_to_vec();
         key.extend_from_slice(&output_id.pack_to_vec());
 
+        self.inner.delete_cf(self.cf_handle(CF_SENDER_TO_OUTPUT_ID)?, key)?;
+
+        Ok(())
+    }
+}
+
+impl Delete<AliasId, OutputId> for Storage {
+    fn delete(&self, alias_id: &AliasId) -> Result<(), <Self as StorageBackend>::Error> {
+        self.inner
+            .delete_cf(self.cf_handle(CF_ALIAS_ID_TO_OUTPUT_ID)?, alias_id)?;
+
+        Ok(())
+    }
+}
+
+impl Delete<NftId, OutputId> for Storage {
+    fn delete(&self, nft_id: &NftId) -> Result<(), <Self as StorageBackend>::Error> {
+        self.inner.delete_cf(self.cf_handle(CF_NFT_ID_TO_OUTPUT_ID)?, nft_id)?;
+
+        Ok(())
+    }
+}
+
+impl Delete<FoundryId, OutputId> for Storage {
+    fn delete(&self, foundry_id: &FoundryId) -> Result<(), <Self as StorageBackend>::Error> {
         self.inner
-            .delete_cf(self.cf_handle(CF_ED25519_ADDRESS_TO_OUTPUT_ID)?, key)?;
+            .delete_cf(self.cf_handle(CF_FOUNDRY_ID_TO_OUTPUT_ID)?, foundry_id)?;
 
         Ok(())
     }
@@ -7,13 +7,16 @@ use crate::{
 };
 
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::access::Exist;
@@ -83,17 +86,69 @@ impl Exist<Unspent, ()> for Storage {
     }
 }
 
-impl Exist<(Ed25519Address, OutputId), ()> for Storage {
+impl Exist<((Address, AddressRole), OutputId), ()> for Storage {
     fn exist(
         &self,
-        (address, output_id): &(Ed25519Address, OutputId),
+        ((address, role), output_id): &((Address, AddressRole), OutputId),
     ) -> Result<bool, <Self as StorageBackend>::Error> {
-        let mut key = address.as_ref().to_vec();
+        let mut key = address.pack_to_vec();
+        key.extend_from_slice(&role.pack_to_vec());
         key.extend_from_slice(&output_id.pack_to_vec());
 
         Ok(self
             .inner
-            .get_cf(self.cf_handle(CF_ED25519_ADDRESS_TO_OUTPUT_ID)?, key)?
+            .get_cf(self.cf_handle(CF_ADDRESS_TO_OUTPUT_ID)?, key)?
+            .is_some())
+    }
+}
+
+impl Exist<(TagFeatureBlock, OutputId), ()> for Storage {
+    fn exist(&self, (tag, output_id): &(TagFeatureBlock, OutputId)) -> Result<bool, <Self as StorageBackend>::Error> {
+        let mut key = tag.tag().to_vec();
+        key.extend_from_slice(&output_id.pack_to_vec());
+
+        Ok(self.inner.get_cf(self.cf_handle(CF_TAG_TO_OUTPUT_ID)?, key)?.is_some())
+    }
+}
+
+impl Exist<(SenderFeatureBlock, OutputId), ()> for Storage {
+    fn exist(
+        &self,
+        (sender, output_id): &(SenderFeatureBlock, OutputId),
+    ) -> Result<bool, <Self as StorageBackend>::Error> {
+        let mut key = sender.pack_to_vec();
+        key.extend_from_slice(&output_id.pack_to_vec());
+
+        Ok(self
+            .inner
+            .get_cf(self.cf_handle(CF_SENDER_TO_OUTPUT_ID)?, key)?
+            .is_some())
+    }
+}
+
+impl Exist<AliasId, OutputId> for Storage {
+    fn exist(&self, alias_id: &AliasId) -> Result<bool, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_cf(self.cf_handle(CF_ALIAS_ID_TO_OUTPUT_ID)?, alias_id)?
+            .is_some())
+    }
+}
+
+impl Exist<NftId, OutputId> for Storage {
+    fn exist(&self, nft_id: &NftId) -> Result<bool, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_cf(self.cf_handle(CF_NFT_ID_TO_OUTPUT_ID)?, nft_id)?
+            .is_some())
+    }
+}
+
+impl Exist<FoundryId, OutputId> for Storage {
+    fn exist(&self, foundry_id: &FoundryId) -> Result<bool, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_cf(self.cf_handle(CF_FOUNDRY_ID_TO_OUTPUT_ID)?, foundry_id)?
             .is_some())
     }
 }
@@ -7,12 +7,16 @@ use crate::{
 };
 
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
+    snapshot::info::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::{access::Fetch, system::System};
@@ -21,6 +25,7 @@ use bee_tangle::{
 };
 
 use packable::PackableExt;
+use rocksdb::{Direction, IteratorMode};
 
 impl Fetch<u8, System> for Storage {
     fn fetch(&self, key: &u8) -> Result<Option<System>, <Self as StorageBackend>::Error> {
@@ -57,6 +62,7 @@ impl Fetch<MessageId, Vec<MessageId>> for Storage {
         Ok(Some(
             self.inner
                 .prefix_iterator_cf(self.cf_handle(CF_MESSAGE_ID_TO_MESSAGE_ID)?, parent)
+                .take_while(|(key, _)| key.starts_with(parent.as_ref()))
                 .map(|(key, _)| {
                     let (_, child) = key.split_at(MessageId::LENGTH);
                     // Unpacking from storage is fine.
@@ -92,13 +98,20 @@ impl Fetch<OutputId, ConsumedOutput> for Storage {
     }
 }
 
-impl Fetch<Ed25519Address, Vec<OutputId>> for Storage {
-    fn fetch(&self, address: &Ed25519Address) -> Result<Option<Vec<OutputId>>, <Self as StorageBackend>::Error> {
+impl Fetch<(Address, AddressRole), Vec<OutputId>> for Storage {
+    fn fetch(
+        &self,
+        (address, role): &(Address, AddressRole),
+    ) -> Result<Option<Vec<OutputId>>, <Self as StorageBackend>::Error> {
+        let mut prefix = address.pack_to_vec();
+        prefix.extend_from_slice(&role.pack_to_vec());
+
         Ok(Some(
             self.inner
-                .prefix_iterator_cf(self.cf_handle(CF_ED25519_ADDRESS_TO_OUTPUT_ID)?, address)
+                .prefix_iterator_cf(self.cf_handle(CF_ADDRESS_TO_OUTPUT_ID)?, &prefix)
+                .take_while(|(key, _)| key.starts_with(&prefix))
                 .map(|(key, _)| {
-                    let (_, output_id) = key.split_at(Ed25519Address::LENGTH);
+                    let (_, output_id) = key.split_at(prefix.len());
                     // Unpacking from storage is fine.
                     TryFrom::<[u8; OutputId::LENGTH]>::try_from(output_id.try_into().unwrap()).unwrap()
                 })
@@ -108,6 +121,83 @@ impl Fetch<Ed25519Address, Vec<OutputId>> for Storage {
     }
 }
 
+impl Fetch<TagFeatureBlock, Vec<OutputId>> for Storage {
+    fn fetch(&self, tag: &TagFeatureBlock) -> Result<Option<Vec<OutputId>>, <Self as StorageBackend>::Error> {
+        let prefix = tag.tag();
+
+        Ok(Some(
+            self.inner
+                .iterator_cf(
+                    self.cf_handle(CF_TAG_TO_OUTPUT_ID)?,
+                    IteratorMode::From(prefix, Direction::Forward),
+                )
+                .take_while(|(key, _)| key.starts_with(prefix))
+                // Tags are stored without their length, so the keys of the longer tags starting with this one are
+                // skipped.
+                .filter(|(key, _)| key.len() == prefix.len() + OutputId::LENGTH)
+                .map(|(key, _)| {
+                    let (_, output_id) = key.split_at(key.len() - OutputId::LENGTH);
+                    // Unpacking from storage is fine.
+                    TryFrom::<[u8; OutputId::LENGTH]>::try_from(output_id.try_into().unwrap()).unwrap()
+                })
+                .take(self.config.fetch_output_id_limit)
+                .collect(),
+        ))
+    }
+}
+
+impl Fetch<SenderFeatureBlock, Vec<OutputId>> for Storage {
+    fn fetch(&self, sender: &SenderFeatureBlock) -> Result<Option<Vec<OutputId>>, <Self as StorageBackend>::Error> {
+        let prefix = sender.pack_to_vec();
+
+        Ok(Some(
+            self.inner
+                .iterator_cf(
+                    self.cf_handle(CF_SENDER_TO_OUTPUT_ID)?,
+                    IteratorMode::From(&prefix, Direction::Forward),
+                )
+                .take_while(|(key, _)| key.starts_with(&prefix))
+                .map(|(key, _)| {
+                    let (_, output_id) = key.split_at(key.len() - OutputId::LENGTH);
+                    // Unpacking from storage is fine.
+                    TryFrom::<[u8; OutputId::LENGTH]>::try_from(output_id.try_into().unwrap()).unwrap()
+                })
+                .take(self.config.fetch_output_id_limit)
+                .collect(),
+        ))
+    }
+}
+
+impl Fetch<AliasId, OutputId> for Storage {
+    fn fetch(&self, alias_id: &AliasId) -> Result<Option<OutputId>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_cf(self.cf_handle(CF_ALIAS_ID_TO_OUTPUT_ID)?, alias_id)?
+            // Unpacking from storage is fine.
+            .map(|v| OutputId::unpack_unverified(&mut v.as_slice()).unwrap()))
+    }
+}
+
+impl Fetch<NftId, OutputId> for Storage {
+    fn fetch(&self, nft_id: &NftId) -> Result<Option<OutputId>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_cf(self.cf_handle(CF_NFT_ID_TO_OUTPUT_ID)?, nft_id)?
+            // Unpacking from storage is fine.
+            .map(|v| OutputId::unpack_unverified(&mut v.as_slice()).unwrap()))
+    }
+}
+
+impl Fetch<FoundryId, OutputId> for Storage {
+    fn fetch(&self, foundry_id: &FoundryId) -> Result<Option<OutputId>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_cf(self.cf_handle(CF_FOUNDRY_ID_TO_OUTPUT_ID)?, foundry_id)?
+            // Unpacking from storage is fine.
+            .map(|v| OutputId::unpack_unverified(&mut v.as_slice()).unwrap()))
+    }
+}
+
 impl Fetch<(), LedgerIndex> for Storage {
     fn fetch(&self, (): &()) -> Result<Option<LedgerIndex>, <Self as StorageBackend>::Error> {
         Ok(self
@@ -163,12 +253,12 @@ impl Fetch<MilestoneIndex, Vec<UnreferencedMessage>> for Storage {
         &self,
         index: &MilestoneIndex,
     ) -> Result<Option<Vec<UnreferencedMessage>>, <Self as StorageBackend>::Error> {
+        let prefix = index.pack_to_vec();
+
         Ok(Some(
             self.inner
-                .prefix_iterator_cf(
-                    self.cf_handle(CF_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE)?,
-                    index.pack_to_vec(),
-                )
+                .prefix_iterator_cf(self.cf_handle(CF_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE)?, &prefix)
+                .take_while(|(key, _)| key.starts_with(&prefix))
                 .map(|(key, _)| {
                     let (_, unreferenced_message) = key.split_at(std::mem::size_of::<MilestoneIndex>());
                     // Unpacking from storage is fine.
@@ -182,9 +272,12 @@ impl Fetch<MilestoneIndex, Vec<UnreferencedMessage>> for Storage {
 
 impl Fetch<MilestoneIndex, Vec<Receipt>> for Storage {
     fn fetch(&self, index: &MilestoneIndex) -> Result<Option<Vec<Receipt>>, <Self as StorageBackend>::Error> {
+        let prefix = index.pack_to_vec();
+
         Ok(Some(
             self.inner
-                .prefix_iterator_cf(self.cf_handle(CF_MILESTONE_INDEX_TO_RECEIPT)?, index.pack_to_vec())
+                .prefix_iterator_cf(self.cf_handle(CF_MILESTONE_INDEX_TO_RECEIPT)?, &prefix)
+                .take_while(|(key, _)| key.starts_with(&prefix))
                 .map(|(mut key, _)| {
                     let (_, receipt) = key.split_at_mut(std::mem::size_of::<MilestoneIndex>());
                     // Unpacking from storage is fine.
@@ -198,9 +291,12 @@ impl Fetch<MilestoneIndex, Vec<Receipt>> for Storage {
 
 impl Fetch<bool, Vec<TreasuryOutput>> for Storage {
     fn fetch(&self, spent: &bool) -> Result<Option<Vec<TreasuryOutput>>, <Self as StorageBackend>::Error> {
+        let prefix = spent.pack_to_vec();
+
         Ok(Some(
             self.inner
-                .prefix_iterator_cf(self.cf_handle(CF_SPENT_TO_TREASURY_OUTPUT)?, spent.pack_to_vec())
+                .prefix_iterator_cf(self.cf_handle(CF_SPENT_TO_TREASURY_OUTPUT)?, &prefix)
+                .take_while(|(key, _)| key.starts_with(&prefix))
                 .map(|(mut key, _)| {
                     let (_, output) = key.split_at_mut(std::mem::size_of::<bool>());
                     // Unpacking from storage is fine.
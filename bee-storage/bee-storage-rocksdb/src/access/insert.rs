@@ -7,13 +7,16 @@ use crate::{
 };
 
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::{access::Insert, system::System};
@@ -105,17 +108,80 @@ impl Insert<Unspent, ()> for Storage {
     }
 }
 
-impl Insert<(Ed25519Address, OutputId), ()> for Storage {
+impl Insert<((Address, AddressRole), OutputId), ()> for Storage {
     fn insert(
         &self,
-        (address, output_id): &(Ed25519Address, OutputId),
+        ((address, role), output_id): &((Address, AddressRole), OutputId),
         (): &(),
     ) -> Result<(), <Self as StorageBackend>::Error> {
-        let mut key = address.as_ref().to_vec();
+        let mut key = address.pack_to_vec();
+        key.extend_from_slice(&role.pack_to_vec());
         key.extend_from_slice(&output_id.pack_to_vec());
 
+        self.inner.put_cf(self.cf_handle(CF_ADDRESS_TO_OUTPUT_ID)?, key, [])?;
+
+        Ok(())
+    }
+}
+
+impl Insert<(TagFeatureBlock, OutputId), ()> for Storage {
+    fn insert(
+        &self,
+        (tag, output_id): &(TagFeatureBlock, OutputId),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = tag.tag().to_vec();
+        key.extend_from_slice(&output_id.pack_to_vec());
+
+        self.inner.put_cf(self.cf_handle(CF_TAG_TO_OUTPUT_ID)?, key, [])?;
+
+        Ok(())
+    }
+}
+
+impl Insert<(SenderFeatureBlock, OutputId), ()> for Storage {
+    fn insert(
+        &self,
+        (sender, output_id): &(SenderFeatureBlock, OutputId),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = sender.pack_to_vec();
+        key.extend_from_slice(&output_id.pack_to_vec());
+
+        self.inner.put_cf(self.cf_handle(CF_SENDER_TO_OUTPUT_ID)?, key, [])?;
+
+        Ok(())
+    }
+}
+
+impl Insert<AliasId, OutputId> for Storage {
+    fn insert(&self, alias_id: &AliasId, output_id: &OutputId) -> Result<(), <Self as StorageBackend>::Error> {
+        self.inner.put_cf(
+            self.cf_handle(CF_ALIAS_ID_TO_OUTPUT_ID)?,
+            alias_id,
+            output_id.pack_to_vec(),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl Insert<NftId, OutputId> for Storage {
+    fn insert(&self, nft_id: &NftId, output_id: &OutputId) -> Result<(), <Self as StorageBackend>::Error> {
         self.inner
-            .put_cf(self.cf_handle(CF_ED25519_ADDRESS_TO_OUTPUT_ID)?, key, [])?;
+            .put_cf(self.cf_handle(CF_NFT_ID_TO_OUTPUT_ID)?, nft_id, output_id.pack_to_vec())?;
+
+        Ok(())
+    }
+}
+
+impl Insert<FoundryId, OutputId> for Storage {
+    fn insert(&self, foundry_id: &FoundryId, output_id: &OutputId) -> Result<(), <Self as StorageBackend>::Error> {
+        self.inner.put_cf(
+            self.cf_handle(CF_FOUNDRY_ID_TO_OUTPUT_ID)?,
+            foundry_id,
+            output_id.pack_to_vec(),
+        )?;
 
         Ok(())
     }
@@ -7,21 +7,28 @@ use crate::{
 };
 
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
-use bee_storage::{access::AsIterator, system::System};
+use bee_storage::{
+    access::{AsIterator, AsRangeIterator, IterDirection},
+    system::System,
+};
 use bee_tangle::{
     metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
 };
 
 use packable::PackableExt;
-use rocksdb::{DBIterator, IteratorMode};
+use rocksdb::{DBIterator, IteratorMode, ReadOptions};
 
 use std::marker::PhantomData;
 
@@ -39,6 +46,99 @@ impl<'a, K, V> StorageIterator<'a, K, V> {
     }
 }
 
+/// Returns the smallest key that is greater than all the keys starting with the given prefix, if any.
+fn prefix_upper_bound(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut upper = prefix.to_vec();
+
+    while let Some(last) = upper.pop() {
+        if last < u8::MAX {
+            upper.push(last + 1);
+            return Some(upper);
+        }
+    }
+
+    None
+}
+
+impl Storage {
+    fn range_iterator<K, V>(
+        &self,
+        cf: &'static str,
+        lower: Option<Vec<u8>>,
+        upper: Option<Vec<u8>>,
+        direction: IterDirection,
+    ) -> Result<StorageIterator<'_, K, V>, <Self as StorageBackend>::Error> {
+        let mut options = ReadOptions::default();
+
+        // Prefix extractors of column families must not restrict the iteration.
+        options.set_total_order_seek(true);
+
+        if let Some(lower) = lower {
+            options.set_iterate_lower_bound(lower);
+        }
+        if let Some(upper) = upper {
+            options.set_iterate_upper_bound(upper);
+        }
+
+        let mode = match direction {
+            IterDirection::Forward => IteratorMode::Start,
+            IterDirection::Reverse => IteratorMode::End,
+        };
+
+        Ok(StorageIterator::new(self.inner.iterator_cf_opt(
+            self.cf_handle(cf)?,
+            options,
+            mode,
+        )))
+    }
+}
+
+macro_rules! impl_range_iter {
+    ($prefix:ty, $key:ty, $value:ty, $cf:expr, $pack_prefix:expr, $pack_key:expr) => {
+        impl<'a> AsRangeIterator<'a, $prefix, $key, $value> for Storage {
+            type AsRangeIter = StorageIterator<'a, $key, $value>;
+
+            fn iter_from(
+                &'a self,
+                start: &$key,
+                direction: IterDirection,
+            ) -> Result<Self::AsRangeIter, <Self as StorageBackend>::Error> {
+                let start: Vec<u8> = $pack_key(start);
+
+                match direction {
+                    IterDirection::Forward => self.range_iterator($cf, Some(start), None, direction),
+                    IterDirection::Reverse => {
+                        // The immediate successor of the start key, to include it.
+                        let mut upper = start;
+                        upper.push(0x00);
+                        self.range_iterator($cf, None, Some(upper), direction)
+                    }
+                }
+            }
+
+            fn iter_range(
+                &'a self,
+                start: &$key,
+                end: &$key,
+                direction: IterDirection,
+            ) -> Result<Self::AsRangeIter, <Self as StorageBackend>::Error> {
+                self.range_iterator($cf, Some($pack_key(start)), Some($pack_key(end)), direction)
+            }
+
+            fn iter_prefix(
+                &'a self,
+                prefix: &$prefix,
+                direction: IterDirection,
+            ) -> Result<Self::AsRangeIter, <Self as StorageBackend>::Error> {
+                let prefix: Vec<u8> = $pack_prefix(prefix);
+                let upper = prefix_upper_bound(&prefix);
+
+                self.range_iterator($cf, Some(prefix), upper, direction)
+            }
+        }
+    };
+}
+
 macro_rules! impl_iter {
     ($key:ty, $value:ty, $cf:expr) => {
         impl<'a> AsIterator<'a, $key, $value> for Storage {
@@ -153,14 +253,51 @@ impl<'a> StorageIterator<'a, Unspent, ()> {
     }
 }
 
-impl<'a> StorageIterator<'a, (Ed25519Address, OutputId), ()> {
-    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((Ed25519Address, OutputId), ()) {
-        let (mut address, mut output_id) = key.split_at(MessageId::LENGTH);
+impl<'a> StorageIterator<'a, ((Address, AddressRole), OutputId), ()> {
+    fn unpack_key_value(key: &[u8], _: &[u8]) -> (((Address, AddressRole), OutputId), ()) {
+        let (mut address, key) = key.split_at(key.len() - std::mem::size_of::<AddressRole>() - OutputId::LENGTH);
+        let (mut role, mut output_id) = key.split_at(std::mem::size_of::<AddressRole>());
+
+        (
+            (
+                (
+                    // Unpacking from storage is fine.
+                    Address::unpack_unverified(&mut address).unwrap(),
+                    // Unpacking from storage is fine.
+                    AddressRole::unpack_unverified(&mut role).unwrap(),
+                ),
+                // Unpacking from storage is fine.
+                OutputId::unpack_unverified(&mut output_id).unwrap(),
+            ),
+            (),
+        )
+    }
+}
+
+impl<'a> StorageIterator<'a, (TagFeatureBlock, OutputId), ()> {
+    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((TagFeatureBlock, OutputId), ()) {
+        let (tag, mut output_id) = key.split_at(key.len() - OutputId::LENGTH);
+
+        (
+            (
+                // Unpacking from storage is fine.
+                TagFeatureBlock::new(tag.to_vec()).unwrap(),
+                // Unpacking from storage is fine.
+                OutputId::unpack_unverified(&mut output_id).unwrap(),
+            ),
+            (),
+        )
+    }
+}
+
+impl<'a> StorageIterator<'a, (SenderFeatureBlock, OutputId), ()> {
+    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((SenderFeatureBlock, OutputId), ()) {
+        let (mut sender, mut output_id) = key.split_at(key.len() - OutputId::LENGTH);
 
         (
             (
                 // Unpacking from storage is fine.
-                Ed25519Address::unpack_unverified(&mut address).unwrap(),
+                SenderFeatureBlock::unpack_unverified(&mut sender).unwrap(),
                 // Unpacking from storage is fine.
                 OutputId::unpack_unverified(&mut output_id).unwrap(),
             ),
@@ -169,6 +306,39 @@ impl<'a> StorageIterator<'a, (Ed25519Address, OutputId), ()> {
     }
 }
 
+impl<'a> StorageIterator<'a, AliasId, OutputId> {
+    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (AliasId, OutputId) {
+        (
+            // Unpacking from storage is fine.
+            AliasId::unpack_unverified(&mut key).unwrap(),
+            // Unpacking from storage is fine.
+            OutputId::unpack_unverified(&mut value).unwrap(),
+        )
+    }
+}
+
+impl<'a> StorageIterator<'a, NftId, OutputId> {
+    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (NftId, OutputId) {
+        (
+            // Unpacking from storage is fine.
+            NftId::unpack_unverified(&mut key).unwrap(),
+            // Unpacking from storage is fine.
+            OutputId::unpack_unverified(&mut value).unwrap(),
+        )
+    }
+}
+
+impl<'a> StorageIterator<'a, FoundryId, OutputId> {
+    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (FoundryId, OutputId) {
+        (
+            // Unpacking from storage is fine.
+            FoundryId::unpack_unverified(&mut key).unwrap(),
+            // Unpacking from storage is fine.
+            OutputId::unpack_unverified(&mut value).unwrap(),
+        )
+    }
+}
+
 impl<'a> StorageIterator<'a, (), LedgerIndex> {
     fn unpack_key_value(_: &[u8], mut value: &[u8]) -> ((), LedgerIndex) {
         (
@@ -277,7 +447,12 @@ impl_iter!((MessageId, MessageId), (), CF_MESSAGE_ID_TO_MESSAGE_ID);
 impl_iter!(OutputId, CreatedOutput, CF_OUTPUT_ID_TO_CREATED_OUTPUT);
 impl_iter!(OutputId, ConsumedOutput, CF_OUTPUT_ID_TO_CONSUMED_OUTPUT);
 impl_iter!(Unspent, (), CF_OUTPUT_ID_UNSPENT);
-impl_iter!((Ed25519Address, OutputId), (), CF_ED25519_ADDRESS_TO_OUTPUT_ID);
+impl_iter!(((Address, AddressRole), OutputId), (), CF_ADDRESS_TO_OUTPUT_ID);
+impl_iter!((TagFeatureBlock, OutputId), (), CF_TAG_TO_OUTPUT_ID);
+impl_iter!((SenderFeatureBlock, OutputId), (), CF_SENDER_TO_OUTPUT_ID);
+impl_iter!(AliasId, OutputId, CF_ALIAS_ID_TO_OUTPUT_ID);
+impl_iter!(NftId, OutputId, CF_NFT_ID_TO_OUTPUT_ID);
+impl_iter!(FoundryId, OutputId, CF_FOUNDRY_ID_TO_OUTPUT_ID);
 impl_iter!((), LedgerIndex, CF_LEDGER_INDEX);
 impl_iter!(MilestoneIndex, Milestone, CF_MILESTONE_INDEX_TO_MILESTONE);
 impl_iter!((), SnapshotInfo, CF_SNAPSHOT_INFO);
@@ -290,3 +465,64 @@ impl_iter!(
 );
 impl_iter!((MilestoneIndex, Receipt), (), CF_MILESTONE_INDEX_TO_RECEIPT);
 impl_iter!((bool, TreasuryOutput), (), CF_SPENT_TO_TREASURY_OUTPUT);
+
+impl_range_iter!(
+    MessageId,
+    (MessageId, MessageId),
+    (),
+    CF_MESSAGE_ID_TO_MESSAGE_ID,
+    |parent: &MessageId| parent.as_ref().to_vec(),
+    |(parent, child): &(MessageId, MessageId)| [parent.as_ref(), child.as_ref()].concat()
+);
+impl_range_iter!(
+    (Address, AddressRole),
+    ((Address, AddressRole), OutputId),
+    (),
+    CF_ADDRESS_TO_OUTPUT_ID,
+    |(address, role): &(Address, AddressRole)| [address.pack_to_vec(), role.pack_to_vec()].concat(),
+    |((address, role), output_id): &((Address, AddressRole), OutputId)| {
+        [address.pack_to_vec(), role.pack_to_vec(), output_id.pack_to_vec()].concat()
+    }
+);
+impl_range_iter!(
+    TagFeatureBlock,
+    (TagFeatureBlock, OutputId),
+    (),
+    CF_TAG_TO_OUTPUT_ID,
+    |tag: &TagFeatureBlock| tag.tag().to_vec(),
+    |(tag, output_id): &(TagFeatureBlock, OutputId)| [tag.tag().to_vec(), output_id.pack_to_vec()].concat()
+);
+impl_range_iter!(
+    SenderFeatureBlock,
+    (SenderFeatureBlock, OutputId),
+    (),
+    CF_SENDER_TO_OUTPUT_ID,
+    |sender: &SenderFeatureBlock| sender.pack_to_vec(),
+    |(sender, output_id): &(SenderFeatureBlock, OutputId)| [sender.pack_to_vec(), output_id.pack_to_vec()].concat()
+);
+impl_range_iter!(
+    MilestoneIndex,
+    (MilestoneIndex, UnreferencedMessage),
+    (),
+    CF_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE,
+    |index: &MilestoneIndex| index.pack_to_vec(),
+    |(index, unreferenced_message): &(MilestoneIndex, UnreferencedMessage)| {
+        [index.pack_to_vec().as_slice(), unreferenced_message.as_ref()].concat()
+    }
+);
+impl_range_iter!(
+    MilestoneIndex,
+    (MilestoneIndex, Receipt),
+    (),
+    CF_MILESTONE_INDEX_TO_RECEIPT,
+    |index: &MilestoneIndex| index.pack_to_vec(),
+    |(index, receipt): &(MilestoneIndex, Receipt)| [index.pack_to_vec(), receipt.pack_to_vec()].concat()
+);
+impl_range_iter!(
+    bool,
+    (bool, TreasuryOutput),
+    (),
+    CF_SPENT_TO_TREASURY_OUTPUT,
+    |spent: &bool| spent.pack_to_vec(),
+    |(spent, output): &(bool, TreasuryOutput)| [spent.pack_to_vec(), output.pack_to_vec()].concat()
+);
@@ -9,7 +9,7 @@ use crate::{
 use bee_ledger::types::{ConsumedOutput, CreatedOutput, OutputDiff};
 use bee_message::{
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{AliasId, FoundryId, NftId, OutputId},
     Message, MessageId,
 };
 use bee_storage::{access::MultiFetch, system::System};
@@ -62,6 +62,9 @@ impl_multi_fetch!(MessageId, Message, CF_MESSAGE_ID_TO_MESSAGE);
 impl_multi_fetch!(MessageId, MessageMetadata, CF_MESSAGE_ID_TO_METADATA);
 impl_multi_fetch!(OutputId, CreatedOutput, CF_OUTPUT_ID_TO_CREATED_OUTPUT);
 impl_multi_fetch!(OutputId, ConsumedOutput, CF_OUTPUT_ID_TO_CONSUMED_OUTPUT);
+impl_multi_fetch!(AliasId, OutputId, CF_ALIAS_ID_TO_OUTPUT_ID);
+impl_multi_fetch!(NftId, OutputId, CF_NFT_ID_TO_OUTPUT_ID);
+impl_multi_fetch!(FoundryId, OutputId, CF_FOUNDRY_ID_TO_OUTPUT_ID);
 impl_multi_fetch!(MilestoneIndex, Milestone, CF_MILESTONE_INDEX_TO_MILESTONE);
 impl_multi_fetch!(SolidEntryPoint, MilestoneIndex, CF_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX);
 impl_multi_fetch!(MilestoneIndex, OutputDiff, CF_MILESTONE_INDEX_TO_OUTPUT_DIFF);
@@ -7,12 +7,16 @@ use crate::{
 };
 
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::access::Truncate;
@@ -68,7 +72,12 @@ impl_truncate!((MessageId, MessageId), (), CF_MESSAGE_ID_TO_MESSAGE_ID);
 impl_truncate!(OutputId, CreatedOutput, CF_OUTPUT_ID_TO_CREATED_OUTPUT);
 impl_truncate!(OutputId, ConsumedOutput, CF_OUTPUT_ID_TO_CONSUMED_OUTPUT);
 impl_truncate!(Unspent, (), CF_OUTPUT_ID_UNSPENT);
-impl_truncate!((Ed25519Address, OutputId), (), CF_ED25519_ADDRESS_TO_OUTPUT_ID);
+impl_truncate!(((Address, AddressRole), OutputId), (), CF_ADDRESS_TO_OUTPUT_ID);
+impl_truncate!((TagFeatureBlock, OutputId), (), CF_TAG_TO_OUTPUT_ID);
+impl_truncate!((SenderFeatureBlock, OutputId), (), CF_SENDER_TO_OUTPUT_ID);
+impl_truncate!(AliasId, OutputId, CF_ALIAS_ID_TO_OUTPUT_ID);
+impl_truncate!(NftId, OutputId, CF_NFT_ID_TO_OUTPUT_ID);
+impl_truncate!(FoundryId, OutputId, CF_FOUNDRY_ID_TO_OUTPUT_ID);
 impl_truncate!((), LedgerIndex, CF_LEDGER_INDEX);
 impl_truncate!(MilestoneIndex, Milestone, CF_MILESTONE_INDEX_TO_MILESTONE);
 impl_truncate!((), SnapshotInfo, CF_SNAPSHOT_INFO);
@@ -8,7 +8,12 @@ pub const CF_MESSAGE_ID_TO_MESSAGE_ID: &str = "message_id_to_message_id";
 pub const CF_OUTPUT_ID_TO_CREATED_OUTPUT: &str = "output_id_to_created_output";
 pub const CF_OUTPUT_ID_TO_CONSUMED_OUTPUT: &str = "output_id_to_consumed_output";
 pub const CF_OUTPUT_ID_UNSPENT: &str = "output_id_unspent";
-pub const CF_ED25519_ADDRESS_TO_OUTPUT_ID: &str = "ed25519_address_to_output_id";
+pub const CF_ADDRESS_TO_OUTPUT_ID: &str = "address_to_output_id";
+pub const CF_ALIAS_ID_TO_OUTPUT_ID: &str = "alias_id_to_output_id";
+pub const CF_NFT_ID_TO_OUTPUT_ID: &str = "nft_id_to_output_id";
+pub const CF_FOUNDRY_ID_TO_OUTPUT_ID: &str = "foundry_id_to_output_id";
+pub const CF_TAG_TO_OUTPUT_ID: &str = "tag_to_output_id";
+pub const CF_SENDER_TO_OUTPUT_ID: &str = "sender_to_output_id";
 pub const CF_LEDGER_INDEX: &str = "ledger_index";
 pub const CF_MILESTONE_INDEX_TO_MILESTONE: &str = "milestone_index_to_milestone";
 pub const CF_SNAPSHOT_INFO: &str = "snapshot_info";
@@ -17,3 +22,6 @@ pub const CF_MILESTONE_INDEX_TO_OUTPUT_DIFF: &str = "milestone_index_to_output_d
 pub const CF_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE: &str = "milestone_index_to_unreferenced_message";
 pub const CF_MILESTONE_INDEX_TO_RECEIPT: &str = "milestone_index_to_receipt";
 pub const CF_SPENT_TO_TREASURY_OUTPUT: &str = "spent_to_treasury_output";
+
+/// Legacy column family of the Ed25519 address index, only used to migrate older storages.
+pub const CF_ED25519_ADDRESS_TO_OUTPUT_ID: &str = "ed25519_address_to_output_id";
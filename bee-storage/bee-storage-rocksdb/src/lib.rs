@@ -7,4 +7,5 @@ pub mod compaction;
 pub mod compression;
 pub mod config;
 pub mod error;
+mod migration;
 pub mod storage;
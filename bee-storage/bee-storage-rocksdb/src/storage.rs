@@ -5,22 +5,47 @@ use super::{
     column_families::*,
     config::{RocksDbConfig, RocksDbConfigBuilder, StorageConfig},
     error::Error,
+    migration::migration_steps,
 };
 
 pub use bee_storage::{
     access::{Fetch, Insert},
-    backend::StorageBackend,
+    backend::{StorageBackend, StorageBackup},
     system::{StorageHealth, StorageVersion, System, SYSTEM_HEALTH_KEY, SYSTEM_VERSION_KEY},
 };
 
-use bee_message::{address::Ed25519Address, milestone::MilestoneIndex, MessageId};
+use bee_storage::migration::{migrate, Error as MigrationError};
+
+use bee_message::{
+    address::{AliasAddress, Ed25519Address, NftAddress},
+    milestone::MilestoneIndex,
+    MessageId,
+};
 
 use rocksdb::{
-    ColumnFamily, ColumnFamilyDescriptor, DBCompactionStyle, DBCompressionType, Env, FlushOptions, Options,
-    SliceTransform, DB,
+    checkpoint::Checkpoint, ColumnFamily, ColumnFamilyDescriptor, DBCompactionStyle, DBCompressionType, Env,
+    FlushOptions, Options, SliceTransform, DB,
 };
 
-pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion(9);
+use std::path::Path;
+
+pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion(11);
+
+/// Extracts the packed address and address role prefix of a key of the address to output id column family.
+///
+/// The whole key is returned if it is not a valid key of this column family, since this function is called by RocksDB
+/// and must not panic.
+fn address_role_prefix(key: &[u8]) -> &[u8] {
+    let address_len = match key.first() {
+        Some(&Ed25519Address::KIND) => Ed25519Address::LENGTH,
+        Some(&AliasAddress::KIND) => AliasAddress::LENGTH,
+        Some(&NftAddress::KIND) => NftAddress::LENGTH,
+        _ => return key,
+    };
+
+    // Address kind, address and address role.
+    key.get(..1 + address_len + 1).unwrap_or(key)
+}
 
 pub struct Storage {
     pub(crate) config: StorageConfig,
@@ -28,7 +53,8 @@ pub struct Storage {
 }
 
 impl Storage {
-    fn new(config: RocksDbConfig) -> Result<Self, Error> {
+    /// Opens the database without checking its version and health, as opposed to `StorageBackend::start`.
+    pub fn new(config: RocksDbConfig) -> Result<Self, Error> {
         let cf_system = ColumnFamilyDescriptor::new(CF_SYSTEM, Options::default());
 
         let cf_message_id_to_message = ColumnFamilyDescriptor::new(CF_MESSAGE_ID_TO_MESSAGE, Options::default());
@@ -48,8 +74,18 @@ impl Storage {
         let cf_output_id_unspent = ColumnFamilyDescriptor::new(CF_OUTPUT_ID_UNSPENT, Options::default());
 
         let mut options = Options::default();
-        options.set_prefix_extractor(SliceTransform::create_fixed_prefix(Ed25519Address::LENGTH));
-        let cf_ed25519_address_to_output_id = ColumnFamilyDescriptor::new(CF_ED25519_ADDRESS_TO_OUTPUT_ID, options);
+        options.set_prefix_extractor(SliceTransform::create("address_role", address_role_prefix, None));
+        let cf_address_to_output_id = ColumnFamilyDescriptor::new(CF_ADDRESS_TO_OUTPUT_ID, options);
+
+        let cf_alias_id_to_output_id = ColumnFamilyDescriptor::new(CF_ALIAS_ID_TO_OUTPUT_ID, Options::default());
+
+        let cf_nft_id_to_output_id = ColumnFamilyDescriptor::new(CF_NFT_ID_TO_OUTPUT_ID, Options::default());
+
+        let cf_foundry_id_to_output_id = ColumnFamilyDescriptor::new(CF_FOUNDRY_ID_TO_OUTPUT_ID, Options::default());
+
+        let cf_tag_to_output_id = ColumnFamilyDescriptor::new(CF_TAG_TO_OUTPUT_ID, Options::default());
+
+        let cf_sender_to_output_id = ColumnFamilyDescriptor::new(CF_SENDER_TO_OUTPUT_ID, Options::default());
 
         let cf_ledger_index = ColumnFamilyDescriptor::new(CF_LEDGER_INDEX, Options::default());
 
@@ -81,6 +117,59 @@ impl Storage {
         options.set_prefix_extractor(SliceTransform::create_fixed_prefix(std::mem::size_of::<bool>()));
         let cf_spent_to_treasury = ColumnFamilyDescriptor::new(CF_SPENT_TO_TREASURY_OUTPUT, options);
 
+        let opts = Self::db_options(&config)?;
+
+        let mut column_families = vec![
+            cf_system,
+            cf_message_id_to_message,
+            cf_message_id_to_metadata,
+            cf_message_id_to_message_id,
+            cf_output_id_to_created_output,
+            cf_output_id_to_consumed_output,
+            cf_output_id_unspent,
+            cf_address_to_output_id,
+            cf_alias_id_to_output_id,
+            cf_nft_id_to_output_id,
+            cf_foundry_id_to_output_id,
+            cf_tag_to_output_id,
+            cf_sender_to_output_id,
+            cf_ledger_index,
+            cf_milestone_index_to_milestone,
+            cf_snapshot_info,
+            cf_solid_entry_point_to_milestone_index,
+            cf_milestone_index_to_output_diff,
+            cf_milestone_index_to_unreferenced_message,
+            cf_milestone_index_to_receipt,
+            cf_spent_to_treasury,
+        ];
+
+        // The legacy column families of older storages need to be opened to be migrated.
+        if DB::list_cf(&opts, &config.path)
+            .unwrap_or_default()
+            .iter()
+            .any(|name| name == CF_ED25519_ADDRESS_TO_OUTPUT_ID)
+        {
+            column_families.push(ColumnFamilyDescriptor::new(
+                CF_ED25519_ADDRESS_TO_OUTPUT_ID,
+                Options::default(),
+            ));
+        }
+
+        let db = DB::open_cf_descriptors(&opts, config.path, column_families)?;
+
+        let mut flushopts = FlushOptions::new();
+        flushopts.set_wait(true);
+        db.flush_opt(&flushopts)?;
+        db.flush_cf_opt(db.cf_handle(CF_SYSTEM).unwrap(), &flushopts)?;
+
+        Ok(Storage {
+            config: config.storage,
+            inner: db,
+        })
+    }
+
+    /// Returns the database options from the given configuration.
+    fn db_options(config: &RocksDbConfig) -> Result<Options, Error> {
         let mut opts = Options::default();
         opts.create_if_missing(config.create_if_missing);
         opts.create_missing_column_families(config.create_missing_column_families);
@@ -112,33 +201,17 @@ impl Storage {
         env.set_high_priority_background_threads(config.env.set_high_priority_background_threads);
         opts.set_env(&env);
 
-        let db = DB::open_cf_descriptors(
-            &opts,
-            config.path,
-            vec![
-                cf_system,
-                cf_message_id_to_message,
-                cf_message_id_to_metadata,
-                cf_message_id_to_message_id,
-                cf_output_id_to_created_output,
-                cf_output_id_to_consumed_output,
-                cf_output_id_unspent,
-                cf_ed25519_address_to_output_id,
-                cf_ledger_index,
-                cf_milestone_index_to_milestone,
-                cf_snapshot_info,
-                cf_solid_entry_point_to_milestone_index,
-                cf_milestone_index_to_output_diff,
-                cf_milestone_index_to_unreferenced_message,
-                cf_milestone_index_to_receipt,
-                cf_spent_to_treasury,
-            ],
-        )?;
+        Ok(opts)
+    }
 
-        let mut flushopts = FlushOptions::new();
-        flushopts.set_wait(true);
-        db.flush_opt(&flushopts)?;
-        db.flush_cf_opt(db.cf_handle(CF_SYSTEM).unwrap(), &flushopts)?;
+    /// Opens the database in read-only mode, without checking nor updating its version and health.
+    ///
+    /// The column families are opened with their default options, which is fine since reads don't rely on their prefix
+    /// extractors.
+    pub fn open_read_only(config: RocksDbConfig) -> Result<Self, Error> {
+        let opts = Self::db_options(&config)?;
+        let column_families = DB::list_cf(&opts, &config.path)?;
+        let db = DB::open_cf_for_read_only(&opts, config.path, column_families, false)?;
 
         Ok(Storage {
             config: config.storage,
@@ -157,17 +230,7 @@ impl StorageBackend for Storage {
     type Error = Error;
 
     fn start(config: Self::Config) -> Result<Self, Self::Error> {
-        let storage = Self::new(config)?;
-
-        match Fetch::<u8, System>::fetch(&storage, &SYSTEM_VERSION_KEY)? {
-            Some(System::Version(version)) => {
-                if version != STORAGE_VERSION {
-                    return Err(Error::VersionMismatch(version, STORAGE_VERSION));
-                }
-            }
-            None => Insert::<u8, System>::insert(&storage, &SYSTEM_VERSION_KEY, &System::Version(STORAGE_VERSION))?,
-            _ => panic!("Another system value was inserted on the version key."),
-        }
+        let mut storage = Self::new(config)?;
 
         if let Some(health) = storage.get_health()? {
             if health != StorageHealth::Healthy {
@@ -175,6 +238,16 @@ impl StorageBackend for Storage {
             }
         }
 
+        match Fetch::<u8, System>::fetch(&storage, &SYSTEM_VERSION_KEY)? {
+            Some(System::Version(version)) => migrate(&mut storage, version, STORAGE_VERSION, &migration_steps())
+                .map_err(|e| match e {
+                    MigrationError::MissingStep(_) => Error::VersionMismatch(version, STORAGE_VERSION),
+                    MigrationError::Backend(e) => e,
+                })?,
+            None => Insert::<u8, System>::insert(&storage, &SYSTEM_VERSION_KEY, &System::Version(STORAGE_VERSION))?,
+            _ => panic!("Another system value was inserted on the version key."),
+        }
+
         storage.set_health(StorageHealth::Idle)?;
 
         Ok(storage)
@@ -204,3 +277,19 @@ impl StorageBackend for Storage {
         Insert::<u8, System>::insert(self, &SYSTEM_HEALTH_KEY, &System::Health(health))
     }
 }
+
+impl StorageBackup for Storage {
+    fn backup(&self, path: &Path) -> Result<(), Self::Error> {
+        // A checkpoint is a consistent point-in-time copy of the whole database, made of hard links where possible.
+        Checkpoint::new(&self.inner)?.create_checkpoint(path)?;
+
+        // The checkpoint holds the health of the running storage, it is reopened to be marked as healthy.
+        let backup = Self::new(
+            RocksDbConfigBuilder::default()
+                .with_path(path.to_string_lossy().into_owned())
+                .finish(),
+        )?;
+
+        backup.shutdown()
+    }
+}
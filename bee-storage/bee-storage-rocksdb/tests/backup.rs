@@ -0,0 +1,45 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::{Message, MessageId};
+use bee_storage::{
+    access::{Exist, Fetch, Insert},
+    backend::{StorageBackend, StorageBackup},
+    system::StorageHealth,
+};
+use bee_storage_rocksdb::{config::RocksDbConfigBuilder, storage::Storage};
+use bee_test::rand::message::{rand_message, rand_message_id};
+
+use std::path::Path;
+
+#[test]
+fn backup_running_storage() {
+    let path = String::from("./tests/database/backup_running_storage");
+    let backup_path = String::from("./tests/database/backup_running_storage_backup");
+    let _ = std::fs::remove_dir_all(&path);
+    let _ = std::fs::remove_dir_all(&backup_path);
+
+    let storage = Storage::start(RocksDbConfigBuilder::default().with_path(path.clone()).finish()).unwrap();
+
+    let (message_id, message) = (rand_message_id(), rand_message());
+    Insert::<MessageId, Message>::insert(&storage, &message_id, &message).unwrap();
+
+    storage.backup(Path::new(&backup_path)).unwrap();
+    assert_eq!(storage.get_health().unwrap(), Some(StorageHealth::Idle));
+    assert!(storage.backup(Path::new(&backup_path)).is_err());
+
+    storage.shutdown().unwrap();
+
+    let backup = Storage::start(RocksDbConfigBuilder::default().with_path(backup_path.clone()).finish()).unwrap();
+
+    assert!(Exist::<MessageId, Message>::exist(&backup, &message_id).unwrap());
+    assert_eq!(
+        Fetch::<MessageId, Message>::fetch(&backup, &message_id).unwrap(),
+        Some(message)
+    );
+
+    backup.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(&path);
+    let _ = std::fs::remove_dir_all(&backup_path);
+}
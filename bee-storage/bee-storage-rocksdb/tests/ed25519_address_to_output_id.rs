@@ -1,10 +0,0 @@
-// Copyright 2020-2021 IOTA Stiftung
-// SPDX-License-Identifier: Apache-2.0
-
-#[macro_use]
-mod access;
-
-impl_access_test!(
-    ed25519_address_to_output_id_access_rocksdb,
-    ed25519_address_to_output_id_access
-);
@@ -0,0 +1,177 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::types::{output_addresses, AddressRole, CreatedOutput, Unspent};
+use bee_message::{
+    address::Address,
+    output::{AliasId, FoundryId, NftId, Output, OutputId},
+};
+use bee_storage::{
+    access::{Exist, Fetch, Insert},
+    backend::StorageBackend,
+    system::{StorageHealth, StorageVersion, System, SYSTEM_VERSION_KEY},
+};
+use bee_storage_rocksdb::{config::RocksDbConfigBuilder, storage::Storage};
+use bee_test::rand::{
+    address::rand_ed25519_address,
+    message::rand_message_id,
+    milestone::rand_milestone_index,
+    number::rand_number,
+    output::{rand_alias_output, rand_foundry_output, rand_nft_output, rand_output_id},
+};
+
+use packable::PackableExt;
+use rocksdb::{Options, DB};
+
+const LEGACY_CF: &str = "ed25519_address_to_output_id";
+/// Column families that didn't exist yet in the baseline storage version.
+const NEW_CFS: [&str; 6] = [
+    "address_to_output_id",
+    "alias_id_to_output_id",
+    "nft_id_to_output_id",
+    "foundry_id_to_output_id",
+    "tag_to_output_id",
+    "sender_to_output_id",
+];
+
+fn insert_output(storage: &Storage, output: Output, unspent: bool) -> OutputId {
+    let output_id = rand_output_id();
+
+    Insert::<OutputId, CreatedOutput>::insert(
+        storage,
+        &output_id,
+        &CreatedOutput::new(rand_message_id(), rand_milestone_index(), rand_number(), output),
+    )
+    .unwrap();
+    if unspent {
+        Insert::<Unspent, ()>::insert(storage, &Unspent::new(output_id), &()).unwrap();
+    }
+
+    output_id
+}
+
+#[test]
+fn migrate_from_baseline() {
+    let path = String::from("./tests/database/migrate_from_baseline");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = RocksDbConfigBuilder::default().with_path((&path).into()).finish();
+
+    // Builds a storage at the baseline version, with a legacy Ed25519 address index and without the new indexes.
+    let storage = Storage::start(config.clone()).unwrap();
+    let alias_output = rand_alias_output();
+    let alias_output_id = insert_output(&storage, alias_output.clone().into(), true);
+    let nft_output = rand_nft_output();
+    let nft_output_id = insert_output(&storage, nft_output.clone().into(), true);
+    let foundry_output = rand_foundry_output();
+    let foundry_output_id = insert_output(&storage, foundry_output.clone().into(), true);
+    let spent_alias_output = rand_alias_output();
+    let spent_alias_output_id = insert_output(&storage, spent_alias_output.clone().into(), false);
+    Insert::<u8, System>::insert(&storage, &SYSTEM_VERSION_KEY, &System::Version(StorageVersion(9))).unwrap();
+    storage.shutdown().unwrap();
+
+    // The legacy index refers to outputs that are not unspent, they must not be migrated.
+    let address = rand_ed25519_address();
+    let output_ids = (0..10).map(|_| rand_output_id()).collect::<Vec<_>>();
+
+    {
+        let opts = Options::default();
+        let mut db = DB::open_cf(&opts, &path, DB::list_cf(&opts, &path).unwrap()).unwrap();
+
+        for cf in NEW_CFS {
+            db.drop_cf(cf).unwrap();
+        }
+
+        db.create_cf(LEGACY_CF, &opts).unwrap();
+        let cf = db.cf_handle(LEGACY_CF).unwrap();
+
+        for output_id in output_ids.iter() {
+            let mut key = address.as_ref().to_vec();
+            key.extend_from_slice(&output_id.pack_to_vec());
+            db.put_cf(cf, key, []).unwrap();
+        }
+
+        db.flush().unwrap();
+    }
+
+    let storage = Storage::start(config.clone()).unwrap();
+
+    assert_eq!(
+        Fetch::<u8, System>::fetch(&storage, &SYSTEM_VERSION_KEY).unwrap(),
+        Some(System::Version(StorageVersion(11)))
+    );
+    assert_eq!(storage.get_health().unwrap(), Some(StorageHealth::Idle));
+
+    assert_eq!(
+        Fetch::<AliasId, OutputId>::fetch(&storage, alias_output.alias_id()).unwrap(),
+        Some(alias_output_id)
+    );
+    assert_eq!(
+        Fetch::<NftId, OutputId>::fetch(&storage, nft_output.nft_id()).unwrap(),
+        Some(nft_output_id)
+    );
+    assert_eq!(
+        Fetch::<FoundryId, OutputId>::fetch(&storage, &foundry_output.id()).unwrap(),
+        Some(foundry_output_id)
+    );
+    assert_eq!(
+        Fetch::<AliasId, OutputId>::fetch(&storage, spent_alias_output.alias_id()).unwrap(),
+        None
+    );
+
+    for (output, output_id) in [
+        (Output::from(alias_output), alias_output_id),
+        (Output::from(nft_output), nft_output_id),
+        (Output::from(foundry_output), foundry_output_id),
+    ] {
+        for address in output_addresses(&output) {
+            assert!(Exist::<((Address, AddressRole), OutputId), ()>::exist(&storage, &(address, output_id)).unwrap());
+        }
+    }
+    for address in output_addresses(&Output::from(spent_alias_output)) {
+        assert!(
+            !Exist::<((Address, AddressRole), OutputId), ()>::exist(&storage, &(address, spent_alias_output_id))
+                .unwrap()
+        );
+    }
+    assert_eq!(
+        Fetch::<(Address, AddressRole), Vec<OutputId>>::fetch(
+            &storage,
+            &(Address::from(address), AddressRole::Address)
+        )
+        .unwrap(),
+        Some(Vec::new())
+    );
+
+    storage.shutdown().unwrap();
+
+    let cf_names = DB::list_cf(&Options::default(), &path).unwrap();
+    assert!(!cf_names.iter().any(|name| name == LEGACY_CF));
+    for cf in ["tag_to_output_id", "sender_to_output_id"] {
+        assert!(cf_names.iter().any(|name| name == cf));
+    }
+
+    let _ = std::fs::remove_dir_all(&path);
+}
+
+#[test]
+fn unknown_version_mismatch() {
+    let path = String::from("./tests/database/unknown_version_mismatch");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = RocksDbConfigBuilder::default().with_path((&path).into()).finish();
+
+    let storage = Storage::start(config.clone()).unwrap();
+    Insert::<u8, System>::insert(&storage, &SYSTEM_VERSION_KEY, &System::Version(StorageVersion(12))).unwrap();
+    storage.shutdown().unwrap();
+
+    assert!(matches!(
+        Storage::start(config),
+        Err(bee_storage_rocksdb::error::Error::VersionMismatch(
+            StorageVersion(12),
+            StorageVersion(11)
+        ))
+    ));
+
+    let _ = std::fs::remove_dir_all(&path);
+}
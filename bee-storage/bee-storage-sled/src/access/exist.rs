@@ -6,13 +6,16 @@
 use crate::{storage::Storage, trees::*};
 
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::{access::Exist, backend::StorageBackend};
@@ -76,18 +79,61 @@ impl Exist<Unspent, ()> for Storage {
     }
 }
 
-impl Exist<(Ed25519Address, OutputId), ()> for Storage {
+impl Exist<((Address, AddressRole), OutputId), ()> for Storage {
     fn exist(
         &self,
-        (address, output_id): &(Ed25519Address, OutputId),
+        ((address, role), output_id): &((Address, AddressRole), OutputId),
     ) -> Result<bool, <Self as StorageBackend>::Error> {
-        let mut key = address.as_ref().to_vec();
+        let mut key = address.pack_to_vec();
+        key.extend_from_slice(&role.pack_to_vec());
         key.extend_from_slice(&output_id.pack_to_vec());
 
+        Ok(self.inner.open_tree(TREE_ADDRESS_TO_OUTPUT_ID)?.contains_key(key)?)
+    }
+}
+
+impl Exist<(TagFeatureBlock, OutputId), ()> for Storage {
+    fn exist(&self, (tag, output_id): &(TagFeatureBlock, OutputId)) -> Result<bool, <Self as StorageBackend>::Error> {
+        let mut key = tag.tag().to_vec();
+        key.extend_from_slice(&output_id.pack_to_vec());
+
+        Ok(self.inner.open_tree(TREE_TAG_TO_OUTPUT_ID)?.contains_key(key)?)
+    }
+}
+
+impl Exist<(SenderFeatureBlock, OutputId), ()> for Storage {
+    fn exist(
+        &self,
+        (sender, output_id): &(SenderFeatureBlock, OutputId),
+    ) -> Result<bool, <Self as StorageBackend>::Error> {
+        let mut key = sender.pack_to_vec();
+        key.extend_from_slice(&output_id.pack_to_vec());
+
+        Ok(self.inner.open_tree(TREE_SENDER_TO_OUTPUT_ID)?.contains_key(key)?)
+    }
+}
+
+impl Exist<AliasId, OutputId> for Storage {
+    fn exist(&self, alias_id: &AliasId) -> Result<bool, <Self as StorageBackend>::Error> {
         Ok(self
             .inner
-            .open_tree(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)?
-            .contains_key(key)?)
+            .open_tree(TREE_ALIAS_ID_TO_OUTPUT_ID)?
+            .contains_key(alias_id)?)
+    }
+}
+
+impl Exist<NftId, OutputId> for Storage {
+    fn exist(&self, nft_id: &NftId) -> Result<bool, <Self as StorageBackend>::Error> {
+        Ok(self.inner.open_tree(TREE_NFT_ID_TO_OUTPUT_ID)?.contains_key(nft_id)?)
+    }
+}
+
+impl Exist<FoundryId, OutputId> for Storage {
+    fn exist(&self, foundry_id: &FoundryId) -> Result<bool, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .open_tree(TREE_FOUNDRY_ID_TO_OUTPUT_ID)?
+            .contains_key(foundry_id)?)
     }
 }
 
@@ -6,12 +6,16 @@
 use crate::{storage::Storage, trees::*};
 
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
+    snapshot::info::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::{access::Fetch, backend::StorageBackend, system::System};
@@ -94,15 +98,68 @@ impl Fetch<OutputId, ConsumedOutput> for Storage {
     }
 }
 
-impl Fetch<Ed25519Address, Vec<OutputId>> for Storage {
-    fn fetch(&self, address: &Ed25519Address) -> Result<Option<Vec<OutputId>>, <Self as StorageBackend>::Error> {
+impl Fetch<(Address, AddressRole), Vec<OutputId>> for Storage {
+    fn fetch(
+        &self,
+        (address, role): &(Address, AddressRole),
+    ) -> Result<Option<Vec<OutputId>>, <Self as StorageBackend>::Error> {
+        let mut prefix = address.pack_to_vec();
+        prefix.extend_from_slice(&role.pack_to_vec());
+
+        Ok(Some(
+            self.inner
+                .open_tree(TREE_ADDRESS_TO_OUTPUT_ID)?
+                .scan_prefix(&prefix)
+                .map(|result| {
+                    let (key, _) = result?;
+                    let (_, output_id) = key.split_at(prefix.len());
+                    // Unpacking from storage is fine.
+                    Ok((<[u8; OutputId::LENGTH]>::try_from(output_id).unwrap())
+                        .try_into()
+                        .unwrap())
+                })
+                .take(self.config.storage.fetch_output_id_limit)
+                .collect::<Result<Vec<OutputId>, Self::Error>>()?,
+        ))
+    }
+}
+
+impl Fetch<TagFeatureBlock, Vec<OutputId>> for Storage {
+    fn fetch(&self, tag: &TagFeatureBlock) -> Result<Option<Vec<OutputId>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            self.inner
+                .open_tree(TREE_TAG_TO_OUTPUT_ID)?
+                .scan_prefix(tag.tag())
+                // Tags are stored without their length, so the keys of the longer tags starting with this one are
+                // skipped.
+                .filter(|result| {
+                    result
+                        .as_ref()
+                        .map_or(true, |(key, _)| key.len() == tag.tag().len() + OutputId::LENGTH)
+                })
+                .map(|result| {
+                    let (key, _) = result?;
+                    let (_, output_id) = key.split_at(key.len() - OutputId::LENGTH);
+                    // Unpacking from storage is fine.
+                    Ok((<[u8; OutputId::LENGTH]>::try_from(output_id).unwrap())
+                        .try_into()
+                        .unwrap())
+                })
+                .take(self.config.storage.fetch_output_id_limit)
+                .collect::<Result<Vec<OutputId>, Self::Error>>()?,
+        ))
+    }
+}
+
+impl Fetch<SenderFeatureBlock, Vec<OutputId>> for Storage {
+    fn fetch(&self, sender: &SenderFeatureBlock) -> Result<Option<Vec<OutputId>>, <Self as StorageBackend>::Error> {
         Ok(Some(
             self.inner
-                .open_tree(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)?
-                .scan_prefix(address)
+                .open_tree(TREE_SENDER_TO_OUTPUT_ID)?
+                .scan_prefix(sender.pack_to_vec())
                 .map(|result| {
                     let (key, _) = result?;
-                    let (_, output_id) = key.split_at(Ed25519Address::LENGTH);
+                    let (_, output_id) = key.split_at(key.len() - OutputId::LENGTH);
                     // Unpacking from storage is fine.
                     Ok((<[u8; OutputId::LENGTH]>::try_from(output_id).unwrap())
                         .try_into()
@@ -114,6 +171,39 @@ impl Fetch<Ed25519Address, Vec<OutputId>> for Storage {
     }
 }
 
+impl Fetch<AliasId, OutputId> for Storage {
+    fn fetch(&self, alias_id: &AliasId) -> Result<Option<OutputId>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .open_tree(TREE_ALIAS_ID_TO_OUTPUT_ID)?
+            .get(alias_id)?
+            // Unpacking from storage is fine.
+            .map(|v| OutputId::unpack_unverified(&mut v.as_ref()).unwrap()))
+    }
+}
+
+impl Fetch<NftId, OutputId> for Storage {
+    fn fetch(&self, nft_id: &NftId) -> Result<Option<OutputId>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .open_tree(TREE_NFT_ID_TO_OUTPUT_ID)?
+            .get(nft_id)?
+            // Unpacking from storage is fine.
+            .map(|v| OutputId::unpack_unverified(&mut v.as_ref()).unwrap()))
+    }
+}
+
+impl Fetch<FoundryId, OutputId> for Storage {
+    fn fetch(&self, foundry_id: &FoundryId) -> Result<Option<OutputId>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .open_tree(TREE_FOUNDRY_ID_TO_OUTPUT_ID)?
+            .get(foundry_id)?
+            // Unpacking from storage is fine.
+            .map(|v| OutputId::unpack_unverified(&mut v.as_ref()).unwrap()))
+    }
+}
+
 impl Fetch<(), LedgerIndex> for Storage {
     fn fetch(&self, (): &()) -> Result<Option<LedgerIndex>, <Self as StorageBackend>::Error> {
         Ok(self
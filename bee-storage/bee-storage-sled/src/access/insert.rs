@@ -6,13 +6,16 @@
 use crate::{storage::Storage, trees::*};
 
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::{access::Insert, backend::StorageBackend, system::System};
@@ -95,18 +98,77 @@ impl Insert<Unspent, ()> for Storage {
     }
 }
 
-impl Insert<(Ed25519Address, OutputId), ()> for Storage {
+impl Insert<((Address, AddressRole), OutputId), ()> for Storage {
     fn insert(
         &self,
-        (address, output_id): &(Ed25519Address, OutputId),
+        ((address, role), output_id): &((Address, AddressRole), OutputId),
         (): &(),
     ) -> Result<(), <Self as StorageBackend>::Error> {
-        let mut key = address.as_ref().to_vec();
+        let mut key = address.pack_to_vec();
+        key.extend_from_slice(&role.pack_to_vec());
         key.extend_from_slice(&output_id.pack_to_vec());
 
+        self.inner.open_tree(TREE_ADDRESS_TO_OUTPUT_ID)?.insert(key, &[])?;
+
+        Ok(())
+    }
+}
+
+impl Insert<(TagFeatureBlock, OutputId), ()> for Storage {
+    fn insert(
+        &self,
+        (tag, output_id): &(TagFeatureBlock, OutputId),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = tag.tag().to_vec();
+        key.extend_from_slice(&output_id.pack_to_vec());
+
+        self.inner.open_tree(TREE_TAG_TO_OUTPUT_ID)?.insert(key, &[])?;
+
+        Ok(())
+    }
+}
+
+impl Insert<(SenderFeatureBlock, OutputId), ()> for Storage {
+    fn insert(
+        &self,
+        (sender, output_id): &(SenderFeatureBlock, OutputId),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = sender.pack_to_vec();
+        key.extend_from_slice(&output_id.pack_to_vec());
+
+        self.inner.open_tree(TREE_SENDER_TO_OUTPUT_ID)?.insert(key, &[])?;
+
+        Ok(())
+    }
+}
+
+impl Insert<AliasId, OutputId> for Storage {
+    fn insert(&self, alias_id: &AliasId, output_id: &OutputId) -> Result<(), <Self as StorageBackend>::Error> {
         self.inner
-            .open_tree(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)?
-            .insert(key, &[])?;
+            .open_tree(TREE_ALIAS_ID_TO_OUTPUT_ID)?
+            .insert(alias_id, output_id.pack_to_vec())?;
+
+        Ok(())
+    }
+}
+
+impl Insert<NftId, OutputId> for Storage {
+    fn insert(&self, nft_id: &NftId, output_id: &OutputId) -> Result<(), <Self as StorageBackend>::Error> {
+        self.inner
+            .open_tree(TREE_NFT_ID_TO_OUTPUT_ID)?
+            .insert(nft_id, output_id.pack_to_vec())?;
+
+        Ok(())
+    }
+}
+
+impl Insert<FoundryId, OutputId> for Storage {
+    fn insert(&self, foundry_id: &FoundryId, output_id: &OutputId) -> Result<(), <Self as StorageBackend>::Error> {
+        self.inner
+            .open_tree(TREE_FOUNDRY_ID_TO_OUTPUT_ID)?
+            .insert(foundry_id, output_id.pack_to_vec())?;
 
         Ok(())
     }
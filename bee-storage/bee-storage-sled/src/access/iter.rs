@@ -6,38 +6,105 @@
 use crate::{storage::Storage, trees::*};
 
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
-use bee_storage::{access::AsIterator, backend::StorageBackend, system::System};
+use bee_storage::{
+    access::{AsIterator, AsRangeIterator, IterDirection},
+    backend::StorageBackend,
+    system::System,
+};
 use bee_tangle::{
     metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
 };
 
 use packable::PackableExt;
 
-use std::marker::PhantomData;
+use std::{marker::PhantomData, ops::Bound};
 
 /// Type used to iterate a subtree.
 pub struct StorageIterator<'a, K, V> {
     inner: sled::Iter,
+    direction: IterDirection,
     marker: PhantomData<&'a (K, V)>,
 }
 
 impl<'a, K, V> StorageIterator<'a, K, V> {
     fn new(inner: sled::Iter) -> Self {
+        Self::with_direction(inner, IterDirection::Forward)
+    }
+
+    fn with_direction(inner: sled::Iter, direction: IterDirection) -> Self {
         StorageIterator::<K, V> {
             inner,
+            direction,
             marker: PhantomData,
         }
     }
 }
 
+macro_rules! impl_range_iter {
+    ($prefix:ty, $key:ty, $value:ty, $cf:expr, $pack_prefix:expr, $pack_key:expr) => {
+        impl<'a> AsRangeIterator<'a, $prefix, $key, $value> for Storage {
+            type AsRangeIter = StorageIterator<'a, $key, $value>;
+
+            fn iter_from(
+                &'a self,
+                start: &$key,
+                direction: IterDirection,
+            ) -> Result<Self::AsRangeIter, <Self as StorageBackend>::Error> {
+                let start: Vec<u8> = $pack_key(start);
+                let range = match direction {
+                    IterDirection::Forward => (Bound::Included(start), Bound::Unbounded),
+                    IterDirection::Reverse => (Bound::Unbounded, Bound::Included(start)),
+                };
+
+                Ok(StorageIterator::with_direction(
+                    self.inner.open_tree($cf)?.range(range),
+                    direction,
+                ))
+            }
+
+            fn iter_range(
+                &'a self,
+                start: &$key,
+                end: &$key,
+                direction: IterDirection,
+            ) -> Result<Self::AsRangeIter, <Self as StorageBackend>::Error> {
+                let start: Vec<u8> = $pack_key(start);
+                let end: Vec<u8> = $pack_key(end);
+
+                Ok(StorageIterator::with_direction(
+                    self.inner.open_tree($cf)?.range(start..end),
+                    direction,
+                ))
+            }
+
+            fn iter_prefix(
+                &'a self,
+                prefix: &$prefix,
+                direction: IterDirection,
+            ) -> Result<Self::AsRangeIter, <Self as StorageBackend>::Error> {
+                let prefix: Vec<u8> = $pack_prefix(prefix);
+
+                Ok(StorageIterator::with_direction(
+                    self.inner.open_tree($cf)?.scan_prefix(prefix),
+                    direction,
+                ))
+            }
+        }
+    };
+}
+
 macro_rules! impl_iter {
     ($key:ty, $value:ty, $cf:expr) => {
         impl<'a> AsIterator<'a, $key, $value> for Storage {
@@ -53,7 +120,12 @@ macro_rules! impl_iter {
             type Item = Result<($key, $value), <Storage as StorageBackend>::Error>;
 
             fn next(&mut self) -> Option<Self::Item> {
-                self.inner.next().map(|result| {
+                let next = match self.direction {
+                    IterDirection::Forward => self.inner.next(),
+                    IterDirection::Reverse => self.inner.next_back(),
+                };
+
+                next.map(|result| {
                     result
                         .map(|(key, value)| Self::unpack_key_value(&key, &value))
                         .map_err(From::from)
@@ -152,14 +224,35 @@ impl<'a> StorageIterator<'a, Unspent, ()> {
     }
 }
 
-impl<'a> StorageIterator<'a, (Ed25519Address, OutputId), ()> {
-    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((Ed25519Address, OutputId), ()) {
-        let (mut address, mut output_id) = key.split_at(MessageId::LENGTH);
+impl<'a> StorageIterator<'a, ((Address, AddressRole), OutputId), ()> {
+    fn unpack_key_value(key: &[u8], _: &[u8]) -> (((Address, AddressRole), OutputId), ()) {
+        let (mut address, key) = key.split_at(key.len() - std::mem::size_of::<AddressRole>() - OutputId::LENGTH);
+        let (mut role, mut output_id) = key.split_at(std::mem::size_of::<AddressRole>());
 
         (
             (
+                (
+                    // Unpacking from storage is fine.
+                    Address::unpack_unverified(&mut address).unwrap(),
+                    // Unpacking from storage is fine.
+                    AddressRole::unpack_unverified(&mut role).unwrap(),
+                ),
                 // Unpacking from storage is fine.
-                Ed25519Address::unpack_unverified(&mut address).unwrap(),
+                OutputId::unpack_unverified(&mut output_id).unwrap(),
+            ),
+            (),
+        )
+    }
+}
+
+impl<'a> StorageIterator<'a, (TagFeatureBlock, OutputId), ()> {
+    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((TagFeatureBlock, OutputId), ()) {
+        let (tag, mut output_id) = key.split_at(key.len() - OutputId::LENGTH);
+
+        (
+            (
+                // Unpacking from storage is fine.
+                TagFeatureBlock::new(tag.to_vec()).unwrap(),
                 // Unpacking from storage is fine.
                 OutputId::unpack_unverified(&mut output_id).unwrap(),
             ),
@@ -168,6 +261,55 @@ impl<'a> StorageIterator<'a, (Ed25519Address, OutputId), ()> {
     }
 }
 
+impl<'a> StorageIterator<'a, (SenderFeatureBlock, OutputId), ()> {
+    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((SenderFeatureBlock, OutputId), ()) {
+        let (mut sender, mut output_id) = key.split_at(key.len() - OutputId::LENGTH);
+
+        (
+            (
+                // Unpacking from storage is fine.
+                SenderFeatureBlock::unpack_unverified(&mut sender).unwrap(),
+                // Unpacking from storage is fine.
+                OutputId::unpack_unverified(&mut output_id).unwrap(),
+            ),
+            (),
+        )
+    }
+}
+
+impl<'a> StorageIterator<'a, AliasId, OutputId> {
+    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (AliasId, OutputId) {
+        (
+            // Unpacking from storage is fine.
+            AliasId::unpack_unverified(&mut key).unwrap(),
+            // Unpacking from storage is fine.
+            OutputId::unpack_unverified(&mut value).unwrap(),
+        )
+    }
+}
+
+impl<'a> StorageIterator<'a, NftId, OutputId> {
+    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (NftId, OutputId) {
+        (
+            // Unpacking from storage is fine.
+            NftId::unpack_unverified(&mut key).unwrap(),
+            // Unpacking from storage is fine.
+            OutputId::unpack_unverified(&mut value).unwrap(),
+        )
+    }
+}
+
+impl<'a> StorageIterator<'a, FoundryId, OutputId> {
+    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (FoundryId, OutputId) {
+        (
+            // Unpacking from storage is fine.
+            FoundryId::unpack_unverified(&mut key).unwrap(),
+            // Unpacking from storage is fine.
+            OutputId::unpack_unverified(&mut value).unwrap(),
+        )
+    }
+}
+
 impl<'a> StorageIterator<'a, (), LedgerIndex> {
     fn unpack_key_value(_: &[u8], mut value: &[u8]) -> ((), LedgerIndex) {
         (
@@ -296,7 +438,12 @@ impl_iter!((MessageId, MessageId), (), TREE_MESSAGE_ID_TO_MESSAGE_ID);
 impl_iter!(OutputId, CreatedOutput, TREE_OUTPUT_ID_TO_CREATED_OUTPUT);
 impl_iter!(OutputId, ConsumedOutput, TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT);
 impl_iter!(Unspent, (), TREE_OUTPUT_ID_UNSPENT);
-impl_iter!((Ed25519Address, OutputId), (), TREE_ED25519_ADDRESS_TO_OUTPUT_ID);
+impl_iter!(((Address, AddressRole), OutputId), (), TREE_ADDRESS_TO_OUTPUT_ID);
+impl_iter!((TagFeatureBlock, OutputId), (), TREE_TAG_TO_OUTPUT_ID);
+impl_iter!((SenderFeatureBlock, OutputId), (), TREE_SENDER_TO_OUTPUT_ID);
+impl_iter!(AliasId, OutputId, TREE_ALIAS_ID_TO_OUTPUT_ID);
+impl_iter!(NftId, OutputId, TREE_NFT_ID_TO_OUTPUT_ID);
+impl_iter!(FoundryId, OutputId, TREE_FOUNDRY_ID_TO_OUTPUT_ID);
 impl_iter!((), LedgerIndex, TREE_LEDGER_INDEX);
 impl_iter!(MilestoneIndex, Milestone, TREE_MILESTONE_INDEX_TO_MILESTONE);
 impl_iter!((), SnapshotInfo, TREE_SNAPSHOT_INFO);
@@ -313,3 +460,64 @@ impl_iter!(
 );
 impl_iter!((MilestoneIndex, Receipt), (), TREE_MILESTONE_INDEX_TO_RECEIPT);
 impl_iter!((bool, TreasuryOutput), (), TREE_SPENT_TO_TREASURY_OUTPUT);
+
+impl_range_iter!(
+    MessageId,
+    (MessageId, MessageId),
+    (),
+    TREE_MESSAGE_ID_TO_MESSAGE_ID,
+    |parent: &MessageId| parent.as_ref().to_vec(),
+    |(parent, child): &(MessageId, MessageId)| [parent.as_ref(), child.as_ref()].concat()
+);
+impl_range_iter!(
+    (Address, AddressRole),
+    ((Address, AddressRole), OutputId),
+    (),
+    TREE_ADDRESS_TO_OUTPUT_ID,
+    |(address, role): &(Address, AddressRole)| [address.pack_to_vec(), role.pack_to_vec()].concat(),
+    |((address, role), output_id): &((Address, AddressRole), OutputId)| {
+        [address.pack_to_vec(), role.pack_to_vec(), output_id.pack_to_vec()].concat()
+    }
+);
+impl_range_iter!(
+    TagFeatureBlock,
+    (TagFeatureBlock, OutputId),
+    (),
+    TREE_TAG_TO_OUTPUT_ID,
+    |tag: &TagFeatureBlock| tag.tag().to_vec(),
+    |(tag, output_id): &(TagFeatureBlock, OutputId)| [tag.tag().to_vec(), output_id.pack_to_vec()].concat()
+);
+impl_range_iter!(
+    SenderFeatureBlock,
+    (SenderFeatureBlock, OutputId),
+    (),
+    TREE_SENDER_TO_OUTPUT_ID,
+    |sender: &SenderFeatureBlock| sender.pack_to_vec(),
+    |(sender, output_id): &(SenderFeatureBlock, OutputId)| [sender.pack_to_vec(), output_id.pack_to_vec()].concat()
+);
+impl_range_iter!(
+    MilestoneIndex,
+    (MilestoneIndex, UnreferencedMessage),
+    (),
+    TREE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE,
+    |index: &MilestoneIndex| index.pack_to_vec(),
+    |(index, unreferenced_message): &(MilestoneIndex, UnreferencedMessage)| {
+        [index.pack_to_vec().as_slice(), unreferenced_message.as_ref()].concat()
+    }
+);
+impl_range_iter!(
+    MilestoneIndex,
+    (MilestoneIndex, Receipt),
+    (),
+    TREE_MILESTONE_INDEX_TO_RECEIPT,
+    |index: &MilestoneIndex| index.pack_to_vec(),
+    |(index, receipt): &(MilestoneIndex, Receipt)| [index.pack_to_vec(), receipt.pack_to_vec()].concat()
+);
+impl_range_iter!(
+    bool,
+    (bool, TreasuryOutput),
+    (),
+    TREE_SPENT_TO_TREASURY_OUTPUT,
+    |spent: &bool| spent.pack_to_vec(),
+    |(spent, output): &(bool, TreasuryOutput)| [spent.pack_to_vec(), output.pack_to_vec()].concat()
+);
@@ -8,7 +8,7 @@ use crate::{storage::Storage, trees::*};
 use bee_ledger::types::{ConsumedOutput, CreatedOutput, OutputDiff};
 use bee_message::{
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{AliasId, FoundryId, NftId, OutputId},
     Message, MessageId,
 };
 use bee_storage::{access::MultiFetch, backend::StorageBackend, system::System};
@@ -94,6 +94,9 @@ impl_multi_fetch!(MessageId, Message, TREE_MESSAGE_ID_TO_MESSAGE);
 impl_multi_fetch!(MessageId, MessageMetadata, TREE_MESSAGE_ID_TO_METADATA);
 impl_multi_fetch!(OutputId, CreatedOutput, TREE_OUTPUT_ID_TO_CREATED_OUTPUT);
 impl_multi_fetch!(OutputId, ConsumedOutput, TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT);
+impl_multi_fetch!(AliasId, OutputId, TREE_ALIAS_ID_TO_OUTPUT_ID);
+impl_multi_fetch!(NftId, OutputId, TREE_NFT_ID_TO_OUTPUT_ID);
+impl_multi_fetch!(FoundryId, OutputId, TREE_FOUNDRY_ID_TO_OUTPUT_ID);
 impl_multi_fetch!(MilestoneIndex, Milestone, TREE_MILESTONE_INDEX_TO_MILESTONE);
 impl_multi_fetch!(
     SolidEntryPoint,
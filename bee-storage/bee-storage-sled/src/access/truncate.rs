@@ -6,12 +6,16 @@
 use crate::{storage::Storage, trees::*};
 
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressRole, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::Ed25519Address,
+    address::Address,
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{
+        feature_block::{SenderFeatureBlock, TagFeatureBlock},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     Message, MessageId,
 };
 use bee_storage::{access::Truncate, backend::StorageBackend};
@@ -37,7 +41,12 @@ impl_truncate!((MessageId, MessageId), (), TREE_MESSAGE_ID_TO_MESSAGE_ID);
 impl_truncate!(OutputId, CreatedOutput, TREE_OUTPUT_ID_TO_CREATED_OUTPUT);
 impl_truncate!(OutputId, ConsumedOutput, TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT);
 impl_truncate!(Unspent, (), TREE_OUTPUT_ID_UNSPENT);
-impl_truncate!((Ed25519Address, OutputId), (), TREE_ED25519_ADDRESS_TO_OUTPUT_ID);
+impl_truncate!(((Address, AddressRole), OutputId), (), TREE_ADDRESS_TO_OUTPUT_ID);
+impl_truncate!((TagFeatureBlock, OutputId), (), TREE_TAG_TO_OUTPUT_ID);
+impl_truncate!((SenderFeatureBlock, OutputId), (), TREE_SENDER_TO_OUTPUT_ID);
+impl_truncate!(AliasId, OutputId, TREE_ALIAS_ID_TO_OUTPUT_ID);
+impl_truncate!(NftId, OutputId, TREE_NFT_ID_TO_OUTPUT_ID);
+impl_truncate!(FoundryId, OutputId, TREE_FOUNDRY_ID_TO_OUTPUT_ID);
 impl_truncate!((), LedgerIndex, TREE_LEDGER_INDEX);
 impl_truncate!(MilestoneIndex, Milestone, TREE_MILESTONE_INDEX_TO_MILESTONE);
 impl_truncate!((), SnapshotInfo, TREE_SNAPSHOT_INFO);
@@ -8,5 +8,6 @@
 
 pub mod access;
 pub mod config;
+mod migration;
 pub mod storage;
 pub mod trees;
@@ -0,0 +1,137 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Migration steps of the sled storage.
+
+use crate::{
+    access::batch::StorageBatch,
+    storage::{Error, Storage},
+    trees::*,
+};
+
+use bee_ledger::types::{output_addresses, AddressRole, CreatedOutput, Unspent};
+use bee_message::{
+    address::Address,
+    output::{AliasId, FoundryId, NftId, Output, OutputId},
+};
+use bee_storage::{
+    access::{AsIterator, Batch, BatchBuilder, Fetch},
+    migration::MigrationStep,
+    system::StorageVersion,
+};
+
+use log::info;
+
+/// Number of entries written in a single batch when building an index.
+const MIGRATION_BATCH_SIZE: usize = 100_000;
+
+/// Returns the registered migration steps of the sled storage.
+pub(crate) fn migration_steps() -> Vec<MigrationStep<Storage>> {
+    vec![
+        MigrationStep::new(
+            StorageVersion(0),
+            "replace the Ed25519 address index by an index of all the addresses of the unspent outputs",
+            build_address_index,
+        ),
+        MigrationStep::new(
+            StorageVersion(1),
+            "index the unspent alias, NFT and foundry outputs by their chain IDs",
+            build_chain_id_index,
+        ),
+    ]
+}
+
+/// Calls `index` on every unspent output to write its index entries, and commits them in batches.
+///
+/// `index` returns the number of entries it wrote.
+fn index_unspent_outputs(
+    storage: &Storage,
+    entries: &str,
+    mut index: impl FnMut(&mut StorageBatch, &OutputId, &Output) -> Result<usize, Error>,
+) -> Result<(), Error> {
+    let mut batch = Storage::batch_begin();
+    let mut batch_len = 0;
+    let mut indexed = 0;
+
+    for result in AsIterator::<Unspent, ()>::iter(storage)? {
+        let (unspent, ()) = result?;
+        let output_id = unspent.id();
+
+        // Unspent outputs without created output are reported by the storage check.
+        let created_output = match Fetch::<OutputId, CreatedOutput>::fetch(storage, output_id)? {
+            Some(created_output) => created_output,
+            None => continue,
+        };
+
+        let len = index(&mut batch, output_id, created_output.inner())?;
+        batch_len += len;
+        indexed += len;
+
+        if batch_len >= MIGRATION_BATCH_SIZE {
+            storage.batch_commit(std::mem::take(&mut batch), true)?;
+            batch_len = 0;
+            info!("Indexed {} {}.", indexed, entries);
+        }
+    }
+
+    storage.batch_commit(batch, true)?;
+    info!("Indexed {} {}.", indexed, entries);
+
+    Ok(())
+}
+
+/// Indexes the unspent outputs by all their addresses and the roles of these addresses, and drops the legacy Ed25519
+/// address index.
+///
+/// The legacy index can't be converted since it lacks the other kinds of addresses and roles, the new index is
+/// therefore rebuilt from the unspent outputs.
+fn build_address_index(storage: &mut Storage) -> Result<(), Error> {
+    let storage = &*storage;
+
+    index_unspent_outputs(storage, "address index entries", |batch, output_id, output| {
+        let addresses = output_addresses(output);
+
+        for address in addresses.iter() {
+            Batch::<((Address, AddressRole), OutputId), ()>::batch_insert(
+                storage,
+                batch,
+                &(*address, *output_id),
+                &(),
+            )?;
+        }
+
+        Ok(addresses.len())
+    })?;
+
+    storage.inner.drop_tree(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)?;
+
+    Ok(())
+}
+
+/// Indexes the unspent alias, NFT and foundry outputs by their chain IDs.
+fn build_chain_id_index(storage: &mut Storage) -> Result<(), Error> {
+    let storage = &*storage;
+
+    index_unspent_outputs(storage, "chain ID index entries", |batch, output_id, output| {
+        match output {
+            Output::Alias(output) => Batch::<AliasId, OutputId>::batch_insert(
+                storage,
+                batch,
+                &output.alias_id().or_from_output_id(*output_id),
+                output_id,
+            )?,
+            Output::Nft(output) => Batch::<NftId, OutputId>::batch_insert(
+                storage,
+                batch,
+                &output.nft_id().or_from_output_id(*output_id),
+                output_id,
+            )?,
+            Output::Foundry(output) => {
+                Batch::<FoundryId, OutputId>::batch_insert(storage, batch, &output.id(), output_id)?
+            }
+            _ => return Ok(0),
+        }
+
+        Ok(1)
+    })
+}
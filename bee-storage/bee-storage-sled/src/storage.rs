@@ -3,16 +3,22 @@
 
 //! The sled storage backend.
 
-use crate::config::{SledConfig, SledConfigBuilder};
+use crate::{
+    config::{SledConfig, SledConfigBuilder},
+    migration::migration_steps,
+};
 
 use bee_storage::{
     access::{Fetch, Insert},
-    backend::StorageBackend,
+    backend::{StorageBackend, StorageBackup},
+    migration::{migrate, Error as MigrationError},
     system::{StorageHealth, StorageVersion, System, SYSTEM_HEALTH_KEY, SYSTEM_VERSION_KEY},
 };
 
 use thiserror::Error;
 
+use std::path::Path;
+
 /// Error to be raised when a backend operation fails.
 #[derive(Debug, Error)]
 pub enum Error {
@@ -26,9 +32,12 @@ pub enum Error {
     /// The storage was not closed properly.
     #[error("unhealthy storage: {0:?}, remove storage folder and restart")]
     UnhealthyStorage(StorageHealth),
+    /// The storage can't be backed up while running.
+    #[error("backups are not supported by sled, stop the node and copy the storage folder instead")]
+    BackupUnsupported,
 }
 
-pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion(0);
+pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion(2);
 
 /// The sled database.
 pub struct Storage {
@@ -50,7 +59,7 @@ impl Storage {
             .use_compression(config.compression_factor.is_some())
             .compression_factor(config.compression_factor.unwrap_or(1) as i32)
             .temporary(config.temporary)
-            .create_new(!config.create_new);
+            .create_new(config.create_new);
 
         let inner = sled_cfg.open()?;
 
@@ -64,17 +73,7 @@ impl StorageBackend for Storage {
     type Error = Error;
 
     fn start(config: Self::Config) -> Result<Self, Self::Error> {
-        let storage = Self::new(config)?;
-
-        match Fetch::<u8, System>::fetch(&storage, &SYSTEM_VERSION_KEY)? {
-            Some(System::Version(version)) => {
-                if version != STORAGE_VERSION {
-                    return Err(Error::VersionMismatch(version, STORAGE_VERSION));
-                }
-            }
-            None => Insert::<u8, System>::insert(&storage, &SYSTEM_VERSION_KEY, &System::Version(STORAGE_VERSION))?,
-            _ => panic!("Another system value was inserted on the version key."),
-        }
+        let mut storage = Self::new(config)?;
 
         if let Some(health) = storage.get_health()? {
             if health != StorageHealth::Healthy {
@@ -82,6 +81,16 @@ impl StorageBackend for Storage {
             }
         }
 
+        match Fetch::<u8, System>::fetch(&storage, &SYSTEM_VERSION_KEY)? {
+            Some(System::Version(version)) => migrate(&mut storage, version, STORAGE_VERSION, &migration_steps())
+                .map_err(|e| match e {
+                    MigrationError::MissingStep(_) => Error::VersionMismatch(version, STORAGE_VERSION),
+                    MigrationError::Backend(e) => e,
+                })?,
+            None => Insert::<u8, System>::insert(&storage, &SYSTEM_VERSION_KEY, &System::Version(STORAGE_VERSION))?,
+            _ => panic!("Another system value was inserted on the version key."),
+        }
+
         storage.set_health(StorageHealth::Idle)?;
 
         Ok(storage)
@@ -109,3 +118,10 @@ impl StorageBackend for Storage {
         Insert::<u8, System>::insert(self, &SYSTEM_HEALTH_KEY, &System::Health(health))
     }
 }
+
+impl StorageBackup for Storage {
+    /// Sled has no checkpoint facility and writes can't be paused, so a running database can't be copied consistently.
+    fn backup(&self, _path: &Path) -> Result<(), Self::Error> {
+        Err(Error::BackupUnsupported)
+    }
+}
@@ -18,8 +18,18 @@ pub const TREE_OUTPUT_ID_TO_CREATED_OUTPUT: &str = "output_id_to_created_output"
 pub const TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT: &str = "output_id_to_consumed_output";
 /// Identifier for the `Unspent` tree.
 pub const TREE_OUTPUT_ID_UNSPENT: &str = "output_id_unspent";
-/// Identifier for the `Ed25519Address` to `OutputId` tree.
-pub const TREE_ED25519_ADDRESS_TO_OUTPUT_ID: &str = "ed25519_address_to_output_id";
+/// Identifier for the `(Address, AddressRole)` to `OutputId` tree.
+pub const TREE_ADDRESS_TO_OUTPUT_ID: &str = "address_to_output_id";
+/// Identifier for the `AliasId` to `OutputId` tree.
+pub const TREE_ALIAS_ID_TO_OUTPUT_ID: &str = "alias_id_to_output_id";
+/// Identifier for the `NftId` to `OutputId` tree.
+pub const TREE_NFT_ID_TO_OUTPUT_ID: &str = "nft_id_to_output_id";
+/// Identifier for the `FoundryId` to `OutputId` tree.
+pub const TREE_FOUNDRY_ID_TO_OUTPUT_ID: &str = "foundry_id_to_output_id";
+/// Identifier for the `TagFeatureBlock` to `OutputId` tree.
+pub const TREE_TAG_TO_OUTPUT_ID: &str = "tag_to_output_id";
+/// Identifier for the `SenderFeatureBlock` to `OutputId` tree.
+pub const TREE_SENDER_TO_OUTPUT_ID: &str = "sender_to_output_id";
 /// Identifier for the `LedgerIndex` tree.
 pub const TREE_LEDGER_INDEX: &str = "ledger_index";
 /// Identifier for the `MIlestoneIndex` to `Milestone` tree.
@@ -36,3 +46,5 @@ pub const TREE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE: &str = "milestone_index_
 pub const TREE_MILESTONE_INDEX_TO_RECEIPT: &str = "milestone_index_to_receipt";
 /// Identifier for the `bool` to `Vec<TreasuryOutput>` tree.
 pub const TREE_SPENT_TO_TREASURY_OUTPUT: &str = "spent_to_treasury_output";
+/// Identifier for the legacy `Ed25519Address` to `OutputId` tree, only used to migrate older storages.
+pub const TREE_ED25519_ADDRESS_TO_OUTPUT_ID: &str = "ed25519_address_to_output_id";
@@ -0,0 +1,34 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_storage::{
+    backend::{StorageBackend, StorageBackup},
+    system::StorageHealth,
+};
+use bee_storage_sled::{
+    config::SledConfigBuilder,
+    storage::{Error, Storage},
+};
+
+use std::path::Path;
+
+#[test]
+fn backup_running_storage() {
+    let path = String::from("./tests/database/backup_running_storage");
+    let backup_path = String::from("./tests/database/backup_running_storage_backup");
+    let _ = std::fs::remove_dir_all(&path);
+    let _ = std::fs::remove_dir_all(&backup_path);
+
+    let storage = Storage::start(SledConfigBuilder::default().with_path(path.clone()).finish()).unwrap();
+
+    assert!(matches!(
+        storage.backup(Path::new(&backup_path)),
+        Err(Error::BackupUnsupported)
+    ));
+    assert!(!Path::new(&backup_path).exists());
+    assert_eq!(storage.get_health().unwrap(), Some(StorageHealth::Idle));
+
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(&path);
+}
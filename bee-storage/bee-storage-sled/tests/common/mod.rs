@@ -0,0 +1,20 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use fs2::FileExt;
+
+use std::{fs::OpenOptions, path::Path};
+
+/// Waits until sled releases the lock of the database at the given path, so that it can be opened again.
+/// Sled releases it from its background threads once the last handle to the database is dropped, so opening the
+/// database right away may fail.
+pub fn wait_for_release(path: impl AsRef<Path>) {
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path.as_ref().join("db"))
+        .unwrap();
+
+    file.lock_exclusive().unwrap();
+    file.unlock().unwrap();
+}
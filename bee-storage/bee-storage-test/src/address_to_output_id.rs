@@ -0,0 +1,143 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::types::AddressRole;
+use bee_message::{address::Address, output::OutputId};
+use bee_storage::{
+    access::{AsIterator, AsRangeIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, IterDirection, Truncate},
+    backend,
+};
+use bee_test::rand::{
+    address::{rand_address, rand_address_role},
+    output::rand_output_id,
+};
+
+use std::collections::HashMap;
+
+pub trait StorageBackend:
+    backend::StorageBackend
+    + Exist<((Address, AddressRole), OutputId), ()>
+    + Fetch<(Address, AddressRole), Vec<OutputId>>
+    + Insert<((Address, AddressRole), OutputId), ()>
+    + Delete<((Address, AddressRole), OutputId), ()>
+    + BatchBuilder
+    + Batch<((Address, AddressRole), OutputId), ()>
+    + for<'a> AsIterator<'a, ((Address, AddressRole), OutputId), ()>
+    + for<'a> AsRangeIterator<'a, (Address, AddressRole), ((Address, AddressRole), OutputId), ()>
+    + Truncate<((Address, AddressRole), OutputId), ()>
+{
+}
+
+impl<T> StorageBackend for T where
+    T: backend::StorageBackend
+        + Exist<((Address, AddressRole), OutputId), ()>
+        + Fetch<(Address, AddressRole), Vec<OutputId>>
+        + Insert<((Address, AddressRole), OutputId), ()>
+        + Delete<((Address, AddressRole), OutputId), ()>
+        + BatchBuilder
+        + Batch<((Address, AddressRole), OutputId), ()>
+        + for<'a> AsIterator<'a, ((Address, AddressRole), OutputId), ()>
+        + for<'a> AsRangeIterator<'a, (Address, AddressRole), ((Address, AddressRole), OutputId), ()>
+        + Truncate<((Address, AddressRole), OutputId), ()>
+{
+}
+
+pub fn address_to_output_id_access<B: StorageBackend>(storage: &B) {
+    let (address, output_id) = ((rand_address(), rand_address_role()), rand_output_id());
+
+    assert!(!Exist::<((Address, AddressRole), OutputId), ()>::exist(storage, &(address, output_id)).unwrap());
+    assert!(Fetch::<(Address, AddressRole), Vec<OutputId>>::fetch(storage, &address)
+        .unwrap()
+        .unwrap()
+        .is_empty());
+
+    Insert::<((Address, AddressRole), OutputId), ()>::insert(storage, &(address, output_id), &()).unwrap();
+
+    assert!(Exist::<((Address, AddressRole), OutputId), ()>::exist(storage, &(address, output_id)).unwrap());
+    assert_eq!(
+        Fetch::<(Address, AddressRole), Vec<OutputId>>::fetch(storage, &address)
+            .unwrap()
+            .unwrap(),
+        vec![output_id]
+    );
+
+    Delete::<((Address, AddressRole), OutputId), ()>::delete(storage, &(address, output_id)).unwrap();
+
+    assert!(!Exist::<((Address, AddressRole), OutputId), ()>::exist(storage, &(address, output_id)).unwrap());
+    assert!(Fetch::<(Address, AddressRole), Vec<OutputId>>::fetch(storage, &address)
+        .unwrap()
+        .unwrap()
+        .is_empty());
+
+    let mut batch = B::batch_begin();
+
+    for _ in 0..10 {
+        let (address, output_id) = ((rand_address(), rand_address_role()), rand_output_id());
+        Insert::<((Address, AddressRole), OutputId), ()>::insert(storage, &(address, output_id), &()).unwrap();
+        Batch::<((Address, AddressRole), OutputId), ()>::batch_delete(storage, &mut batch, &(address, output_id))
+            .unwrap();
+    }
+
+    let mut output_ids = HashMap::<(Address, AddressRole), Vec<OutputId>>::new();
+
+    for _ in 0..5 {
+        let address = (rand_address(), rand_address_role());
+        for _ in 0..5 {
+            let output_id = rand_output_id();
+            Batch::<((Address, AddressRole), OutputId), ()>::batch_insert(
+                storage,
+                &mut batch,
+                &(address, output_id),
+                &(),
+            )
+            .unwrap();
+            output_ids.entry(address).or_default().push(output_id);
+        }
+    }
+
+    storage.batch_commit(batch, true).unwrap();
+
+    let iter = AsIterator::<((Address, AddressRole), OutputId), ()>::iter(storage).unwrap();
+    let mut count = 0;
+
+    for result in iter {
+        let ((address, output_id), _) = result.unwrap();
+        assert!(output_ids.get(&address).unwrap().contains(&output_id));
+        count += 1;
+    }
+
+    assert_eq!(count, output_ids.iter().fold(0, |acc, v| acc + v.1.len()));
+
+    for (address, output_ids) in output_ids.iter() {
+        let forward = AsRangeIterator::<(Address, AddressRole), ((Address, AddressRole), OutputId), ()>::iter_prefix(
+            storage,
+            address,
+            IterDirection::Forward,
+        )
+        .unwrap()
+        .map(|result| result.unwrap().0)
+        .collect::<Vec<_>>();
+        let mut reverse =
+            AsRangeIterator::<(Address, AddressRole), ((Address, AddressRole), OutputId), ()>::iter_prefix(
+                storage,
+                address,
+                IterDirection::Reverse,
+            )
+            .unwrap()
+            .map(|result| result.unwrap().0)
+            .collect::<Vec<_>>();
+        reverse.reverse();
+
+        assert_eq!(forward, reverse);
+        assert_eq!(forward.len(), output_ids.len());
+        assert!(forward
+            .iter()
+            .all(|(other, output_id)| other == address && output_ids.contains(output_id)));
+    }
+
+    Truncate::<((Address, AddressRole), OutputId), ()>::truncate(storage).unwrap();
+
+    let mut iter = AsIterator::<((Address, AddressRole), OutputId), ()>::iter(storage).unwrap();
+
+    assert!(iter.next().is_none());
+}
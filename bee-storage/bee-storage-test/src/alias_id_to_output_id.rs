@@ -0,0 +1,120 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::output::{AliasId, OutputId};
+use bee_storage::{
+    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, MultiFetch, Truncate},
+    backend,
+};
+use bee_test::rand::output::{rand_alias_id, rand_output_id};
+
+pub trait StorageBackend:
+    backend::StorageBackend
+    + Exist<AliasId, OutputId>
+    + Fetch<AliasId, OutputId>
+    + for<'a> MultiFetch<'a, AliasId, OutputId>
+    + Insert<AliasId, OutputId>
+    + Delete<AliasId, OutputId>
+    + BatchBuilder
+    + Batch<AliasId, OutputId>
+    + for<'a> AsIterator<'a, AliasId, OutputId>
+    + Truncate<AliasId, OutputId>
+{
+}
+
+impl<T> StorageBackend for T where
+    T: backend::StorageBackend
+        + Exist<AliasId, OutputId>
+        + Fetch<AliasId, OutputId>
+        + for<'a> MultiFetch<'a, AliasId, OutputId>
+        + Insert<AliasId, OutputId>
+        + Delete<AliasId, OutputId>
+        + BatchBuilder
+        + Batch<AliasId, OutputId>
+        + for<'a> AsIterator<'a, AliasId, OutputId>
+        + Truncate<AliasId, OutputId>
+{
+}
+
+pub fn alias_id_to_output_id_access<B: StorageBackend>(storage: &B) {
+    let (alias_id, output_id) = (rand_alias_id(), rand_output_id());
+
+    assert!(!Exist::<AliasId, OutputId>::exist(storage, &alias_id).unwrap());
+    assert!(Fetch::<AliasId, OutputId>::fetch(storage, &alias_id).unwrap().is_none());
+    let results = MultiFetch::<AliasId, OutputId>::multi_fetch(storage, &[alias_id])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(None))));
+
+    Insert::<AliasId, OutputId>::insert(storage, &alias_id, &output_id).unwrap();
+
+    assert!(Exist::<AliasId, OutputId>::exist(storage, &alias_id).unwrap());
+    assert_eq!(
+        Fetch::<AliasId, OutputId>::fetch(storage, &alias_id).unwrap().unwrap(),
+        output_id
+    );
+    let results = MultiFetch::<AliasId, OutputId>::multi_fetch(storage, &[alias_id])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(Some(v))) if v == &output_id));
+
+    Delete::<AliasId, OutputId>::delete(storage, &alias_id).unwrap();
+
+    assert!(!Exist::<AliasId, OutputId>::exist(storage, &alias_id).unwrap());
+    assert!(Fetch::<AliasId, OutputId>::fetch(storage, &alias_id).unwrap().is_none());
+    let results = MultiFetch::<AliasId, OutputId>::multi_fetch(storage, &[alias_id])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(None))));
+
+    let mut batch = B::batch_begin();
+    let mut alias_ids = Vec::new();
+    let mut output_ids = Vec::new();
+
+    for _ in 0..10 {
+        let (alias_id, output_id) = (rand_alias_id(), rand_output_id());
+        Insert::<AliasId, OutputId>::insert(storage, &alias_id, &output_id).unwrap();
+        Batch::<AliasId, OutputId>::batch_delete(storage, &mut batch, &alias_id).unwrap();
+        alias_ids.push(alias_id);
+        output_ids.push((alias_id, None));
+    }
+
+    for _ in 0..10 {
+        let (alias_id, output_id) = (rand_alias_id(), rand_output_id());
+        Batch::<AliasId, OutputId>::batch_insert(storage, &mut batch, &alias_id, &output_id).unwrap();
+        alias_ids.push(alias_id);
+        output_ids.push((alias_id, Some(output_id)));
+    }
+
+    storage.batch_commit(batch, true).unwrap();
+
+    let iter = AsIterator::<AliasId, OutputId>::iter(storage).unwrap();
+    let mut count = 0;
+
+    for result in iter {
+        let (alias_id, output_id) = result.unwrap();
+        assert!(output_ids.contains(&(alias_id, Some(output_id))));
+        count += 1;
+    }
+
+    assert_eq!(count, 10);
+
+    let results = MultiFetch::<AliasId, OutputId>::multi_fetch(storage, &alias_ids)
+        .unwrap()
+        .collect::<Vec<_>>();
+
+    assert_eq!(results.len(), alias_ids.len());
+
+    for ((_, output_id), result) in output_ids.into_iter().zip(results.into_iter()) {
+        assert_eq!(output_id, result.unwrap());
+    }
+
+    Truncate::<AliasId, OutputId>::truncate(storage).unwrap();
+
+    let mut iter = AsIterator::<AliasId, OutputId>::iter(storage).unwrap();
+
+    assert!(iter.next().is_none());
+}
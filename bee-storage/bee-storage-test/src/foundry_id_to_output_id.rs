@@ -0,0 +1,126 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::output::{FoundryId, OutputId};
+use bee_storage::{
+    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, MultiFetch, Truncate},
+    backend,
+};
+use bee_test::rand::output::{rand_foundry_id, rand_output_id};
+
+pub trait StorageBackend:
+    backend::StorageBackend
+    + Exist<FoundryId, OutputId>
+    + Fetch<FoundryId, OutputId>
+    + for<'a> MultiFetch<'a, FoundryId, OutputId>
+    + Insert<FoundryId, OutputId>
+    + Delete<FoundryId, OutputId>
+    + BatchBuilder
+    + Batch<FoundryId, OutputId>
+    + for<'a> AsIterator<'a, FoundryId, OutputId>
+    + Truncate<FoundryId, OutputId>
+{
+}
+
+impl<T> StorageBackend for T where
+    T: backend::StorageBackend
+        + Exist<FoundryId, OutputId>
+        + Fetch<FoundryId, OutputId>
+        + for<'a> MultiFetch<'a, FoundryId, OutputId>
+        + Insert<FoundryId, OutputId>
+        + Delete<FoundryId, OutputId>
+        + BatchBuilder
+        + Batch<FoundryId, OutputId>
+        + for<'a> AsIterator<'a, FoundryId, OutputId>
+        + Truncate<FoundryId, OutputId>
+{
+}
+
+pub fn foundry_id_to_output_id_access<B: StorageBackend>(storage: &B) {
+    let (foundry_id, output_id) = (rand_foundry_id(), rand_output_id());
+
+    assert!(!Exist::<FoundryId, OutputId>::exist(storage, &foundry_id).unwrap());
+    assert!(Fetch::<FoundryId, OutputId>::fetch(storage, &foundry_id)
+        .unwrap()
+        .is_none());
+    let results = MultiFetch::<FoundryId, OutputId>::multi_fetch(storage, &[foundry_id])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(None))));
+
+    Insert::<FoundryId, OutputId>::insert(storage, &foundry_id, &output_id).unwrap();
+
+    assert!(Exist::<FoundryId, OutputId>::exist(storage, &foundry_id).unwrap());
+    assert_eq!(
+        Fetch::<FoundryId, OutputId>::fetch(storage, &foundry_id)
+            .unwrap()
+            .unwrap(),
+        output_id
+    );
+    let results = MultiFetch::<FoundryId, OutputId>::multi_fetch(storage, &[foundry_id])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(Some(v))) if v == &output_id));
+
+    Delete::<FoundryId, OutputId>::delete(storage, &foundry_id).unwrap();
+
+    assert!(!Exist::<FoundryId, OutputId>::exist(storage, &foundry_id).unwrap());
+    assert!(Fetch::<FoundryId, OutputId>::fetch(storage, &foundry_id)
+        .unwrap()
+        .is_none());
+    let results = MultiFetch::<FoundryId, OutputId>::multi_fetch(storage, &[foundry_id])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(None))));
+
+    let mut batch = B::batch_begin();
+    let mut foundry_ids = Vec::new();
+    let mut output_ids = Vec::new();
+
+    for _ in 0..10 {
+        let (foundry_id, output_id) = (rand_foundry_id(), rand_output_id());
+        Insert::<FoundryId, OutputId>::insert(storage, &foundry_id, &output_id).unwrap();
+        Batch::<FoundryId, OutputId>::batch_delete(storage, &mut batch, &foundry_id).unwrap();
+        foundry_ids.push(foundry_id);
+        output_ids.push((foundry_id, None));
+    }
+
+    for _ in 0..10 {
+        let (foundry_id, output_id) = (rand_foundry_id(), rand_output_id());
+        Batch::<FoundryId, OutputId>::batch_insert(storage, &mut batch, &foundry_id, &output_id).unwrap();
+        foundry_ids.push(foundry_id);
+        output_ids.push((foundry_id, Some(output_id)));
+    }
+
+    storage.batch_commit(batch, true).unwrap();
+
+    let iter = AsIterator::<FoundryId, OutputId>::iter(storage).unwrap();
+    let mut count = 0;
+
+    for result in iter {
+        let (foundry_id, output_id) = result.unwrap();
+        assert!(output_ids.contains(&(foundry_id, Some(output_id))));
+        count += 1;
+    }
+
+    assert_eq!(count, 10);
+
+    let results = MultiFetch::<FoundryId, OutputId>::multi_fetch(storage, &foundry_ids)
+        .unwrap()
+        .collect::<Vec<_>>();
+
+    assert_eq!(results.len(), foundry_ids.len());
+
+    for ((_, output_id), result) in output_ids.into_iter().zip(results.into_iter()) {
+        assert_eq!(output_id, result.unwrap());
+    }
+
+    Truncate::<FoundryId, OutputId>::truncate(storage).unwrap();
+
+    let mut iter = AsIterator::<FoundryId, OutputId>::iter(storage).unwrap();
+
+    assert!(iter.next().is_none());
+}
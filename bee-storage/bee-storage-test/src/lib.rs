@@ -1,7 +1,9 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-mod ed25519_address_to_output_id;
+mod address_to_output_id;
+mod alias_id_to_output_id;
+mod foundry_id_to_output_id;
 mod ledger_index;
 mod message_id_to_message;
 mod message_id_to_message_id;
@@ -10,14 +12,19 @@ mod milestone_index_to_milestone;
 mod milestone_index_to_output_diff;
 mod milestone_index_to_receipt;
 mod milestone_index_to_unreferenced_message;
+mod nft_id_to_output_id;
 mod output_id_to_consumed_output;
 mod output_id_to_created_output;
 mod output_id_unspent;
+mod sender_to_output_id;
 mod snapshot_info;
 mod solid_entry_point_to_milestone_index;
 mod spent_to_treasury_output;
+mod tag_to_output_id;
 
-pub use ed25519_address_to_output_id::ed25519_address_to_output_id_access;
+pub use address_to_output_id::address_to_output_id_access;
+pub use alias_id_to_output_id::alias_id_to_output_id_access;
+pub use foundry_id_to_output_id::foundry_id_to_output_id_access;
 pub use ledger_index::ledger_index_access;
 pub use message_id_to_message::message_id_to_message_access;
 pub use message_id_to_message_id::message_id_to_message_id_access;
@@ -26,9 +33,12 @@ pub use milestone_index_to_milestone::milestone_index_to_milestone_access;
 pub use milestone_index_to_output_diff::milestone_index_to_output_diff_access;
 pub use milestone_index_to_receipt::milestone_index_to_receipt_access;
 pub use milestone_index_to_unreferenced_message::milestone_index_to_unreferenced_message_access;
+pub use nft_id_to_output_id::nft_id_to_output_id_access;
 pub use output_id_to_consumed_output::output_id_to_consumed_output_access;
 pub use output_id_to_created_output::output_id_to_created_output_access;
 pub use output_id_unspent::output_id_unspent_access;
+pub use sender_to_output_id::sender_to_output_id_access;
 pub use snapshot_info::snapshot_info_access;
 pub use solid_entry_point_to_milestone_index::solid_entry_point_to_milestone_index_access;
 pub use spent_to_treasury_output::spent_to_treasury_output_access;
+pub use tag_to_output_id::tag_to_output_id_access;
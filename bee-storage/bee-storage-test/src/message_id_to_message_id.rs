@@ -3,7 +3,7 @@
 
 use bee_message::MessageId;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, AsRangeIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, IterDirection, Truncate},
     backend,
 };
 use bee_test::rand::message::rand_message_id;
@@ -19,6 +19,7 @@ pub trait StorageBackend:
     + BatchBuilder
     + Batch<(MessageId, MessageId), ()>
     + for<'a> AsIterator<'a, (MessageId, MessageId), ()>
+    + for<'a> AsRangeIterator<'a, MessageId, (MessageId, MessageId), ()>
     + Truncate<(MessageId, MessageId), ()>
 {
 }
@@ -32,6 +33,7 @@ impl<T> StorageBackend for T where
         + BatchBuilder
         + Batch<(MessageId, MessageId), ()>
         + for<'a> AsIterator<'a, (MessageId, MessageId), ()>
+        + for<'a> AsRangeIterator<'a, MessageId, (MessageId, MessageId), ()>
         + Truncate<(MessageId, MessageId), ()>
 {
 }
@@ -40,12 +42,10 @@ pub fn message_id_to_message_id_access<B: StorageBackend>(storage: &B) {
     let (parent, child) = (rand_message_id(), rand_message_id());
 
     assert!(!Exist::<(MessageId, MessageId), ()>::exist(storage, &(parent, child)).unwrap());
-    assert!(
-        Fetch::<MessageId, Vec<MessageId>>::fetch(storage, &parent)
-            .unwrap()
-            .unwrap()
-            .is_empty()
-    );
+    assert!(Fetch::<MessageId, Vec<MessageId>>::fetch(storage, &parent)
+        .unwrap()
+        .unwrap()
+        .is_empty());
 
     Insert::<(MessageId, MessageId), ()>::insert(storage, &(parent, child), &()).unwrap();
 
@@ -60,12 +60,10 @@ pub fn message_id_to_message_id_access<B: StorageBackend>(storage: &B) {
     Delete::<(MessageId, MessageId), ()>::delete(storage, &(parent, child)).unwrap();
 
     assert!(!Exist::<(MessageId, MessageId), ()>::exist(storage, &(parent, child)).unwrap());
-    assert!(
-        Fetch::<MessageId, Vec<MessageId>>::fetch(storage, &parent)
-            .unwrap()
-            .unwrap()
-            .is_empty()
-    );
+    assert!(Fetch::<MessageId, Vec<MessageId>>::fetch(storage, &parent)
+        .unwrap()
+        .unwrap()
+        .is_empty());
 
     let mut batch = B::batch_begin();
 
@@ -99,6 +97,83 @@ pub fn message_id_to_message_id_access<B: StorageBackend>(storage: &B) {
 
     assert_eq!(count, edges.iter().fold(0, |acc, v| acc + v.1.len()));
 
+    // Message ids are ordered like their bytes, and so are the edges.
+    let mut sorted_edges = edges
+        .iter()
+        .flat_map(|(parent, children)| children.iter().map(move |child| (*parent, *child)))
+        .collect::<Vec<_>>();
+    sorted_edges.sort();
+
+    for (parent, children) in edges.iter() {
+        let mut children = children.iter().map(|child| (*parent, *child)).collect::<Vec<_>>();
+        children.sort();
+
+        let forward = AsRangeIterator::<MessageId, (MessageId, MessageId), ()>::iter_prefix(
+            storage,
+            parent,
+            IterDirection::Forward,
+        )
+        .unwrap()
+        .map(|result| result.unwrap().0)
+        .collect::<Vec<_>>();
+
+        assert_eq!(forward, children);
+
+        let reverse = AsRangeIterator::<MessageId, (MessageId, MessageId), ()>::iter_prefix(
+            storage,
+            parent,
+            IterDirection::Reverse,
+        )
+        .unwrap()
+        .map(|result| result.unwrap().0)
+        .collect::<Vec<_>>();
+        children.reverse();
+
+        assert_eq!(reverse, children);
+    }
+
+    let (start, end) = (&sorted_edges[5], &sorted_edges[20]);
+
+    let forward =
+        AsRangeIterator::<MessageId, (MessageId, MessageId), ()>::iter_from(storage, start, IterDirection::Forward)
+            .unwrap()
+            .map(|result| result.unwrap().0)
+            .collect::<Vec<_>>();
+
+    assert_eq!(forward, sorted_edges[5..]);
+
+    let reverse =
+        AsRangeIterator::<MessageId, (MessageId, MessageId), ()>::iter_from(storage, start, IterDirection::Reverse)
+            .unwrap()
+            .map(|result| result.unwrap().0)
+            .collect::<Vec<_>>();
+
+    assert_eq!(reverse, sorted_edges[..=5].iter().rev().copied().collect::<Vec<_>>());
+
+    let forward = AsRangeIterator::<MessageId, (MessageId, MessageId), ()>::iter_range(
+        storage,
+        start,
+        end,
+        IterDirection::Forward,
+    )
+    .unwrap()
+    .map(|result| result.unwrap().0)
+    .collect::<Vec<_>>();
+
+    assert_eq!(forward, sorted_edges[5..20]);
+
+    let reverse = AsRangeIterator::<MessageId, (MessageId, MessageId), ()>::iter_range(
+        storage,
+        start,
+        end,
+        IterDirection::Reverse,
+    )
+    .unwrap()
+    .map(|result| result.unwrap().0)
+    .collect::<Vec<_>>();
+
+    assert_eq!(reverse, sorted_edges[5..20].iter().rev().copied().collect::<Vec<_>>());
+
     Truncate::<(MessageId, MessageId), ()>::truncate(storage).unwrap();
 
     let mut iter = AsIterator::<(MessageId, MessageId), ()>::iter(storage).unwrap();
@@ -40,11 +40,9 @@ pub fn milestone_index_to_milestone_access<B: StorageBackend>(storage: &B) {
     let (index, milestone) = (rand_milestone_index(), rand_milestone());
 
     assert!(!Exist::<MilestoneIndex, Milestone>::exist(storage, &index).unwrap());
-    assert!(
-        Fetch::<MilestoneIndex, Milestone>::fetch(storage, &index)
-            .unwrap()
-            .is_none()
-    );
+    assert!(Fetch::<MilestoneIndex, Milestone>::fetch(storage, &index)
+        .unwrap()
+        .is_none());
     let results = MultiFetch::<MilestoneIndex, Milestone>::multi_fetch(storage, &[index])
         .unwrap()
         .collect::<Vec<_>>();
@@ -69,11 +67,9 @@ pub fn milestone_index_to_milestone_access<B: StorageBackend>(storage: &B) {
     Delete::<MilestoneIndex, Milestone>::delete(storage, &index).unwrap();
 
     assert!(!Exist::<MilestoneIndex, Milestone>::exist(storage, &index).unwrap());
-    assert!(
-        Fetch::<MilestoneIndex, Milestone>::fetch(storage, &index)
-            .unwrap()
-            .is_none()
-    );
+    assert!(Fetch::<MilestoneIndex, Milestone>::fetch(storage, &index)
+        .unwrap()
+        .is_none());
     let results = MultiFetch::<MilestoneIndex, Milestone>::multi_fetch(storage, &[index])
         .unwrap()
         .collect::<Vec<_>>();
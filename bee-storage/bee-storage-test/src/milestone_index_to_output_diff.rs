@@ -43,11 +43,9 @@ pub fn milestone_index_to_output_diff_access<B: StorageBackend>(storage: &B) {
     let (index, output_diff) = (rand_milestone_index(), rand_output_diff());
 
     assert!(!Exist::<MilestoneIndex, OutputDiff>::exist(storage, &index).unwrap());
-    assert!(
-        Fetch::<MilestoneIndex, OutputDiff>::fetch(storage, &index)
-            .unwrap()
-            .is_none()
-    );
+    assert!(Fetch::<MilestoneIndex, OutputDiff>::fetch(storage, &index)
+        .unwrap()
+        .is_none());
     let results = MultiFetch::<MilestoneIndex, OutputDiff>::multi_fetch(storage, &[index])
         .unwrap()
         .collect::<Vec<_>>();
@@ -73,11 +71,9 @@ pub fn milestone_index_to_output_diff_access<B: StorageBackend>(storage: &B) {
     Delete::<MilestoneIndex, OutputDiff>::delete(storage, &index).unwrap();
 
     assert!(!Exist::<MilestoneIndex, OutputDiff>::exist(storage, &index).unwrap());
-    assert!(
-        Fetch::<MilestoneIndex, OutputDiff>::fetch(storage, &index)
-            .unwrap()
-            .is_none()
-    );
+    assert!(Fetch::<MilestoneIndex, OutputDiff>::fetch(storage, &index)
+        .unwrap()
+        .is_none());
     let results = MultiFetch::<MilestoneIndex, OutputDiff>::multi_fetch(storage, &[index])
         .unwrap()
         .collect::<Vec<_>>();
@@ -4,7 +4,7 @@
 use bee_ledger::types::Receipt;
 use bee_message::milestone::MilestoneIndex;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, AsRangeIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, IterDirection, Truncate},
     backend,
 };
 use bee_test::rand::{milestone::rand_milestone_index, receipt::rand_ledger_receipt};
@@ -20,6 +20,7 @@ pub trait StorageBackend:
     + BatchBuilder
     + Batch<(MilestoneIndex, Receipt), ()>
     + for<'a> AsIterator<'a, (MilestoneIndex, Receipt), ()>
+    + for<'a> AsRangeIterator<'a, MilestoneIndex, (MilestoneIndex, Receipt), ()>
     + Truncate<(MilestoneIndex, Receipt), ()>
 {
 }
@@ -33,6 +34,7 @@ impl<T> StorageBackend for T where
         + BatchBuilder
         + Batch<(MilestoneIndex, Receipt), ()>
         + for<'a> AsIterator<'a, (MilestoneIndex, Receipt), ()>
+        + for<'a> AsRangeIterator<'a, MilestoneIndex, (MilestoneIndex, Receipt), ()>
         + Truncate<(MilestoneIndex, Receipt), ()>
 {
 }
@@ -41,12 +43,10 @@ pub fn milestone_index_to_receipt_access<B: StorageBackend>(storage: &B) {
     let (index, receipt) = (rand_milestone_index(), rand_ledger_receipt());
 
     assert!(!Exist::<(MilestoneIndex, Receipt), ()>::exist(storage, &(index, receipt.clone())).unwrap());
-    assert!(
-        Fetch::<MilestoneIndex, Vec<Receipt>>::fetch(storage, &index)
-            .unwrap()
-            .unwrap()
-            .is_empty()
-    );
+    assert!(Fetch::<MilestoneIndex, Vec<Receipt>>::fetch(storage, &index)
+        .unwrap()
+        .unwrap()
+        .is_empty());
 
     Insert::<(MilestoneIndex, Receipt), ()>::insert(storage, &(index, receipt.clone()), &()).unwrap();
 
@@ -61,12 +61,10 @@ pub fn milestone_index_to_receipt_access<B: StorageBackend>(storage: &B) {
     Delete::<(MilestoneIndex, Receipt), ()>::delete(storage, &(index, receipt.clone())).unwrap();
 
     assert!(!Exist::<(MilestoneIndex, Receipt), ()>::exist(storage, &(index, receipt)).unwrap());
-    assert!(
-        Fetch::<MilestoneIndex, Vec<Receipt>>::fetch(storage, &index)
-            .unwrap()
-            .unwrap()
-            .is_empty()
-    );
+    assert!(Fetch::<MilestoneIndex, Vec<Receipt>>::fetch(storage, &index)
+        .unwrap()
+        .unwrap()
+        .is_empty());
 
     let mut batch = B::batch_begin();
 
@@ -101,6 +99,32 @@ pub fn milestone_index_to_receipt_access<B: StorageBackend>(storage: &B) {
 
     assert_eq!(count, receipts.iter().fold(0, |acc, v| acc + v.1.len()));
 
+    for (index, receipts) in receipts.iter() {
+        let forward = AsRangeIterator::<MilestoneIndex, (MilestoneIndex, Receipt), ()>::iter_prefix(
+            storage,
+            index,
+            IterDirection::Forward,
+        )
+        .unwrap()
+        .map(|result| result.unwrap().0)
+        .collect::<Vec<_>>();
+        let mut reverse = AsRangeIterator::<MilestoneIndex, (MilestoneIndex, Receipt), ()>::iter_prefix(
+            storage,
+            index,
+            IterDirection::Reverse,
+        )
+        .unwrap()
+        .map(|result| result.unwrap().0)
+        .collect::<Vec<_>>();
+        reverse.reverse();
+
+        assert_eq!(forward, reverse);
+        assert_eq!(forward.len(), receipts.len());
+        assert!(forward
+            .iter()
+            .all(|(other, receipt)| other == index && receipts.contains(receipt)));
+    }
+
     Truncate::<(MilestoneIndex, Receipt), ()>::truncate(storage).unwrap();
 
     let mut iter = AsIterator::<(MilestoneIndex, Receipt), ()>::iter(storage).unwrap();
@@ -3,7 +3,7 @@
 
 use bee_message::milestone::MilestoneIndex;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, AsRangeIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, IterDirection, Truncate},
     backend,
 };
 use bee_tangle::unreferenced_message::UnreferencedMessage;
@@ -20,6 +20,7 @@ pub trait StorageBackend:
     + BatchBuilder
     + Batch<(MilestoneIndex, UnreferencedMessage), ()>
     + for<'a> AsIterator<'a, (MilestoneIndex, UnreferencedMessage), ()>
+    + for<'a> AsRangeIterator<'a, MilestoneIndex, (MilestoneIndex, UnreferencedMessage), ()>
     + Truncate<(MilestoneIndex, UnreferencedMessage), ()>
 {
 }
@@ -33,6 +34,7 @@ impl<T> StorageBackend for T where
         + BatchBuilder
         + Batch<(MilestoneIndex, UnreferencedMessage), ()>
         + for<'a> AsIterator<'a, (MilestoneIndex, UnreferencedMessage), ()>
+        + for<'a> AsRangeIterator<'a, MilestoneIndex, (MilestoneIndex, UnreferencedMessage), ()>
         + Truncate<(MilestoneIndex, UnreferencedMessage), ()>
 {
 }
@@ -121,6 +123,32 @@ pub fn milestone_index_to_unreferenced_message_access<B: StorageBackend>(storage
 
     assert_eq!(count, unreferenced_messages.iter().fold(0, |acc, v| acc + v.1.len()));
 
+    for (index, unreferenced_messages) in unreferenced_messages.iter() {
+        let forward = AsRangeIterator::<MilestoneIndex, (MilestoneIndex, UnreferencedMessage), ()>::iter_prefix(
+            storage,
+            index,
+            IterDirection::Forward,
+        )
+        .unwrap()
+        .map(|result| result.unwrap().0)
+        .collect::<Vec<_>>();
+        let mut reverse = AsRangeIterator::<MilestoneIndex, (MilestoneIndex, UnreferencedMessage), ()>::iter_prefix(
+            storage,
+            index,
+            IterDirection::Reverse,
+        )
+        .unwrap()
+        .map(|result| result.unwrap().0)
+        .collect::<Vec<_>>();
+        reverse.reverse();
+
+        assert_eq!(forward, reverse);
+        assert_eq!(forward.len(), unreferenced_messages.len());
+        assert!(forward.iter().all(
+            |(other, unreferenced_message)| other == index && unreferenced_messages.contains(unreferenced_message)
+        ));
+    }
+
     Truncate::<(MilestoneIndex, UnreferencedMessage), ()>::truncate(storage).unwrap();
 
     let mut iter = AsIterator::<(MilestoneIndex, UnreferencedMessage), ()>::iter(storage).unwrap();
@@ -0,0 +1,120 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::output::{NftId, OutputId};
+use bee_storage::{
+    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, MultiFetch, Truncate},
+    backend,
+};
+use bee_test::rand::output::{rand_nft_id, rand_output_id};
+
+pub trait StorageBackend:
+    backend::StorageBackend
+    + Exist<NftId, OutputId>
+    + Fetch<NftId, OutputId>
+    + for<'a> MultiFetch<'a, NftId, OutputId>
+    + Insert<NftId, OutputId>
+    + Delete<NftId, OutputId>
+    + BatchBuilder
+    + Batch<NftId, OutputId>
+    + for<'a> AsIterator<'a, NftId, OutputId>
+    + Truncate<NftId, OutputId>
+{
+}
+
+impl<T> StorageBackend for T where
+    T: backend::StorageBackend
+        + Exist<NftId, OutputId>
+        + Fetch<NftId, OutputId>
+        + for<'a> MultiFetch<'a, NftId, OutputId>
+        + Insert<NftId, OutputId>
+        + Delete<NftId, OutputId>
+        + BatchBuilder
+        + Batch<NftId, OutputId>
+        + for<'a> AsIterator<'a, NftId, OutputId>
+        + Truncate<NftId, OutputId>
+{
+}
+
+pub fn nft_id_to_output_id_access<B: StorageBackend>(storage: &B) {
+    let (nft_id, output_id) = (rand_nft_id(), rand_output_id());
+
+    assert!(!Exist::<NftId, OutputId>::exist(storage, &nft_id).unwrap());
+    assert!(Fetch::<NftId, OutputId>::fetch(storage, &nft_id).unwrap().is_none());
+    let results = MultiFetch::<NftId, OutputId>::multi_fetch(storage, &[nft_id])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(None))));
+
+    Insert::<NftId, OutputId>::insert(storage, &nft_id, &output_id).unwrap();
+
+    assert!(Exist::<NftId, OutputId>::exist(storage, &nft_id).unwrap());
+    assert_eq!(
+        Fetch::<NftId, OutputId>::fetch(storage, &nft_id).unwrap().unwrap(),
+        output_id
+    );
+    let results = MultiFetch::<NftId, OutputId>::multi_fetch(storage, &[nft_id])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(Some(v))) if v == &output_id));
+
+    Delete::<NftId, OutputId>::delete(storage, &nft_id).unwrap();
+
+    assert!(!Exist::<NftId, OutputId>::exist(storage, &nft_id).unwrap());
+    assert!(Fetch::<NftId, OutputId>::fetch(storage, &nft_id).unwrap().is_none());
+    let results = MultiFetch::<NftId, OutputId>::multi_fetch(storage, &[nft_id])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(None))));
+
+    let mut batch = B::batch_begin();
+    let mut nft_ids = Vec::new();
+    let mut output_ids = Vec::new();
+
+    for _ in 0..10 {
+        let (nft_id, output_id) = (rand_nft_id(), rand_output_id());
+        Insert::<NftId, OutputId>::insert(storage, &nft_id, &output_id).unwrap();
+        Batch::<NftId, OutputId>::batch_delete(storage, &mut batch, &nft_id).unwrap();
+        nft_ids.push(nft_id);
+        output_ids.push((nft_id, None));
+    }
+
+    for _ in 0..10 {
+        let (nft_id, output_id) = (rand_nft_id(), rand_output_id());
+        Batch::<NftId, OutputId>::batch_insert(storage, &mut batch, &nft_id, &output_id).unwrap();
+        nft_ids.push(nft_id);
+        output_ids.push((nft_id, Some(output_id)));
+    }
+
+    storage.batch_commit(batch, true).unwrap();
+
+    let iter = AsIterator::<NftId, OutputId>::iter(storage).unwrap();
+    let mut count = 0;
+
+    for result in iter {
+        let (nft_id, output_id) = result.unwrap();
+        assert!(output_ids.contains(&(nft_id, Some(output_id))));
+        count += 1;
+    }
+
+    assert_eq!(count, 10);
+
+    let results = MultiFetch::<NftId, OutputId>::multi_fetch(storage, &nft_ids)
+        .unwrap()
+        .collect::<Vec<_>>();
+
+    assert_eq!(results.len(), nft_ids.len());
+
+    for ((_, output_id), result) in output_ids.into_iter().zip(results.into_iter()) {
+        assert_eq!(output_id, result.unwrap());
+    }
+
+    Truncate::<NftId, OutputId>::truncate(storage).unwrap();
+
+    let mut iter = AsIterator::<NftId, OutputId>::iter(storage).unwrap();
+
+    assert!(iter.next().is_none());
+}
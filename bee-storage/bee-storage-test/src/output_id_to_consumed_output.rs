@@ -41,11 +41,9 @@ pub fn output_id_to_consumed_output_access<B: StorageBackend>(storage: &B) {
     let (output_id, consumed_output) = (rand_output_id(), rand_consumed_output());
 
     assert!(!Exist::<OutputId, ConsumedOutput>::exist(storage, &output_id).unwrap());
-    assert!(
-        Fetch::<OutputId, ConsumedOutput>::fetch(storage, &output_id)
-            .unwrap()
-            .is_none()
-    );
+    assert!(Fetch::<OutputId, ConsumedOutput>::fetch(storage, &output_id)
+        .unwrap()
+        .is_none());
     let results = MultiFetch::<OutputId, ConsumedOutput>::multi_fetch(storage, &[output_id])
         .unwrap()
         .collect::<Vec<_>>();
@@ -70,11 +68,9 @@ pub fn output_id_to_consumed_output_access<B: StorageBackend>(storage: &B) {
     Delete::<OutputId, ConsumedOutput>::delete(storage, &output_id).unwrap();
 
     assert!(!Exist::<OutputId, ConsumedOutput>::exist(storage, &output_id).unwrap());
-    assert!(
-        Fetch::<OutputId, ConsumedOutput>::fetch(storage, &output_id)
-            .unwrap()
-            .is_none()
-    );
+    assert!(Fetch::<OutputId, ConsumedOutput>::fetch(storage, &output_id)
+        .unwrap()
+        .is_none());
     let results = MultiFetch::<OutputId, ConsumedOutput>::multi_fetch(storage, &[output_id])
         .unwrap()
         .collect::<Vec<_>>();
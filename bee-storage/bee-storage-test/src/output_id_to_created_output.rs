@@ -41,11 +41,9 @@ pub fn output_id_to_created_output_access<B: StorageBackend>(storage: &B) {
     let (output_id, created_output) = (rand_output_id(), rand_created_output());
 
     assert!(!Exist::<OutputId, CreatedOutput>::exist(storage, &output_id).unwrap());
-    assert!(
-        Fetch::<OutputId, CreatedOutput>::fetch(storage, &output_id)
-            .unwrap()
-            .is_none()
-    );
+    assert!(Fetch::<OutputId, CreatedOutput>::fetch(storage, &output_id)
+        .unwrap()
+        .is_none());
     let results = MultiFetch::<OutputId, CreatedOutput>::multi_fetch(storage, &[output_id])
         .unwrap()
         .collect::<Vec<_>>();
@@ -70,11 +68,9 @@ pub fn output_id_to_created_output_access<B: StorageBackend>(storage: &B) {
     Delete::<OutputId, CreatedOutput>::delete(storage, &output_id).unwrap();
 
     assert!(!Exist::<OutputId, CreatedOutput>::exist(storage, &output_id).unwrap());
-    assert!(
-        Fetch::<OutputId, CreatedOutput>::fetch(storage, &output_id)
-            .unwrap()
-            .is_none()
-    );
+    assert!(Fetch::<OutputId, CreatedOutput>::fetch(storage, &output_id)
+        .unwrap()
+        .is_none());
     let results = MultiFetch::<OutputId, CreatedOutput>::multi_fetch(storage, &[output_id])
         .unwrap()
         .collect::<Vec<_>>();
@@ -0,0 +1,138 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::output::{feature_block::SenderFeatureBlock, OutputId};
+use bee_storage::{
+    access::{AsIterator, AsRangeIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, IterDirection, Truncate},
+    backend,
+};
+use bee_test::rand::output::{feature_block::rand_sender_feature_block, rand_output_id};
+
+use std::collections::HashMap;
+
+pub trait StorageBackend:
+    backend::StorageBackend
+    + Exist<(SenderFeatureBlock, OutputId), ()>
+    + Fetch<SenderFeatureBlock, Vec<OutputId>>
+    + Insert<(SenderFeatureBlock, OutputId), ()>
+    + Delete<(SenderFeatureBlock, OutputId), ()>
+    + BatchBuilder
+    + Batch<(SenderFeatureBlock, OutputId), ()>
+    + for<'a> AsIterator<'a, (SenderFeatureBlock, OutputId), ()>
+    + for<'a> AsRangeIterator<'a, SenderFeatureBlock, (SenderFeatureBlock, OutputId), ()>
+    + Truncate<(SenderFeatureBlock, OutputId), ()>
+{
+}
+
+impl<T> StorageBackend for T where
+    T: backend::StorageBackend
+        + Exist<(SenderFeatureBlock, OutputId), ()>
+        + Fetch<SenderFeatureBlock, Vec<OutputId>>
+        + Insert<(SenderFeatureBlock, OutputId), ()>
+        + Delete<(SenderFeatureBlock, OutputId), ()>
+        + BatchBuilder
+        + Batch<(SenderFeatureBlock, OutputId), ()>
+        + for<'a> AsIterator<'a, (SenderFeatureBlock, OutputId), ()>
+        + for<'a> AsRangeIterator<'a, SenderFeatureBlock, (SenderFeatureBlock, OutputId), ()>
+        + Truncate<(SenderFeatureBlock, OutputId), ()>
+{
+}
+
+pub fn sender_to_output_id_access<B: StorageBackend>(storage: &B) {
+    let (sender, output_id) = (rand_sender_feature_block(), rand_output_id());
+
+    assert!(!Exist::<(SenderFeatureBlock, OutputId), ()>::exist(storage, &(sender.clone(), output_id)).unwrap());
+    assert!(Fetch::<SenderFeatureBlock, Vec<OutputId>>::fetch(storage, &sender)
+        .unwrap()
+        .unwrap()
+        .is_empty());
+
+    Insert::<(SenderFeatureBlock, OutputId), ()>::insert(storage, &(sender.clone(), output_id), &()).unwrap();
+
+    assert!(Exist::<(SenderFeatureBlock, OutputId), ()>::exist(storage, &(sender.clone(), output_id)).unwrap());
+    assert_eq!(
+        Fetch::<SenderFeatureBlock, Vec<OutputId>>::fetch(storage, &sender)
+            .unwrap()
+            .unwrap(),
+        vec![output_id]
+    );
+
+    Delete::<(SenderFeatureBlock, OutputId), ()>::delete(storage, &(sender.clone(), output_id)).unwrap();
+
+    assert!(!Exist::<(SenderFeatureBlock, OutputId), ()>::exist(storage, &(sender.clone(), output_id)).unwrap());
+    assert!(Fetch::<SenderFeatureBlock, Vec<OutputId>>::fetch(storage, &sender)
+        .unwrap()
+        .unwrap()
+        .is_empty());
+
+    let mut batch = B::batch_begin();
+
+    for _ in 0..10 {
+        let (sender, output_id) = (rand_sender_feature_block(), rand_output_id());
+        Insert::<(SenderFeatureBlock, OutputId), ()>::insert(storage, &(sender.clone(), output_id), &()).unwrap();
+        Batch::<(SenderFeatureBlock, OutputId), ()>::batch_delete(storage, &mut batch, &(sender.clone(), output_id))
+            .unwrap();
+    }
+
+    let mut output_ids = HashMap::<SenderFeatureBlock, Vec<OutputId>>::new();
+
+    for _ in 0..5 {
+        let sender = rand_sender_feature_block();
+        for _ in 0..5 {
+            let output_id = rand_output_id();
+            Batch::<(SenderFeatureBlock, OutputId), ()>::batch_insert(
+                storage,
+                &mut batch,
+                &(sender.clone(), output_id),
+                &(),
+            )
+            .unwrap();
+            output_ids.entry(sender.clone()).or_default().push(output_id);
+        }
+    }
+
+    storage.batch_commit(batch, true).unwrap();
+
+    let iter = AsIterator::<(SenderFeatureBlock, OutputId), ()>::iter(storage).unwrap();
+    let mut count = 0;
+
+    for result in iter {
+        let ((sender, output_id), _) = result.unwrap();
+        assert!(output_ids.get(&sender).unwrap().contains(&output_id));
+        count += 1;
+    }
+
+    assert_eq!(count, output_ids.iter().fold(0, |acc, v| acc + v.1.len()));
+
+    for (sender, output_ids) in output_ids.iter() {
+        let forward = AsRangeIterator::<SenderFeatureBlock, (SenderFeatureBlock, OutputId), ()>::iter_prefix(
+            storage,
+            sender,
+            IterDirection::Forward,
+        )
+        .unwrap()
+        .map(|result| result.unwrap().0)
+        .collect::<Vec<_>>();
+        let mut reverse = AsRangeIterator::<SenderFeatureBlock, (SenderFeatureBlock, OutputId), ()>::iter_prefix(
+            storage,
+            sender,
+            IterDirection::Reverse,
+        )
+        .unwrap()
+        .map(|result| result.unwrap().0)
+        .collect::<Vec<_>>();
+        reverse.reverse();
+
+        assert_eq!(forward, reverse);
+        assert_eq!(forward.len(), output_ids.len());
+        assert!(forward
+            .iter()
+            .all(|(other, output_id)| other == sender && output_ids.contains(output_id)));
+    }
+
+    Truncate::<(SenderFeatureBlock, OutputId), ()>::truncate(storage).unwrap();
+
+    let mut iter = AsIterator::<(SenderFeatureBlock, OutputId), ()>::iter(storage).unwrap();
+
+    assert!(iter.next().is_none());
+}
@@ -41,11 +41,9 @@ pub fn solid_entry_point_to_milestone_index_access<B: StorageBackend>(storage: &
     let (sep, index) = (rand_solid_entry_point(), rand_milestone_index());
 
     assert!(!Exist::<SolidEntryPoint, MilestoneIndex>::exist(storage, &sep).unwrap());
-    assert!(
-        Fetch::<SolidEntryPoint, MilestoneIndex>::fetch(storage, &sep)
-            .unwrap()
-            .is_none()
-    );
+    assert!(Fetch::<SolidEntryPoint, MilestoneIndex>::fetch(storage, &sep)
+        .unwrap()
+        .is_none());
     let results = MultiFetch::<SolidEntryPoint, MilestoneIndex>::multi_fetch(storage, &[sep])
         .unwrap()
         .collect::<Vec<_>>();
@@ -70,11 +68,9 @@ pub fn solid_entry_point_to_milestone_index_access<B: StorageBackend>(storage: &
     Delete::<SolidEntryPoint, MilestoneIndex>::delete(storage, &sep).unwrap();
 
     assert!(!Exist::<SolidEntryPoint, MilestoneIndex>::exist(storage, &sep).unwrap());
-    assert!(
-        Fetch::<SolidEntryPoint, MilestoneIndex>::fetch(storage, &sep)
-            .unwrap()
-            .is_none()
-    );
+    assert!(Fetch::<SolidEntryPoint, MilestoneIndex>::fetch(storage, &sep)
+        .unwrap()
+        .is_none());
     let results = MultiFetch::<SolidEntryPoint, MilestoneIndex>::multi_fetch(storage, &[sep])
         .unwrap()
         .collect::<Vec<_>>();
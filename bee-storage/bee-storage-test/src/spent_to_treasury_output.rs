@@ -3,7 +3,7 @@
 
 use bee_ledger::types::TreasuryOutput;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, AsRangeIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, IterDirection, Truncate},
     backend,
 };
 use bee_test::rand::{bool::rand_bool, output::rand_ledger_treasury_output};
@@ -19,6 +19,7 @@ pub trait StorageBackend:
     + BatchBuilder
     + Batch<(bool, TreasuryOutput), ()>
     + for<'a> AsIterator<'a, (bool, TreasuryOutput), ()>
+    + for<'a> AsRangeIterator<'a, bool, (bool, TreasuryOutput), ()>
     + Truncate<(bool, TreasuryOutput), ()>
 {
 }
@@ -32,6 +33,7 @@ impl<T> StorageBackend for T where
         + BatchBuilder
         + Batch<(bool, TreasuryOutput), ()>
         + for<'a> AsIterator<'a, (bool, TreasuryOutput), ()>
+        + for<'a> AsRangeIterator<'a, bool, (bool, TreasuryOutput), ()>
         + Truncate<(bool, TreasuryOutput), ()>
 {
 }
@@ -40,12 +42,10 @@ pub fn spent_to_treasury_output_access<B: StorageBackend>(storage: &B) {
     let (spent, treasury_output) = (rand_bool(), rand_ledger_treasury_output());
 
     assert!(!Exist::<(bool, TreasuryOutput), ()>::exist(storage, &(spent, treasury_output.clone())).unwrap());
-    assert!(
-        Fetch::<bool, Vec<TreasuryOutput>>::fetch(storage, &spent)
-            .unwrap()
-            .unwrap()
-            .is_empty()
-    );
+    assert!(Fetch::<bool, Vec<TreasuryOutput>>::fetch(storage, &spent)
+        .unwrap()
+        .unwrap()
+        .is_empty());
 
     Insert::<(bool, TreasuryOutput), ()>::insert(storage, &(spent, treasury_output.clone()), &()).unwrap();
 
@@ -60,12 +60,10 @@ pub fn spent_to_treasury_output_access<B: StorageBackend>(storage: &B) {
     Delete::<(bool, TreasuryOutput), ()>::delete(storage, &(spent, treasury_output.clone())).unwrap();
 
     assert!(!Exist::<(bool, TreasuryOutput), ()>::exist(storage, &(spent, treasury_output)).unwrap());
-    assert!(
-        Fetch::<bool, Vec<TreasuryOutput>>::fetch(storage, &spent)
-            .unwrap()
-            .unwrap()
-            .is_empty()
-    );
+    assert!(Fetch::<bool, Vec<TreasuryOutput>>::fetch(storage, &spent)
+        .unwrap()
+        .unwrap()
+        .is_empty());
 
     let mut batch = B::batch_begin();
 
@@ -106,6 +104,26 @@ pub fn spent_to_treasury_output_access<B: StorageBackend>(storage: &B) {
 
     assert_eq!(count, treasury_outputs.iter().fold(0, |acc, v| acc + v.1.len()));
 
+    for (spent, treasury_outputs) in treasury_outputs.iter() {
+        let forward =
+            AsRangeIterator::<bool, (bool, TreasuryOutput), ()>::iter_prefix(storage, spent, IterDirection::Forward)
+                .unwrap()
+                .map(|result| result.unwrap().0)
+                .collect::<Vec<_>>();
+        let mut reverse =
+            AsRangeIterator::<bool, (bool, TreasuryOutput), ()>::iter_prefix(storage, spent, IterDirection::Reverse)
+                .unwrap()
+                .map(|result| result.unwrap().0)
+                .collect::<Vec<_>>();
+        reverse.reverse();
+
+        assert_eq!(forward, reverse);
+        assert_eq!(forward.len(), treasury_outputs.len());
+        assert!(forward
+            .iter()
+            .all(|(other, treasury_output)| other == spent && treasury_outputs.contains(treasury_output)));
+    }
+
     Truncate::<(bool, TreasuryOutput), ()>::truncate(storage).unwrap();
 
     let mut iter = AsIterator::<(bool, TreasuryOutput), ()>::iter(storage).unwrap();
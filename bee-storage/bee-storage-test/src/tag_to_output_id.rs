@@ -0,0 +1,170 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::output::{feature_block::TagFeatureBlock, OutputId};
+use bee_storage::{
+    access::{AsIterator, AsRangeIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, IterDirection, Truncate},
+    backend,
+};
+use bee_test::rand::output::{feature_block::rand_tag_feature_block, rand_output_id};
+
+use std::collections::HashMap;
+
+pub trait StorageBackend:
+    backend::StorageBackend
+    + Exist<(TagFeatureBlock, OutputId), ()>
+    + Fetch<TagFeatureBlock, Vec<OutputId>>
+    + Insert<(TagFeatureBlock, OutputId), ()>
+    + Delete<(TagFeatureBlock, OutputId), ()>
+    + BatchBuilder
+    + Batch<(TagFeatureBlock, OutputId), ()>
+    + for<'a> AsIterator<'a, (TagFeatureBlock, OutputId), ()>
+    + for<'a> AsRangeIterator<'a, TagFeatureBlock, (TagFeatureBlock, OutputId), ()>
+    + Truncate<(TagFeatureBlock, OutputId), ()>
+{
+}
+
+impl<T> StorageBackend for T where
+    T: backend::StorageBackend
+        + Exist<(TagFeatureBlock, OutputId), ()>
+        + Fetch<TagFeatureBlock, Vec<OutputId>>
+        + Insert<(TagFeatureBlock, OutputId), ()>
+        + Delete<(TagFeatureBlock, OutputId), ()>
+        + BatchBuilder
+        + Batch<(TagFeatureBlock, OutputId), ()>
+        + for<'a> AsIterator<'a, (TagFeatureBlock, OutputId), ()>
+        + for<'a> AsRangeIterator<'a, TagFeatureBlock, (TagFeatureBlock, OutputId), ()>
+        + Truncate<(TagFeatureBlock, OutputId), ()>
+{
+}
+
+pub fn tag_to_output_id_access<B: StorageBackend>(storage: &B) {
+    let (tag, output_id) = (rand_tag_feature_block(), rand_output_id());
+
+    assert!(!Exist::<(TagFeatureBlock, OutputId), ()>::exist(storage, &(tag.clone(), output_id)).unwrap());
+    assert!(Fetch::<TagFeatureBlock, Vec<OutputId>>::fetch(storage, &tag)
+        .unwrap()
+        .unwrap()
+        .is_empty());
+
+    Insert::<(TagFeatureBlock, OutputId), ()>::insert(storage, &(tag.clone(), output_id), &()).unwrap();
+
+    assert!(Exist::<(TagFeatureBlock, OutputId), ()>::exist(storage, &(tag.clone(), output_id)).unwrap());
+    assert_eq!(
+        Fetch::<TagFeatureBlock, Vec<OutputId>>::fetch(storage, &tag)
+            .unwrap()
+            .unwrap(),
+        vec![output_id]
+    );
+
+    Delete::<(TagFeatureBlock, OutputId), ()>::delete(storage, &(tag.clone(), output_id)).unwrap();
+
+    assert!(!Exist::<(TagFeatureBlock, OutputId), ()>::exist(storage, &(tag.clone(), output_id)).unwrap());
+    assert!(Fetch::<TagFeatureBlock, Vec<OutputId>>::fetch(storage, &tag)
+        .unwrap()
+        .unwrap()
+        .is_empty());
+
+    let prefix = TagFeatureBlock::new(vec![0x42, 0x43]).unwrap();
+    let (tag, output_id) = (TagFeatureBlock::new(vec![0x42, 0x43, 0x44]).unwrap(), rand_output_id());
+    Insert::<(TagFeatureBlock, OutputId), ()>::insert(storage, &(prefix.clone(), output_id), &()).unwrap();
+    Insert::<(TagFeatureBlock, OutputId), ()>::insert(storage, &(tag.clone(), output_id), &()).unwrap();
+
+    // Fetching is exact, unlike prefix iteration.
+    assert_eq!(
+        Fetch::<TagFeatureBlock, Vec<OutputId>>::fetch(storage, &prefix)
+            .unwrap()
+            .unwrap(),
+        vec![output_id]
+    );
+    assert_eq!(
+        Fetch::<TagFeatureBlock, Vec<OutputId>>::fetch(storage, &tag)
+            .unwrap()
+            .unwrap(),
+        vec![output_id]
+    );
+
+    Delete::<(TagFeatureBlock, OutputId), ()>::delete(storage, &(prefix, output_id)).unwrap();
+    Delete::<(TagFeatureBlock, OutputId), ()>::delete(storage, &(tag, output_id)).unwrap();
+
+    let mut batch = B::batch_begin();
+
+    for _ in 0..10 {
+        let (tag, output_id) = (rand_tag_feature_block(), rand_output_id());
+        Insert::<(TagFeatureBlock, OutputId), ()>::insert(storage, &(tag.clone(), output_id), &()).unwrap();
+        Batch::<(TagFeatureBlock, OutputId), ()>::batch_delete(storage, &mut batch, &(tag.clone(), output_id)).unwrap();
+    }
+
+    let mut output_ids = HashMap::<TagFeatureBlock, Vec<OutputId>>::new();
+
+    for _ in 0..5 {
+        let tag = rand_tag_feature_block();
+        for _ in 0..5 {
+            let output_id = rand_output_id();
+            Batch::<(TagFeatureBlock, OutputId), ()>::batch_insert(storage, &mut batch, &(tag.clone(), output_id), &())
+                .unwrap();
+            output_ids.entry(tag.clone()).or_default().push(output_id);
+        }
+    }
+
+    storage.batch_commit(batch, true).unwrap();
+
+    let iter = AsIterator::<(TagFeatureBlock, OutputId), ()>::iter(storage).unwrap();
+    let mut count = 0;
+
+    for result in iter {
+        let ((tag, output_id), _) = result.unwrap();
+        assert!(output_ids.get(&tag).unwrap().contains(&output_id));
+        count += 1;
+    }
+
+    assert_eq!(count, output_ids.iter().fold(0, |acc, v| acc + v.1.len()));
+
+    for (tag, output_ids) in output_ids.iter_mut() {
+        output_ids.sort();
+
+        assert_eq!(
+            &Fetch::<TagFeatureBlock, Vec<OutputId>>::fetch(storage, tag)
+                .unwrap()
+                .unwrap(),
+            output_ids
+        );
+    }
+
+    for (tag, output_ids) in output_ids.iter() {
+        let forward = AsRangeIterator::<TagFeatureBlock, (TagFeatureBlock, OutputId), ()>::iter_prefix(
+            storage,
+            tag,
+            IterDirection::Forward,
+        )
+        .unwrap()
+        .map(|result| result.unwrap().0)
+        .collect::<Vec<_>>();
+        let mut reverse = AsRangeIterator::<TagFeatureBlock, (TagFeatureBlock, OutputId), ()>::iter_prefix(
+            storage,
+            tag,
+            IterDirection::Reverse,
+        )
+        .unwrap()
+        .map(|result| result.unwrap().0)
+        .collect::<Vec<_>>();
+        reverse.reverse();
+
+        assert_eq!(forward, reverse);
+        // Tags are stored without their length, so the prefix also matches the longer tags starting with it.
+        assert!(forward.iter().all(|(other, _)| other.tag().starts_with(tag.tag())));
+        assert_eq!(
+            forward.iter().filter(|(other, _)| other == tag).count(),
+            output_ids.len()
+        );
+        assert!(output_ids
+            .iter()
+            .all(|output_id| forward.contains(&(tag.clone(), *output_id))));
+    }
+
+    Truncate::<(TagFeatureBlock, OutputId), ()>::truncate(storage).unwrap();
+
+    let mut iter = AsIterator::<(TagFeatureBlock, OutputId), ()>::iter(storage).unwrap();
+
+    assert!(iter.next().is_none());
+}
@@ -12,3 +12,32 @@ pub trait AsIterator<'a, K, V>: StorageBackend {
     /// Returns a `Iterator` object for the provided <K, V> collection.
     fn iter(&'a self) -> Result<Self::AsIter, Self::Error>;
 }
+
+/// Direction in which the keys of a collection are iterated.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IterDirection {
+    /// Iterates the keys in ascending order.
+    Forward,
+    /// Iterates the keys in descending order.
+    Reverse,
+}
+
+/// `AsRangeIterator<'a, P, K, V>` trait extends the `StorageBackend` with `iter_from`, `iter_range` and `iter_prefix`
+/// operations for the (key: K, value: V) pair, where P is a prefix of K; therefore, it should be explicitly implemented
+/// for the corresponding `StorageBackend`.
+///
+/// Keys are ordered by their byte representation in the storage, which is not necessarily the order of their values.
+pub trait AsRangeIterator<'a, P, K, V>: StorageBackend {
+    /// Type to iterate through a part of the <K, V> collection.
+    type AsRangeIter: Iterator<Item = Result<(K, V), Self::Error>>;
+
+    /// Returns an `Iterator` object over the keys following the provided key in the given direction, the provided key
+    /// included.
+    fn iter_from(&'a self, start: &K, direction: IterDirection) -> Result<Self::AsRangeIter, Self::Error>;
+
+    /// Returns an `Iterator` object over the keys from `start` included to `end` excluded.
+    fn iter_range(&'a self, start: &K, end: &K, direction: IterDirection) -> Result<Self::AsRangeIter, Self::Error>;
+
+    /// Returns an `Iterator` object over the keys starting with the provided prefix.
+    fn iter_prefix(&'a self, prefix: &P, direction: IterDirection) -> Result<Self::AsRangeIter, Self::Error>;
+}
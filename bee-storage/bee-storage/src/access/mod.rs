@@ -26,6 +26,6 @@ pub use delete::Delete;
 pub use exist::Exist;
 pub use fetch::Fetch;
 pub use insert::Insert;
-pub use iter::AsIterator;
+pub use iter::{AsIterator, AsRangeIterator, IterDirection};
 pub use multi_fetch::MultiFetch;
 pub use truncate::Truncate;
@@ -8,6 +8,8 @@ use crate::system::StorageHealth;
 
 use serde::de::DeserializeOwned;
 
+use std::path::Path;
+
 /// Trait to be implemented on a storage backend.
 /// Determines how to start and shutdown the backend.
 pub trait StorageBackend: Send + Sized + Sync + 'static {
@@ -36,3 +38,11 @@ pub trait StorageBackend: Send + Sized + Sync + 'static {
     /// Not all backends may be able to provide this operation.
     fn set_health(&self, health: StorageHealth) -> Result<(), Self::Error>;
 }
+
+/// Trait to be implemented on a storage backend that can be backed up while running.
+pub trait StorageBackup: StorageBackend {
+    /// Creates a copy of the database in the given directory, which must not exist yet.
+    /// The copy is consistent and marked as healthy so that it can be restored as is, backends that can't copy a
+    /// running database consistently return an error instead.
+    fn backup(&self, path: &Path) -> Result<(), Self::Error>;
+}
@@ -8,8 +8,10 @@
 //! ## Access implementation:
 //! - Traits contracts which define the general purpose database operations such as (insert, fetch, ...);
 //! ## Backend implementation:
-//! - Trait contract to start and shutdown backends;
+//! - Trait contract to start, shutdown and back up backends;
 //! - Configuration and associated builder to configure different backends;
+//! ## Migration:
+//! - Registered steps to migrate a storage from an older version to the current one;
 //!
 //! This crate tries to simplify the implementation of various storage backends and provides unified access API for the
 //! application/user space.
@@ -19,4 +21,5 @@
 
 pub mod access;
 pub mod backend;
+pub mod migration;
 pub mod system;
@@ -0,0 +1,102 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module holds the framework to migrate a storage from an older version to the current one.
+
+use crate::{
+    access::Insert,
+    backend::StorageBackend,
+    system::{StorageHealth, StorageVersion, System, SYSTEM_VERSION_KEY},
+};
+
+use log::info;
+
+/// Errors occurring while migrating a storage.
+#[derive(Debug, thiserror::Error)]
+pub enum Error<E: std::error::Error> {
+    /// No migration step is registered to migrate from the given version.
+    #[error("no migration step from storage version {0:?}")]
+    MissingStep(StorageVersion),
+    /// The storage backend failed while migrating.
+    #[error("storage backend error: {0}")]
+    Backend(E),
+}
+
+/// A step migrating a storage from a version `N` to the version `N + 1`.
+pub struct MigrationStep<B: StorageBackend> {
+    from: StorageVersion,
+    description: &'static str,
+    migrate: fn(&mut B) -> Result<(), B::Error>,
+}
+
+impl<B: StorageBackend> MigrationStep<B> {
+    /// Creates a new `MigrationStep` from the given version to the next one.
+    pub fn new(from: StorageVersion, description: &'static str, migrate: fn(&mut B) -> Result<(), B::Error>) -> Self {
+        Self {
+            from,
+            description,
+            migrate,
+        }
+    }
+
+    /// Returns the version the `MigrationStep` migrates from.
+    pub fn from(&self) -> StorageVersion {
+        self.from
+    }
+
+    /// Returns the version the `MigrationStep` migrates to.
+    pub fn to(&self) -> StorageVersion {
+        StorageVersion(self.from.0 + 1)
+    }
+
+    /// Returns a human readable description of the `MigrationStep`.
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+}
+
+/// Migrates a storage from its current version to the target version by running the registered steps in order.
+///
+/// The storage is marked as `StorageHealth::Corrupted` while a step runs so that an interrupted migration is detected
+/// at the next start, and the new version is persisted after each successful step.
+pub fn migrate<B>(
+    storage: &mut B,
+    current: StorageVersion,
+    target: StorageVersion,
+    steps: &[MigrationStep<B>],
+) -> Result<(), Error<B::Error>>
+where
+    B: StorageBackend + Insert<u8, System>,
+{
+    if current.0 > target.0 {
+        return Err(Error::MissingStep(current));
+    }
+
+    let mut version = current;
+
+    while version != target {
+        let step = steps
+            .iter()
+            .find(|step| step.from == version)
+            .ok_or(Error::MissingStep(version))?;
+
+        info!(
+            "Migrating storage from version {} to version {}: {}.",
+            step.from.0,
+            step.to().0,
+            step.description
+        );
+
+        storage.set_health(StorageHealth::Corrupted).map_err(Error::Backend)?;
+        (step.migrate)(storage).map_err(Error::Backend)?;
+        Insert::<u8, System>::insert(storage, &SYSTEM_VERSION_KEY, &System::Version(step.to()))
+            .map_err(Error::Backend)?;
+        storage.set_health(StorageHealth::Healthy).map_err(Error::Backend)?;
+
+        info!("Migrated storage to version {}.", step.to().0);
+
+        version = step.to();
+    }
+
+    Ok(())
+}
@@ -32,6 +32,37 @@ pub enum ConflictReason {
     InputOutputSumMismatch = 4,
     /// The unlock block signature is invalid.
     InvalidSignature = 5,
+    /// The sum of the input and output native tokens does not match.
+    NativeTokensSumMismatch = 6,
+    /// The circulating supply of a foundry does not change in accordance with the minted or melted native tokens.
+    InvalidFoundrySupplyChange = 7,
+    /// An alias output with a non-null alias ID does not transition a consumed alias output, or a new alias output has
+    /// a non-zero state index or foundry counter.
+    InvalidAliasCreation = 8,
+    /// The state index of an alias output is neither kept nor incremented by one.
+    InvalidAliasStateIndex = 9,
+    /// A state transition of an alias output changes fields only the governor can change.
+    InvalidAliasStateTransition = 10,
+    /// A governance transition of an alias output changes fields only the state controller can change.
+    InvalidAliasGovernanceTransition = 11,
+    /// An alias output is destroyed without being unlocked by its governor.
+    InvalidAliasDestruction = 12,
+    /// An NFT output with a non-null NFT ID does not transition a consumed NFT output, or its issuer is not unlocked.
+    InvalidNftCreation = 13,
+    /// A transition of an NFT output changes its immutable metadata or issuer.
+    InvalidNftTransition = 14,
+    /// An NFT address is not unlocked by an NFT unlock block referencing the input of the NFT.
+    InvalidNftUnlockBlock = 15,
+    /// The timelock of a consumed output is not expired yet.
+    TimelockNotExpired = 16,
+    /// The dust deposits of the consumed outputs are not returned to their return addresses.
+    DustDepositReturnMismatch = 17,
+    /// A created output does not cover the dust deposit required by its byte cost.
+    InsufficientDustDeposit = 18,
+    /// An alias address is unlocked by an alias output that is not state transitioned by the transaction.
+    InvalidAliasUnlock = 19,
+    /// New foundry outputs are not matched by the increase of the foundry counter of their state transitioned alias.
+    InvalidFoundryCreation = 20,
     /// The semantic validation failed for a reason not covered by the previous variants.
     SemanticValidationFailed = 255,
 }
@@ -53,6 +84,21 @@ impl TryFrom<u8> for ConflictReason {
             3 => Self::InputUtxoNotFound,
             4 => Self::InputOutputSumMismatch,
             5 => Self::InvalidSignature,
+            6 => Self::NativeTokensSumMismatch,
+            7 => Self::InvalidFoundrySupplyChange,
+            8 => Self::InvalidAliasCreation,
+            9 => Self::InvalidAliasStateIndex,
+            10 => Self::InvalidAliasStateTransition,
+            11 => Self::InvalidAliasGovernanceTransition,
+            12 => Self::InvalidAliasDestruction,
+            13 => Self::InvalidNftCreation,
+            14 => Self::InvalidNftTransition,
+            15 => Self::InvalidNftUnlockBlock,
+            16 => Self::TimelockNotExpired,
+            17 => Self::DustDepositReturnMismatch,
+            18 => Self::InsufficientDustDeposit,
+            19 => Self::InvalidAliasUnlock,
+            20 => Self::InvalidFoundryCreation,
             255 => Self::SemanticValidationFailed,
             x => return Err(Self::Error::InvalidConflict(x)),
         })
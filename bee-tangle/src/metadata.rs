@@ -141,6 +141,15 @@ impl MessageMetadata {
         self.reference_timestamp = timestamp;
     }
 
+    /// Reset the confirmation state of this message, i.e. the referenced flag, the milestone index, the reference
+    /// timestamp and the conflict state, so that it can be referenced again by another milestone.
+    pub fn reset_confirmation(&mut self) {
+        self.flags.set_referenced(false);
+        self.milestone_index = None;
+        self.reference_timestamp = 0;
+        self.conflict = ConflictReason::None;
+    }
+
     /// Get the conflict state of this message.
     pub fn conflict(&self) -> ConflictReason {
         self.conflict
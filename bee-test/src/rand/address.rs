@@ -3,6 +3,7 @@
 
 use crate::rand::{bytes::rand_bytes_array, number::rand_number};
 
+use bee_ledger::types::AddressRole;
 use bee_message::{
     address::{Address, AliasAddress, Ed25519Address, NftAddress},
     output::{AliasId, NftId},
@@ -33,3 +34,8 @@ pub fn rand_address() -> Address {
         _ => unreachable!(),
     }
 }
+
+/// Generates a random address role.
+pub fn rand_address_role() -> AddressRole {
+    AddressRole::ALL[rand_number::<usize>() % AddressRole::ALL.len()]
+}
@@ -33,6 +33,21 @@ pub fn rand_output_id() -> OutputId {
     OutputId::new(rand_transaction_id(), rand_number_range(OUTPUT_INDEX_RANGE)).unwrap()
 }
 
+/// Generates a random [`AliasId`](output::AliasId).
+pub fn rand_alias_id() -> output::AliasId {
+    output::AliasId::from(rand_bytes_array())
+}
+
+/// Generates a random [`NftId`](output::NftId).
+pub fn rand_nft_id() -> output::NftId {
+    output::NftId::from(rand_bytes_array())
+}
+
+/// Generates a random [`FoundryId`](output::FoundryId).
+pub fn rand_foundry_id() -> output::FoundryId {
+    output::FoundryId::from(rand_bytes_array())
+}
+
 /// Generates a random [`Unspent`] output id.
 pub fn rand_unspent_output_id() -> Unspent {
     Unspent::new(rand_output_id())